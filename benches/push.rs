@@ -101,7 +101,6 @@ macro_rules! bench_translate {
     ) => {
         mod $name {
             use super::*;
-            use n_circular_array::Strides;
 
             bench_translate_method!(translate_front, $d, $n);
             bench_translate_method!(translate_back, $d, $n);
@@ -132,7 +131,7 @@ macro_rules! bench_translate_method {
 
             bencher.iter(|| {
                 axis = (axis + 1) % $d;
-                origin[axis] = origin[axis] + 1 % $d;
+                origin[axis] += 1 % $d;
 
                 dst.$method(axis, $d - 1, origin, src_fn);
             });
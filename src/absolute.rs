@@ -0,0 +1,178 @@
+use std::ops::Range;
+
+use crate::array_index::CircularIndex;
+use crate::array_mut::CircularMut;
+use crate::CircularArray;
+
+/// A [`CircularArray`] counting the slices pushed to the front of each axis, for
+/// addressing slices by absolute (since creation) lane number rather than by
+/// index.
+///
+/// Slices present on creation are numbered `0..len` for an axis of length `len`,
+/// and each slice pushed is numbered one greater than the newest. Lane numbers
+/// are stable across wraps, such that a consumer may retain a lane number and
+/// later find whether, and where, the slice remains within the array.
+pub struct AbsoluteAxes<const N: usize, A, T> {
+    /// The array.
+    array: CircularArray<N, A, T>,
+    /// The lane number following the newest slice of each axis.
+    cursor: [usize; N],
+}
+
+impl<const N: usize, A, T> AbsoluteAxes<N, A, T>
+where
+    A: AsRef<[T]> + AsMut<[T]>,
+    T: Clone,
+{
+    /// Create a new `AbsoluteAxes` counting slices pushed to `array`. Existing
+    /// slices of each axis are numbered from `0`.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{AbsoluteAxes, CircularArray};
+    /// let array = CircularArray::new([3, 2], vec![
+    ///     0, 1, 2,
+    ///     3, 4, 5,
+    /// ]);
+    /// let mut absolute = AbsoluteAxes::new(array);
+    ///
+    /// absolute.push_front(0, &[6, 7]);
+    /// absolute.push_front(0, &[8, 9]);
+    /// assert_eq!(absolute.resident(0), 2..5);
+    ///
+    /// let (evicted, iter) = absolute.iter_absolute(0, 1..4);
+    /// assert_eq!(evicted, 1..2);
+    /// assert_eq!(iter.cloned().collect::<Vec<_>>(), &[
+    ///     2, 6,
+    ///     5, 7,
+    /// ]);
+    /// ```
+    pub fn new(array: CircularArray<N, A, T>) -> Self {
+        AbsoluteAxes {
+            cursor: array.shape,
+            array,
+        }
+    }
+
+    /// Get a reference to the array.
+    pub fn array(&self) -> &CircularArray<N, A, T> {
+        &self.array
+    }
+
+    /// Drop the lane counts, returning the array.
+    pub fn into_array(self) -> CircularArray<N, A, T> {
+        self.array
+    }
+
+    /// Get the lane number following the newest slice of the given `axis`.
+    pub fn cursor(&self, axis: usize) -> usize {
+        assert_shape_index!(axis, N);
+
+        self.cursor[axis]
+    }
+
+    /// Get the lane numbers of the slices of the given `axis` within the array,
+    /// from oldest to newest.
+    pub fn resident(&self, axis: usize) -> Range<usize> {
+        let cursor = self.cursor(axis);
+
+        cursor - self.array.shape[axis]..cursor
+    }
+
+    /// Push elements to the front of the given `axis`, aligned to the offset. See
+    /// [`CircularMut::push_front`].
+    pub fn push_front(&mut self, axis: usize, el: &[T]) {
+        self.array.push_front(axis, el);
+        self.cursor[axis] += el.len() / self.array.slice_len(axis).max(1);
+    }
+
+    /// Translate the array by `n` on the given `axis`, inserting elements to the
+    /// front of the array. See [`CircularMut::translate_front`].
+    pub fn translate_front<'b, F>(&mut self, axis: usize, n: usize, origin: [usize; N], el_fn: F)
+    where
+        T: 'b,
+        F: FnMut([Range<usize>; N]) -> &'b [T],
+    {
+        assert_shape_index!(axis, N);
+        self.array.translate_front(axis, n, origin, el_fn);
+        self.cursor[axis] += n;
+    }
+
+    /// Iterate over all elements of the slices of the given `axis` with lane
+    /// numbers within `range`, aligned to the offset. The end of `range` **must**
+    /// not exceed the [`AbsoluteAxes::cursor`].
+    ///
+    /// Returns the lane numbers of the prefix of `range` evicted from the array,
+    /// together with an iterator over the elements of the remaining slices. The
+    /// prefix is empty where every slice of `range` is resident.
+    pub fn iter_absolute(
+        &self,
+        axis: usize,
+        range: Range<usize>,
+    ) -> (Range<usize>, impl Iterator<Item = &T>) {
+        let resident = self.resident(axis);
+        assert!(
+            range.start <= range.end && range.end <= resident.end,
+            "lanes {:?} are out of bounds for axis {} with cursor {}",
+            range,
+            axis,
+            resident.end
+        );
+
+        let split = range.end.min(resident.start).max(range.start);
+        let start = split.max(resident.start) - resident.start;
+        let end = range.end.max(resident.start) - resident.start;
+
+        let iter = (start < end)
+            .then(|| self.array.iter_range(axis, start..end))
+            .into_iter()
+            .flatten();
+
+        (range.start..split, iter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AbsoluteAxes, CircularArrayVec, CircularIndex, CircularMut};
+
+    #[test]
+    fn iter_absolute() {
+        let shape = [4, 3, 2];
+        let mut m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..24);
+        let mut absolute = AbsoluteAxes::new(m.clone());
+
+        for n in 0..=3 {
+            let el = vec![100 + n; n * 8];
+            m.push_front(1, &el);
+            absolute.push_front(1, &el);
+        }
+        assert_eq!(absolute.cursor(1), 9);
+        assert_eq!(absolute.resident(1), 6..9);
+        assert_eq!(absolute.resident(0), 0..4);
+
+        for a in 0..=9 {
+            for b in a..=9 {
+                let (evicted, iter) = absolute.iter_absolute(1, a..b);
+                assert_eq!(evicted, a..b.min(6).max(a));
+                let (start, end) = (a.max(6) - 6, b.max(6) - 6);
+                match start < end {
+                    true => assert!(iter.eq(m.iter_range(1, start..end))),
+                    false => assert_eq!(iter.count(), 0),
+                }
+            }
+        }
+
+        let source = (0..64).collect::<Vec<_>>();
+        absolute.translate_front(2, 5, [0; 3], |[x, _, _]| &source[..x.len()]);
+        assert_eq!(absolute.resident(2), 5..7);
+        assert_eq!(absolute.iter_absolute(2, 0..6).0, 0..5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn iter_absolute_bounds() {
+        let absolute = AbsoluteAxes::new(CircularArrayVec::from_iter([3, 3], 0..9));
+        let _ = absolute.iter_absolute(0, 2..4);
+    }
+}
@@ -0,0 +1,189 @@
+use std::alloc::{self, Layout};
+use std::ptr::{self, NonNull};
+use std::{mem, slice};
+
+use crate::CircularArray;
+
+/// A `CircularArray` backed by an [`AlignedBuffer`].
+pub type CircularArrayAligned<const N: usize, T> = CircularArray<N, AlignedBuffer<T>, T>;
+
+/// A fixed size heap buffer of elements, starting at an address of a guaranteed
+/// alignment, for feeding SIMD kernels. See [`CircularArray::from_iter_aligned`].
+pub struct AlignedBuffer<T> {
+    /// The start of the buffer.
+    ptr: NonNull<T>,
+    /// The number of elements.
+    len: usize,
+    /// The layout of the allocation.
+    layout: Layout,
+}
+
+// SAFETY: The buffer uniquely owns its elements.
+unsafe impl<T: Send> Send for AlignedBuffer<T> {}
+// SAFETY: The buffer only hands out shared references through `&self`.
+unsafe impl<T: Sync> Sync for AlignedBuffer<T> {}
+
+impl<T> AlignedBuffer<T> {
+    /// Move the given `elements` into a new buffer aligned to `align` bytes, or the
+    /// alignment of `T` where greater.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `align` is not a power of two.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::AlignedBuffer;
+    /// let buffer = AlignedBuffer::new(64, vec![0.0f32; 16]);
+    ///
+    /// assert_eq!(buffer.as_ref().as_ptr() as usize % 64, 0);
+    /// ```
+    pub fn new(align: usize, mut elements: Vec<T>) -> Self {
+        assert!(
+            align.is_power_of_two(),
+            "alignment {} is not a power of two",
+            align
+        );
+
+        let len = elements.len();
+        let layout =
+            Layout::from_size_align(mem::size_of::<T>() * len, align.max(mem::align_of::<T>()))
+                .expect("Invalid buffer layout");
+
+        let ptr = match layout.size() {
+            0 => NonNull::new(ptr::without_provenance_mut(layout.align())),
+            // SAFETY: The layout is of non-zero size.
+            _ => NonNull::new(unsafe { alloc::alloc(layout) }.cast()),
+        }
+        .unwrap_or_else(|| alloc::handle_alloc_error(layout));
+
+        // SAFETY: The allocation holds `len` elements, and does not overlap the
+        // vector. Elements are moved, such that the vector must not drop them.
+        unsafe {
+            ptr::copy_nonoverlapping(elements.as_ptr(), ptr.as_ptr(), len);
+            elements.set_len(0);
+        }
+
+        AlignedBuffer { ptr, len, layout }
+    }
+
+    /// Get the alignment of the buffer in bytes.
+    pub fn align(&self) -> usize {
+        self.layout.align()
+    }
+}
+
+impl<T> AsRef<[T]> for AlignedBuffer<T> {
+    fn as_ref(&self) -> &[T] {
+        // SAFETY: The buffer holds `len` initialized elements.
+        unsafe { slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl<T> AsMut<[T]> for AlignedBuffer<T> {
+    fn as_mut(&mut self) -> &mut [T] {
+        // SAFETY: The buffer holds `len` initialized elements, borrowed uniquely.
+        unsafe { slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl<T: Clone> Clone for AlignedBuffer<T> {
+    fn clone(&self) -> Self {
+        AlignedBuffer::new(self.align(), self.as_ref().to_vec())
+    }
+}
+
+impl<T> Drop for AlignedBuffer<T> {
+    fn drop(&mut self) {
+        // SAFETY: The buffer holds `len` initialized elements, allocated with
+        // `layout` where of non-zero size.
+        unsafe {
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.ptr.as_ptr(), self.len));
+            if self.layout.size() != 0 {
+                alloc::dealloc(self.ptr.as_ptr().cast(), self.layout);
+            }
+        }
+    }
+}
+
+impl<const N: usize, T> CircularArray<N, AlignedBuffer<T>, T> {
+    /// Create a new [`CircularArrayAligned`] from an iterator, with the buffer
+    /// aligned to `align` bytes. Note that only the start of the buffer is
+    /// aligned; slices pushed to an offset array may begin at any element.
+    ///
+    /// # Examples
+    /// ```
+    /// # use n_circular_array::{CircularArrayAligned, CircularIndex};
+    /// let array = CircularArrayAligned::from_iter_aligned([4, 4], 64, 0..16u32);
+    ///
+    /// assert_eq!(array.data().as_ref().as_ptr() as usize % 64, 0);
+    /// assert_eq!(array.get([1, 1]), &5);
+    /// ```
+    pub fn from_iter_aligned(
+        shape: [usize; N],
+        align: usize,
+        iter: impl Iterator<Item = T>,
+    ) -> Self {
+        CircularArray::new(shape, AlignedBuffer::new(align, iter.collect()))
+    }
+}
+
+#[cfg(feature = "allocator_api")]
+impl<const N: usize, T, Al: std::alloc::Allocator> CircularArray<N, Vec<T, Al>, T> {
+    /// Create a new `CircularArray` from an iterator, with the buffer allocated
+    /// by the given `alloc`.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(allocator_api)]
+    /// # use std::alloc::Global;
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let array = CircularArray::from_iter_in([3, 3], 0..9, Global);
+    ///
+    /// assert_eq!(array.get([1, 1]), &4);
+    /// ```
+    pub fn from_iter_in(shape: [usize; N], iter: impl Iterator<Item = T>, alloc: Al) -> Self {
+        let mut array = Vec::new_in(alloc);
+        array.extend(iter);
+
+        CircularArray::new(shape, array)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::{AlignedBuffer, CircularArrayAligned, CircularIndex, CircularMut};
+
+    #[test]
+    fn aligned() {
+        for align in [1, 16, 64, 4096] {
+            let mut m = CircularArrayAligned::from_iter_aligned([4, 3, 2], align, 0..24u8);
+            assert_eq!(m.data().as_ref().as_ptr() as usize % align, 0);
+
+            m.push_front(1, &[24; 8]);
+            assert!(m.iter().eq(m.clone().iter()));
+            assert_eq!(m.clone().data().align(), align);
+        }
+
+        let e = AlignedBuffer::new(64, Vec::<()>::new());
+        assert_eq!(e.as_ref().as_ptr() as usize % 64, 0);
+    }
+
+    #[test]
+    fn aligned_drop() {
+        let rc = Rc::new(());
+        let m = CircularArrayAligned::from_iter_aligned([2, 2], 32, (0..4).map(|_| rc.clone()));
+        assert_eq!(Rc::strong_count(&rc), 5);
+
+        drop(m);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn aligned_not_power_of_two() {
+        let _ = AlignedBuffer::new(24, vec![0u8; 4]);
+    }
+}
@@ -1,11 +1,19 @@
 use std::marker::PhantomData;
 
+use crate::array_index::CircularIndex;
+use crate::array_iter::iter_indices;
+use crate::index::RawIndexAdaptor;
+use crate::index_iter::IndexIterator;
 use crate::strides::Strides;
 
 /// A `CircularArray` backed by a `Vec`.
 pub type CircularArrayVec<const N: usize, T> = CircularArray<N, Vec<T>, T>;
 /// A `CircularArray` backed by a `Box`.
 pub type CircularArrayBox<const N: usize, T> = CircularArray<N, Box<[T]>, T>;
+/// A `CircularArray` borrowing a slice.
+pub type CircularArrayView<'a, const N: usize, T> = CircularArray<N, &'a [T], T>;
+/// A `CircularArray` mutably borrowing a slice.
+pub type CircularArrayViewMut<'a, const N: usize, T> = CircularArray<N, &'a mut [T], T>;
 
 /// A circular array of `N` dimensions for elements of type `T`.
 ///
@@ -14,6 +22,10 @@ pub type CircularArrayBox<const N: usize, T> = CircularArray<N, Box<[T]>, T>;
 ///
 /// General array data is accessible as associated methods while element access
 /// and mutation are delegated to [`CircularIndex`](crate::CircularIndex) and [`CircularMut`](crate::CircularMut).
+///
+/// Zero-sized element types such as `()` are supported, such that the array may
+/// track offsets alone. No memory is allocated for the elements of a
+/// `CircularArrayVec<N, ()>`, and all operations behave as for sized elements.
 pub struct CircularArray<const N: usize, A, T> {
     /// The circular array buffer.
     pub(crate) array: A,
@@ -21,18 +33,47 @@ pub struct CircularArray<const N: usize, A, T> {
     pub(crate) shape: [usize; N],
     /// Contiguous sections of memory for each axis.
     pub(crate) strides: Strides<N>,
+    /// The number of elements for a single slice of each axis.
+    pub(crate) slice_lens: [usize; N],
     /// The offset of each axis.
     pub(crate) offset: [usize; N],
+    /// The bitmask of each axis of power of two length, replacing modulo
+    /// reductions with a bitwise and.
+    pub(crate) masks: [Option<usize>; N],
 
     _phantom: PhantomData<T>,
 }
 
+impl<const N: usize, A: Clone, T> Clone for CircularArray<N, A, T> {
+    fn clone(&self) -> Self {
+        CircularArray {
+            array: self.array.clone(),
+            shape: self.shape,
+            strides: self.strides,
+            slice_lens: self.slice_lens,
+            offset: self.offset,
+            masks: self.masks,
+            _phantom: PhantomData,
+        }
+    }
+}
+
 impl<const N: usize, A, T> CircularArray<N, A, T>
 where
     A: AsRef<[T]>,
 {
     /// Create a new `CircularArray` from the given buffer.
     ///
+    /// An axis of length `0` creates an empty array. Empty arrays may be
+    /// compared, formatted, converted, mapped and resized, but indexing, iteration
+    /// and push operations panic. See [`CircularArray::try_new`] to reject such
+    /// shapes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the product of `shape` overflows `usize`, or does not match the
+    /// length of `array`.
+    ///
     /// # Examples
     /// ```
     /// # use n_circular_array::CircularArray;
@@ -57,19 +98,33 @@ where
     /// let circular_array = CircularArray::new_offset(shape, [1, 0, 0], array);
     /// ```
     pub fn new_offset(shape: [usize; N], offset: [usize; N], array: A) -> CircularArray<N, A, T> {
+        let len = shape
+            .iter()
+            .try_fold(1usize, |acc, len| acc.checked_mul(*len));
+        assert!(len.is_some(), "shape {:?} overflows usize", shape);
         assert!(
-            array.as_ref().len() == shape.iter().product(),
+            Some(array.as_ref().len()) == len,
             "Element length does not match shape"
         );
 
         let array = array;
         let strides = Strides::new(&shape);
+        let slice_lens = std::array::from_fn(|axis| {
+            shape
+                .iter()
+                .enumerate()
+                .fold(1, |acc, (i, sh)| if i == axis { acc } else { acc * sh })
+        });
+
+        let masks = shape.map(|len| len.is_power_of_two().then(|| len - 1));
 
         CircularArray {
             array,
             strides,
+            slice_lens,
             shape,
             offset,
+            masks,
             _phantom: PhantomData,
         }
     }
@@ -102,11 +157,79 @@ where
         &mut self.offset
     }
 
+    /// Roll the elements of the given `axis` by `n` by adjusting the offset. No
+    /// elements are moved in memory. Logical index `i` becomes index `i + n`,
+    /// wrapping at the bounds of the axis. Negative values of `n` roll towards
+    /// index `0`. See also [`CircularArray::roll_data`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let mut array = CircularArray::new([3, 2], vec![
+    ///     0, 1, 2,
+    ///     3, 4, 5,
+    /// ]);
+    ///
+    /// array.roll(0, 1);
+    /// assert_eq!(array.iter().cloned().collect::<Vec<_>>(), &[
+    ///     2, 0, 1,
+    ///     5, 3, 4,
+    /// ]);
+    ///
+    /// array.roll(1, -1);
+    /// assert_eq!(array.iter().cloned().collect::<Vec<_>>(), &[
+    ///     5, 3, 4,
+    ///     2, 0, 1,
+    /// ]);
+    /// ```
+    pub fn roll(&mut self, axis: usize, n: isize) {
+        assert_shape_index!(axis, N);
+
+        let len = self.shape[axis];
+        assert!(len > 0, "cannot roll axis {} of length 0", axis);
+
+        let n = n.rem_euclid(len as isize) as usize;
+        self.offset[axis] = (self.offset[axis] + len - n) % len;
+    }
+
+    /// Roll the elements of every axis by the given `offsets`, where `offsets[i]`
+    /// is the roll of axis `i`. Equivalent to calling [`CircularArray::roll`] for
+    /// each axis, however the new offset is computed in full before it is applied.
+    ///
+    /// # Examples
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let mut array = CircularArray::new([3, 2], vec![
+    ///     0, 1, 2,
+    ///     3, 4, 5,
+    /// ]);
+    ///
+    /// array.roll_all([1, -1]);
+    /// assert_eq!(array.iter().cloned().collect::<Vec<_>>(), &[
+    ///     5, 3, 4,
+    ///     2, 0, 1,
+    /// ]);
+    /// ```
+    pub fn roll_all(&mut self, offsets: [isize; N]) {
+        self.offset = std::array::from_fn(|i| {
+            let len = self.shape[i];
+            assert!(len > 0, "cannot roll axis {} of length 0", i);
+
+            let n = offsets[i].rem_euclid(len as isize) as usize;
+            (self.offset[i] + len - n) % len
+        });
+    }
+
     /// Get the number of elements in the array.
     pub fn len(&self) -> usize {
         self.shape.iter().product()
     }
 
+    /// Returns `true` if the array contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
     /// Get the number of elements for a single slice of the buffer, for the given
     /// `axis`. Pushing `n` slices of elements onto an axis requires `n * slice_len`
     /// elements to be passed to the respective method.
@@ -151,10 +274,13 @@ where
     /// ]);
     /// ```
     pub fn slice_len(&self, axis: usize) -> usize {
-        self.shape
-            .iter()
-            .enumerate()
-            .fold(1, |acc, (i, sh)| if i == axis { acc } else { acc * sh })
+        self.slice_lens[axis]
+    }
+
+    /// Get the number of elements for a single slice of each axis. See
+    /// [`CircularArray::slice_len`].
+    pub fn slice_lens(&self) -> &[usize; N] {
+        &self.slice_lens
     }
 
     /// Drop the `CircularArray`, returning the inner buffer. Note that data is
@@ -179,6 +305,347 @@ where
     pub fn data_mut(&mut self) -> &mut A {
         &mut self.array
     }
+
+    /// Borrow the array as a [`CircularArrayView`], retaining the shape and offset.
+    ///
+    /// # Examples
+    /// ```
+    /// # use n_circular_array::{CircularArrayVec, CircularArrayView, CircularIndex};
+    /// let array = CircularArrayVec::from_iter_offset([3, 3], [1, 0], 0..9);
+    /// let view: CircularArrayView<2, _> = array.view();
+    ///
+    /// assert_eq!(view.offset(), &[1, 0]);
+    /// assert_eq!(view.get([0, 0]), &1);
+    /// ```
+    pub fn view(&self) -> CircularArrayView<'_, N, T> {
+        CircularArray::new_offset(self.shape, self.offset, self.array.as_ref())
+    }
+
+    /// Create a new [`CircularArrayVec`] of the same shape by mapping each element
+    /// by `f`, aligned to the offset. The new array has an offset of `[0; N]`. See
+    /// also [`CircularArray::map_raw`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let array = CircularArray::new_offset([3, 2], [1, 0], vec![
+    ///     2u16, 0, 1,
+    ///     5, 3, 4,
+    /// ]);
+    /// let mapped = array.map(|el| *el as f32 * 0.5);
+    ///
+    /// assert_eq!(mapped.offset(), &[0, 0]);
+    /// assert_eq!(mapped.data(), &[
+    ///     0.0, 0.5, 1.0,
+    ///     1.5, 2.0, 2.5,
+    /// ]);
+    /// ```
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> CircularArrayVec<N, U> {
+        if self.is_empty() {
+            return CircularArray::new(self.shape, vec![]);
+        }
+
+        let spans = IndexIterator::new_bound_contiguous(self.spans());
+        let array = spans
+            .into_flat_ranges(&self.strides)
+            .flat_map(|range| &self.array.as_ref()[range])
+            .map(f)
+            .collect::<Vec<_>>();
+
+        CircularArray::new(self.shape, array)
+    }
+
+    /// Create a new [`CircularArrayVec`] of the same shape by mapping each element
+    /// by `f` in raw order. The new array has the offset of this array. This is
+    /// faster than [`CircularArray::map`] where the offset need not be normalized.
+    ///
+    /// # Examples
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let array = CircularArray::new_offset([3, 2], [1, 0], vec![
+    ///     2u16, 0, 1,
+    ///     5, 3, 4,
+    /// ]);
+    /// let mapped = array.map_raw(|el| *el as f32 * 0.5);
+    ///
+    /// assert_eq!(mapped.offset(), &[1, 0]);
+    /// assert_eq!(mapped.iter().cloned().collect::<Vec<_>>(), &[
+    ///     0.0, 0.5, 1.0,
+    ///     1.5, 2.0, 2.5,
+    /// ]);
+    /// ```
+    pub fn map_raw<U, F: FnMut(&T) -> U>(&self, f: F) -> CircularArrayVec<N, U> {
+        let array = self.array.as_ref().iter().map(f).collect::<Vec<_>>();
+
+        CircularArray::new_offset(self.shape, self.offset, array)
+    }
+}
+
+impl<const N: usize, A, T> CircularArray<N, A, T>
+where
+    A: AsRef<[T]> + AsMut<[T]>,
+{
+    /// Mutably borrow the array as a [`CircularArrayViewMut`], retaining the shape
+    /// and offset. Note that offset changes made through the view (for example, by
+    /// pushing elements) are **not** reflected in the borrowed array.
+    ///
+    /// # Examples
+    /// ```
+    /// # use n_circular_array::{CircularArrayVec, CircularIndex, CircularMut};
+    /// let mut array = CircularArrayVec::from_iter_offset([3, 3], [1, 0], 0..9);
+    /// *array.view_mut().get_mut([0, 0]) = 10;
+    ///
+    /// assert_eq!(array.get([0, 0]), &10);
+    /// ```
+    pub fn view_mut(&mut self) -> CircularArrayViewMut<'_, N, T> {
+        CircularArray::new_offset(self.shape, self.offset, self.array.as_mut())
+    }
+
+    /// Roll the elements of the given `axis` by `n`, rotating elements in memory.
+    /// The offset is unchanged. The resulting logical order is equal to that of
+    /// [`CircularArray::roll`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let mut array = CircularArray::new([3, 2], vec![
+    ///     0, 1, 2,
+    ///     3, 4, 5,
+    /// ]);
+    /// array.roll_data(0, 1);
+    ///
+    /// assert_eq!(array.offset(), &[0, 0]);
+    /// assert_eq!(array.data(), &[
+    ///     2, 0, 1,
+    ///     5, 3, 4,
+    /// ]);
+    /// ```
+    pub fn roll_data(&mut self, axis: usize, n: isize) {
+        assert_shape_index!(axis, N);
+
+        let len = self.shape[axis];
+        assert!(len > 0, "cannot roll axis {} of length 0", axis);
+
+        let n = n.rem_euclid(len as isize) as usize;
+        let stride = self.strides[axis];
+
+        if n != 0 {
+            self.array
+                .as_mut()
+                .chunks_exact_mut(stride * len)
+                .for_each(|block| block.rotate_right(n * stride));
+        }
+    }
+
+    /// Reset every element to `T::default()` and the offset to `[0; N]`, retaining
+    /// the underlying buffer.
+    ///
+    /// # Examples
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let mut array = CircularArray::new_offset([3, 2], [1, 1], vec![
+    ///     5, 3, 4,
+    ///     2, 0, 1,
+    /// ]);
+    /// array.clear_default();
+    ///
+    /// assert_eq!(array.offset(), &[0, 0]);
+    /// assert_eq!(array.data(), &[
+    ///     0, 0, 0,
+    ///     0, 0, 0,
+    /// ]);
+    /// ```
+    pub fn clear_default(&mut self)
+    where
+        T: Default,
+    {
+        self.array.as_mut().fill_with(T::default);
+        self.offset = [0; N];
+    }
+}
+
+impl<const N: usize, A, T> CircularArray<N, A, T>
+where
+    A: AsRef<[T]>,
+    T: Clone,
+{
+    /// Create a new [`CircularArrayVec`] with the axes reordered by `axes`, where
+    /// axis `i` of the new array is axis `axes[i]` of this array. The logical
+    /// content and offset of each axis is preserved.
+    ///
+    /// # Examples
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let array = CircularArray::new_offset([3, 2], [1, 0], vec![
+    ///     2, 0, 1,
+    ///     5, 3, 4,
+    /// ]);
+    /// let permuted = array.permute_axes([1, 0]);
+    ///
+    /// assert_eq!(permuted.shape(), &[2, 3]);
+    /// assert_eq!(permuted.offset(), &[0, 1]);
+    /// assert_eq!(permuted.iter().cloned().collect::<Vec<_>>(), &[
+    ///     0, 3,
+    ///     1, 4,
+    ///     2, 5,
+    /// ]);
+    /// ```
+    pub fn permute_axes(&self, axes: [usize; N]) -> CircularArrayVec<N, T> {
+        assert_permutation!(axes, N);
+
+        let shape = axes.map(|axis| self.shape[axis]);
+        let offset = axes.map(|axis| self.offset[axis]);
+        let strides = axes.map(|axis| self.strides[axis]);
+        let array = self.array.as_ref();
+
+        CircularArrayVec::from_iter_offset(
+            shape,
+            offset,
+            iter_indices(shape).map(|index| {
+                let i = index
+                    .iter()
+                    .zip(strides)
+                    .map(|(idx, stride)| idx * stride)
+                    .sum::<usize>();
+                array[i].clone()
+            }),
+        )
+    }
+
+    /// Create a new [`CircularArrayVec`] of the given `shape` from the elements
+    /// of this array, aligned to the offset. The new array has an offset of `[0; M]`.
+    /// Elements are copied in contiguous slices where possible.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of elements of `shape` does not match the array length.
+    ///
+    /// # Examples
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let array = CircularArray::new_offset([3, 2], [1, 0], vec![
+    ///     2, 0, 1,
+    ///     5, 3, 4,
+    /// ]);
+    /// let reshaped = array.reshape([2, 3]);
+    ///
+    /// assert_eq!(reshaped.offset(), &[0, 0]);
+    /// assert_eq!(reshaped.data(), &[
+    ///     0, 1,
+    ///     2, 3,
+    ///     4, 5,
+    /// ]);
+    /// ```
+    pub fn reshape<const M: usize>(&self, shape: [usize; M]) -> CircularArrayVec<M, T> {
+        assert!(
+            shape.iter().product::<usize>() == self.len(),
+            "shape {:?} does not match array length {}",
+            shape,
+            self.len()
+        );
+
+        CircularArray::new(shape, self.to_vec())
+    }
+
+    /// Copy the elements of the array into a `Vec`, aligned to the offset. Elements
+    /// are copied in contiguous slices where possible. See also
+    /// [`CircularArray::into_vec`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use n_circular_array::CircularArray;
+    /// let array = CircularArray::new_offset([3, 2], [1, 0], vec![
+    ///     2, 0, 1,
+    ///     5, 3, 4,
+    /// ]);
+    ///
+    /// assert_eq!(array.to_vec(), &[
+    ///     0, 1, 2,
+    ///     3, 4, 5,
+    /// ]);
+    /// ```
+    pub fn to_vec(&self) -> Vec<T> {
+        let mut array = Vec::with_capacity(self.len());
+        if !self.is_empty() {
+            IndexIterator::new_bound_contiguous(self.spans())
+                .into_flat_ranges(&self.strides)
+                .for_each(|range| array.extend_from_slice(&self.array.as_ref()[range]));
+        }
+
+        array
+    }
+
+    /// Create a new [`CircularArrayVec`] with the given `axis` moved to the last
+    /// (outermost) axis, such that slices of `axis` are contiguous and cheapest to
+    /// push. Remaining axes retain their relative order. The new array has an
+    /// offset of `[0; N]`. See [`CircularArray::into_ordered`] to store the array
+    /// in memory order while retaining the axis numbering.
+    ///
+    /// # Examples
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let array = CircularArray::new_offset([3, 2, 2], [1, 0, 1], vec![
+    ///      8,  6,  7,
+    ///     11,  9, 10,
+    ///
+    ///      2,  0,  1,
+    ///      5,  3,  4,
+    /// ]);
+    /// let reordered = array.reorder_for_axis(0);
+    ///
+    /// assert_eq!(reordered.shape(), &[2, 2, 3]);
+    /// assert_eq!(reordered.offset(), &[0, 0, 0]);
+    /// assert_eq!(reordered.data(), &[
+    ///     0, 3,
+    ///     6, 9,
+    ///
+    ///     1, 4,
+    ///     7, 10,
+    ///
+    ///     2, 5,
+    ///     8, 11,
+    /// ]);
+    /// ```
+    pub fn reorder_for_axis(&self, axis: usize) -> CircularArrayVec<N, T> {
+        assert_shape_index!(axis, N);
+
+        let axes: [usize; N] = std::array::from_fn(|i| match i {
+            i if i + 1 == N => axis,
+            i if i < axis => i,
+            i => i + 1,
+        });
+        let view = self.permuted_view(axes);
+
+        CircularArrayVec::from_iter(*view.shape(), view.iter().cloned())
+    }
+
+    /// Create a new [`CircularArrayVec`] holding a copy of the elements of this
+    /// array, aligned to the offset. The new array has an offset of `[0; N]`,
+    /// rotating elements during the copy. See also [`Clone`], which retains the
+    /// offset.
+    ///
+    /// # Examples
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let array = CircularArray::new_offset([3, 2], [1, 0], vec![
+    ///     2, 0, 1,
+    ///     5, 3, 4,
+    /// ]);
+    /// let normalized = array.clone_normalized();
+    ///
+    /// assert_eq!(normalized.offset(), &[0, 0]);
+    /// assert_eq!(normalized.data(), &[
+    ///     0, 1, 2,
+    ///     3, 4, 5,
+    /// ]);
+    /// ```
+    pub fn clone_normalized(&self) -> CircularArrayVec<N, T> {
+        if self.is_empty() {
+            return CircularArray::new(self.shape, vec![]);
+        }
+
+        self.reshape(self.shape)
+    }
 }
 
 impl<const N: usize, T> CircularArray<N, Vec<T>, T> {
@@ -246,3 +713,186 @@ impl<const N: usize, T> CircularArray<N, Box<[T]>, T> {
         Self::new_offset(shape, offset, array)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{CircularArrayVec, CircularIndex};
+
+    #[test]
+    fn slice_lens() {
+        let m = CircularArrayVec::from_iter([4, 3, 2], 0..24);
+
+        assert_eq!(m.slice_lens(), &[6, 8, 12]);
+        assert_eq!(m.slice_len(1), 8);
+    }
+
+    #[test]
+    fn roll() {
+        let shape = [4, 3, 2];
+        let m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..shape.iter().product());
+
+        for axis in 0..3 {
+            for n in -5..5 {
+                let mut rolled = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..24);
+                let mut rolled_data = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..24);
+                rolled.roll(axis, n);
+                rolled_data.roll_data(axis, n);
+
+                assert_eq!(rolled_data.offset(), m.offset());
+                assert_eq!(
+                    rolled.iter().collect::<Vec<_>>(),
+                    rolled_data.iter().collect::<Vec<_>>()
+                );
+
+                let len = m.shape()[axis] as isize;
+                let i = (1 + n).rem_euclid(len) as usize;
+                assert_eq!(
+                    rolled.iter_index(axis, i).collect::<Vec<_>>(),
+                    m.iter_index(axis, 1).collect::<Vec<_>>()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn roll_all() {
+        let shape = [4, 3, 2];
+        let mut m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..shape.iter().product());
+        let mut expected =
+            CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..shape.iter().product());
+
+        for offsets in [[1, -1, 3], [-9, 4, 0], [0, 0, -1]] {
+            m.roll_all(offsets);
+            (0..3).for_each(|axis| expected.roll(axis, offsets[axis]));
+
+            assert_eq!(m.offset(), expected.offset());
+        }
+    }
+
+    #[test]
+    fn clear_default() {
+        let shape = [4, 3, 2];
+        let mut m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 1..25);
+        let ptr = m.data().as_ptr();
+        m.clear_default();
+
+        assert_eq!(m.offset(), &[0; 3]);
+        assert_eq!(m.data().as_ptr(), ptr);
+        assert!(m.iter().all(|el| *el == 0));
+    }
+
+    #[test]
+    fn map() {
+        let shape = [4, 3, 2];
+        let m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..shape.iter().product());
+        let expected = m.iter().map(|el| el * 2).collect::<Vec<_>>();
+
+        let mapped = m.map(|el| el * 2);
+        assert_eq!(mapped.offset(), &[0; 3]);
+        assert_eq!(mapped.data(), &expected);
+
+        let mapped = m.map_raw(|el| el * 2);
+        assert_eq!(mapped.offset(), m.offset());
+        assert_eq!(mapped.iter().cloned().collect::<Vec<_>>(), expected);
+
+        let e = CircularArrayVec::<3, usize>::from_iter([4, 0, 2], 0..0);
+        assert_eq!(e.map(|el| el * 2).shape(), &[4, 0, 2]);
+    }
+
+    #[test]
+    fn permute_axes() {
+        let shape = [4, 3, 2];
+        let m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..shape.iter().product());
+        let p = m.permute_axes([2, 0, 1]);
+
+        assert_eq!(p.shape(), &[2, 4, 3]);
+        assert_eq!(p.offset(), &[1, 1, 2]);
+        m.indexed_iter()
+            .for_each(|([x, y, z], el)| assert_eq!(p.get([z, x, y]), el));
+        assert_eq!(p.permute_axes([1, 2, 0]).data(), m.data());
+    }
+
+    #[test]
+    fn reshape() {
+        let shape = [4, 3, 2];
+        let m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..shape.iter().product());
+        let r = m.reshape([6, 4]);
+
+        assert_eq!(r.shape(), &[6, 4]);
+        assert_eq!(r.offset(), &[0, 0]);
+        assert_eq!(r.data(), &m.iter().cloned().collect::<Vec<_>>());
+        assert_eq!(r.reshape([24]).data(), r.data());
+    }
+
+    #[test]
+    fn reorder_for_axis() {
+        let shape = [4, 3, 2];
+        let m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..shape.iter().product());
+
+        for (axis, axes) in [(0, [1, 2, 0]), (1, [0, 2, 1]), (2, [0, 1, 2])] {
+            let r = m.reorder_for_axis(axis);
+            let p = m.permute_axes(axes);
+
+            assert_eq!(r.shape(), p.shape());
+            assert_eq!(r.offset(), &[0; 3]);
+            assert!(r.iter().eq(p.iter()));
+        }
+    }
+
+    #[test]
+    fn masks() {
+        let mut m = CircularArrayVec::from_iter_offset([4, 3, 1], [3, 2, 0], 0..12);
+        assert_eq!(m.masks, [Some(3), None, Some(0)]);
+
+        m.incr_offset(0, 3);
+        m.decr_offset(1, 1);
+        assert_eq!(m.offset(), &[2, 1, 0]);
+        assert_eq!(m.get([3, 2, 0]), &1);
+    }
+
+    #[test]
+    fn clone_normalized() {
+        let shape = [4, 3, 2];
+        let m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..shape.iter().product());
+
+        let c = m.clone();
+        assert_eq!(c.offset(), m.offset());
+        assert_eq!(c.data(), m.data());
+
+        let n = m.clone_normalized();
+        assert_eq!(n.offset(), &[0; 3]);
+        assert!(n.iter().eq(m.iter()));
+        assert!(n.iter_raw().eq(m.iter()));
+
+        let e = CircularArrayVec::<2, i32>::from_iter([0, 2], 0..0);
+        assert_eq!(e.clone_normalized().shape(), &[0, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot roll axis 1 of length 0")]
+    fn roll_empty() {
+        let mut m = CircularArrayVec::<2, i32>::from_iter([2, 0], 0..0);
+        m.roll(1, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot roll axis 1 of length 0")]
+    fn roll_data_empty() {
+        let mut m = CircularArrayVec::<2, i32>::from_iter([2, 0], 0..0);
+        m.roll_data(1, 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn reshape_len() {
+        let m = CircularArrayVec::from_iter([3, 3], 0..9);
+        m.reshape([4, 2]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn permute_axes_repeated() {
+        let m = CircularArrayVec::from_iter([3, 3], 0..9);
+        m.permute_axes([0, 0]);
+    }
+}
@@ -0,0 +1,457 @@
+use std::ops::{Index, IndexMut, Range};
+
+use crate::array_index::CircularIndex;
+use crate::array_mut::CircularMut;
+use crate::{CircularArray, CircularArrayVec, CircularArrayView};
+
+/// Named operations for 2-dimensional arrays.
+///
+/// Axis `0` indexes the **columns** of the array, and axis `1` indexes the
+/// **rows**. A row therefore contains `shape()[0]` elements, and a column contains
+/// `shape()[1]` elements.
+impl<A: AsRef<[T]>, T> CircularArray<2, A, T> {
+    /// Get the number of rows (the length of axis `1`).
+    pub fn n_rows(&self) -> usize {
+        self.shape[1]
+    }
+
+    /// Get the number of columns (the length of axis `0`).
+    pub fn n_cols(&self) -> usize {
+        self.shape[0]
+    }
+
+    /// Iterate over the elements of the row at `index`, aligned to the offset.
+    /// Equivalent to [`CircularIndex::iter_index`] for axis `1`.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::CircularArray;
+    /// let array = CircularArray::new_offset([3, 3], [1, 1], vec![
+    ///     8, 6, 7,
+    ///     2, 0, 1,
+    ///     5, 3, 4
+    /// ]);
+    /// assert_eq!(array.row(0).cloned().collect::<Vec<_>>(), &[0, 1, 2]);
+    /// ```
+    pub fn row(&self, index: usize) -> impl ExactSizeIterator<Item = &T> {
+        self.iter_index(1, index)
+    }
+
+    /// Iterate over the elements of the column at `index`, aligned to the offset.
+    /// Equivalent to [`CircularIndex::iter_index`] for axis `0`.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::CircularArray;
+    /// let array = CircularArray::new_offset([3, 3], [1, 1], vec![
+    ///     8, 6, 7,
+    ///     2, 0, 1,
+    ///     5, 3, 4
+    /// ]);
+    /// assert_eq!(array.col(0).cloned().collect::<Vec<_>>(), &[0, 3, 6]);
+    /// ```
+    pub fn col(&self, index: usize) -> impl ExactSizeIterator<Item = &T> {
+        self.iter_index(0, index)
+    }
+
+    /// Iterate over all rows of the array, aligned to the offset.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::CircularArray;
+    /// let array = CircularArray::new_offset([3, 2], [1, 1], vec![
+    ///     5, 3, 4,
+    ///     2, 0, 1,
+    /// ]);
+    /// let rows = array
+    ///     .rows()
+    ///     .map(|row| row.cloned().collect::<Vec<_>>())
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(rows, [[0, 1, 2], [3, 4, 5]]);
+    /// ```
+    pub fn rows(&self) -> impl ExactSizeIterator<Item = impl ExactSizeIterator<Item = &T>> {
+        self.iter_axis(1)
+    }
+
+    /// Iterate over all columns of the array, aligned to the offset.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::CircularArray;
+    /// let array = CircularArray::new_offset([3, 2], [1, 1], vec![
+    ///     5, 3, 4,
+    ///     2, 0, 1,
+    /// ]);
+    /// let cols = array
+    ///     .cols()
+    ///     .map(|col| col.cloned().collect::<Vec<_>>())
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(cols, [[0, 3], [1, 4], [2, 5]]);
+    /// ```
+    pub fn cols(&self) -> impl ExactSizeIterator<Item = impl ExactSizeIterator<Item = &T>> {
+        self.iter_axis(0)
+    }
+
+    /// Iterate over the elements of the rows within `range`, aligned to the offset.
+    /// Equivalent to [`CircularIndex::iter_range`] for axis `1`.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::CircularArray;
+    /// let array = CircularArray::new_offset([3, 3], [1, 1], vec![
+    ///     8, 6, 7,
+    ///     2, 0, 1,
+    ///     5, 3, 4
+    /// ]);
+    /// assert_eq!(array.rows_range(1..3).cloned().collect::<Vec<_>>(), &[3, 4, 5, 6, 7, 8]);
+    /// ```
+    pub fn rows_range(&self, range: Range<usize>) -> impl ExactSizeIterator<Item = &T> {
+        self.iter_range(1, range)
+    }
+
+    /// Iterate over the elements of the columns within `range`, aligned to the
+    /// offset. Equivalent to [`CircularIndex::iter_range`] for axis `0`.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::CircularArray;
+    /// let array = CircularArray::new_offset([3, 3], [1, 1], vec![
+    ///     8, 6, 7,
+    ///     2, 0, 1,
+    ///     5, 3, 4
+    /// ]);
+    /// assert_eq!(array.cols_range(0..2).cloned().collect::<Vec<_>>(), &[0, 1, 3, 4, 6, 7]);
+    /// ```
+    pub fn cols_range(&self, range: Range<usize>) -> impl ExactSizeIterator<Item = &T> {
+        self.iter_range(0, range)
+    }
+
+    /// Get a 1-dimensional [`CircularArrayView`] of the row at `index`, aligned to
+    /// the offset. The view borrows the row elements, and retains the offset of
+    /// axis `0`.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let array = CircularArray::new_offset([3, 2], [1, 1], vec![
+    ///     5, 3, 4,
+    ///     2, 0, 1,
+    /// ]);
+    /// let row = array.row_view(0);
+    ///
+    /// assert_eq!(row.shape(), &[3]);
+    /// assert_eq!(row.get([2]), &2);
+    /// assert_eq!(row.iter().cloned().collect::<Vec<_>>(), &[0, 1, 2]);
+    /// ```
+    pub fn row_view(&self, index: usize) -> CircularArrayView<'_, 1, T> {
+        let axis = 1;
+        assert_slice_index!(self, axis, index);
+
+        let raw = (index + self.offset[axis]) % self.shape[axis];
+        let len = self.strides[axis];
+
+        CircularArray::new_offset(
+            [self.shape[0]],
+            [self.offset[0]],
+            &self.array.as_ref()[raw * len..(raw + 1) * len],
+        )
+    }
+
+    /// Iterate over 1-dimensional views of all rows of the array, aligned to the
+    /// offset. See [`CircularArray::row_view`].
+    pub fn row_views(&self) -> impl ExactSizeIterator<Item = CircularArrayView<'_, 1, T>> {
+        (0..self.n_rows()).map(|i| self.row_view(i))
+    }
+}
+
+impl<A: AsRef<[T]> + AsMut<[T]>, T: Clone> CircularArray<2, A, T> {
+    /// Iterate mutably over the elements of the row at `index`, aligned to the
+    /// offset. Equivalent to [`CircularArray::iter_index_mut`] for axis `1`.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let mut array = CircularArray::new_offset([3, 3], [1, 1], vec![
+    ///     8, 6, 7,
+    ///     2, 0, 1,
+    ///     5, 3, 4
+    /// ]);
+    /// array.row_mut(0).for_each(|el| *el *= 10);
+    ///
+    /// assert_eq!(array.iter().cloned().collect::<Vec<_>>(), &[
+    ///     0, 10, 20,
+    ///     3,  4,  5,
+    ///     6,  7,  8
+    /// ]);
+    /// ```
+    pub fn row_mut(&mut self, index: usize) -> impl ExactSizeIterator<Item = &mut T> {
+        self.iter_index_mut(1, index)
+    }
+
+    /// Iterate mutably over the elements of the column at `index`, aligned to the
+    /// offset. Equivalent to [`CircularArray::iter_index_mut`] for axis `0`.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let mut array = CircularArray::new_offset([3, 3], [1, 1], vec![
+    ///     8, 6, 7,
+    ///     2, 0, 1,
+    ///     5, 3, 4
+    /// ]);
+    /// array.col_mut(0).for_each(|el| *el += 10);
+    ///
+    /// assert_eq!(array.iter().cloned().collect::<Vec<_>>(), &[
+    ///     10, 1, 2,
+    ///     13, 4, 5,
+    ///     16, 7, 8
+    /// ]);
+    /// ```
+    pub fn col_mut(&mut self, index: usize) -> impl ExactSizeIterator<Item = &mut T> {
+        self.iter_index_mut(0, index)
+    }
+
+    /// Push rows to the front of the array. Elements must be an exact multiple of
+    /// the row length. Equivalent to [`CircularMut::push_front`] for axis `1`.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let mut array = CircularArray::new([3, 3], vec![
+    ///     0, 1, 2,
+    ///     3, 4, 5,
+    ///     6, 7, 8
+    /// ]);
+    /// array.push_row(&[9, 10, 11]);
+    ///
+    /// assert_eq!(array.iter().cloned().collect::<Vec<_>>(), &[
+    ///     3,  4,  5,
+    ///     6,  7,  8,
+    ///     9, 10, 11
+    /// ]);
+    /// ```
+    pub fn push_row(&mut self, el: &[T]) {
+        self.push_front(1, el);
+    }
+
+    /// Push columns to the front of the array. Elements must be an exact multiple
+    /// of the column length. Equivalent to [`CircularMut::push_front`] for axis `0`.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let mut array = CircularArray::new([3, 3], vec![
+    ///     0, 1, 2,
+    ///     3, 4, 5,
+    ///     6, 7, 8
+    /// ]);
+    /// array.push_col(&[9, 10, 11]);
+    ///
+    /// assert_eq!(array.iter().cloned().collect::<Vec<_>>(), &[
+    ///     1, 2,  9,
+    ///     4, 5, 10,
+    ///     7, 8, 11
+    /// ]);
+    /// ```
+    pub fn push_col(&mut self, el: &[T]) {
+        self.push_front(0, el);
+    }
+}
+
+impl<A: AsRef<[T]>, T: Clone> CircularArray<2, A, T> {
+    /// Create a new [`CircularArrayVec`] with rows and columns swapped. Equivalent
+    /// to [`CircularArray::permute_axes`] with axes `[1, 0]`.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let array = CircularArray::new([3, 2], vec![
+    ///     0, 1, 2,
+    ///     3, 4, 5,
+    /// ]);
+    ///
+    /// assert_eq!(array.transposed().iter().cloned().collect::<Vec<_>>(), &[
+    ///     0, 3,
+    ///     1, 4,
+    ///     2, 5,
+    /// ]);
+    /// ```
+    pub fn transposed(&self) -> CircularArrayVec<2, T> {
+        self.permute_axes([1, 0])
+    }
+}
+
+/// Index the array by a tuple of indices, aligned to the offset. Equivalent to
+/// indexing by `[x, y]`.
+///
+/// # Example
+/// ```
+/// # use n_circular_array::CircularArray;
+/// let mut array = CircularArray::new_offset([3, 2], [1, 0], vec![
+///     2, 0, 1,
+///     5, 3, 4,
+/// ]);
+/// array[(2, 1)] = 9;
+///
+/// assert_eq!(array[(0, 0)], 0);
+/// assert_eq!(array[(2, 1)], array[[2, 1]]);
+/// ```
+impl<A: AsRef<[T]>, T> Index<(usize, usize)> for CircularArray<2, A, T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        self.get([x, y])
+    }
+}
+
+impl<A: AsRef<[T]> + AsMut<[T]>, T: Clone> IndexMut<(usize, usize)> for CircularArray<2, A, T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        self.get_mut([x, y])
+    }
+}
+
+/// Create a [`CircularArrayVec`] from nested arrays of rows, where each inner array
+/// is a row. The shape is `[X, Y]` (`X` columns, `Y` rows).
+///
+/// # Example
+/// ```
+/// # use n_circular_array::{CircularArrayVec, CircularIndex};
+/// let array = CircularArrayVec::from([
+///     [0, 1, 2],
+///     [3, 4, 5],
+/// ]);
+///
+/// assert_eq!(array.shape(), &[3, 2]);
+/// assert_eq!(array.data(), &[0, 1, 2, 3, 4, 5]);
+/// ```
+impl<const X: usize, const Y: usize, T> From<[[T; X]; Y]> for CircularArrayVec<2, T> {
+    fn from(rows: [[T; X]; Y]) -> Self {
+        CircularArray::new([X, Y], rows.into_iter().flatten().collect())
+    }
+}
+
+/// Create a [`CircularArrayVec`] from a `Vec` of rows. The shape is
+/// `[rows[0].len(), rows.len()]`.
+///
+/// # Panics
+///
+/// Panics if the rows are not of equal length.
+///
+/// # Example
+/// ```
+/// # use n_circular_array::{CircularArrayVec, CircularIndex};
+/// let array = CircularArrayVec::from(vec![
+///     vec![0, 1, 2],
+///     vec![3, 4, 5],
+/// ]);
+///
+/// assert_eq!(array.shape(), &[3, 2]);
+/// assert_eq!(array.data(), &[0, 1, 2, 3, 4, 5]);
+/// ```
+impl<T> From<Vec<Vec<T>>> for CircularArrayVec<2, T> {
+    fn from(rows: Vec<Vec<T>>) -> Self {
+        let n_cols = rows.first().map_or(0, Vec::len);
+        rows.iter().enumerate().for_each(|(i, row)| {
+            assert!(
+                row.len() == n_cols,
+                "row {} of length {} does not match row length {}",
+                i,
+                row.len(),
+                n_cols
+            )
+        });
+
+        CircularArray::new([n_cols, rows.len()], rows.into_iter().flatten().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CircularArrayVec, CircularIndex};
+
+    #[test]
+    fn rows_cols() {
+        let mut m = CircularArrayVec::from_iter_offset([4, 3], [1, 2], 0..12);
+
+        #[rustfmt::skip]
+        assert_eq!(m.rows().map(|row| row.cloned().collect::<Vec<_>>()).collect::<Vec<_>>(), [
+            [ 9, 10, 11,  8],
+            [ 1,  2,  3,  0],
+            [ 5,  6,  7,  4],
+        ]);
+        #[rustfmt::skip]
+        assert_eq!(m.cols().map(|col| col.cloned().collect::<Vec<_>>()).collect::<Vec<_>>(), [
+            [ 9, 1, 5],
+            [10, 2, 6],
+            [11, 3, 7],
+            [ 8, 0, 4],
+        ]);
+
+        m.row_mut(2).for_each(|el| *el = 0);
+        m.col_mut(3).for_each(|el| *el = 1);
+        #[rustfmt::skip]
+        assert_eq!(m.rows().map(|row| row.cloned().collect::<Vec<_>>()).collect::<Vec<_>>(), [
+            [ 9, 10, 11,  1],
+            [ 1,  2,  3,  1],
+            [ 0,  0,  0,  1],
+        ]);
+    }
+
+    #[test]
+    fn row_views() {
+        let m = CircularArrayVec::from_iter_offset([4, 3], [1, 2], 0..12);
+
+        assert_eq!(m.row_views().len(), 3);
+        for (i, view) in m.row_views().enumerate() {
+            assert!(view.iter().eq(m.row(i)));
+        }
+        assert!(m.rows_range(1..3).eq(m.row(1).chain(m.row(2))));
+        assert!(m.cols_range(2..4).eq(m.rows().flat_map(|row| row.skip(2))));
+    }
+
+    #[test]
+    fn push_row_col() {
+        let mut m = CircularArrayVec::from_iter([3, 2], 0..6);
+
+        m.push_row(&[6, 7, 8]);
+        m.push_col(&[9, 10]);
+        #[rustfmt::skip]
+        assert_eq!(m.rows().map(|row| row.cloned().collect::<Vec<_>>()).collect::<Vec<_>>(), [
+            [4, 5,  9],
+            [7, 8, 10],
+        ]);
+    }
+
+    #[test]
+    fn from_nested() {
+        let rows = [[0, 1, 2, 3], [4, 5, 6, 7], [8, 9, 10, 11]];
+        let expected = CircularArrayVec::from_iter([4, 3], 0..12);
+
+        let m = CircularArrayVec::from(rows);
+        assert_eq!(m.shape(), expected.shape());
+        assert_eq!(m.data(), expected.data());
+
+        let m = CircularArrayVec::from(rows.map(Vec::from).to_vec());
+        assert_eq!(m.shape(), expected.shape());
+        assert_eq!(m.data(), expected.data());
+
+        assert_eq!(CircularArrayVec::<2, u8>::from(vec![]).shape(), &[0, 0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_nested_ragged() {
+        let _ = CircularArrayVec::from(vec![vec![0, 1], vec![2]]);
+    }
+
+    #[test]
+    fn index_tuple() {
+        let mut m = CircularArrayVec::from_iter_offset([4, 3], [1, 2], 0..12);
+
+        assert_eq!(m[(1, 2)], m[[1, 2]]);
+        m[(3, 0)] = 99;
+        assert_eq!(m[[3, 0]], 99);
+    }
+}
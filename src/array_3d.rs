@@ -0,0 +1,386 @@
+use std::ops::{Index, IndexMut, Range};
+
+use crate::array_index::CircularIndex;
+use crate::array_mut::CircularMut;
+use crate::{CircularArray, CircularArrayVec, CircularArrayView};
+
+/// Named operations for 3-dimensional arrays.
+///
+/// Axis `2` (the outermost axis) indexes the **layers** of the array. Each layer
+/// is a 2-dimensional array of shape `[shape()[0], shape()[1]]`, stored contiguously.
+impl<A: AsRef<[T]>, T> CircularArray<3, A, T> {
+    /// Get the number of layers (the length of axis `2`).
+    pub fn n_layers(&self) -> usize {
+        self.shape[2]
+    }
+
+    /// Iterate over the elements of the layer at `index`, aligned to the offset.
+    /// Equivalent to [`CircularIndex::iter_index`] for axis `2`.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::CircularArray;
+    /// let array = CircularArray::new_offset([2, 2, 2], [0, 0, 1], vec![
+    ///     4, 5,
+    ///     6, 7,
+    ///
+    ///     0, 1,
+    ///     2, 3,
+    /// ]);
+    /// assert_eq!(array.layer(0).cloned().collect::<Vec<_>>(), &[0, 1, 2, 3]);
+    /// ```
+    pub fn layer(&self, index: usize) -> impl ExactSizeIterator<Item = &T> {
+        self.iter_index(2, index)
+    }
+
+    /// Iterate over all layers of the array, aligned to the offset.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::CircularArray;
+    /// let array = CircularArray::new_offset([2, 2, 2], [0, 0, 1], vec![
+    ///     4, 5,
+    ///     6, 7,
+    ///
+    ///     0, 1,
+    ///     2, 3,
+    /// ]);
+    /// let layers = array
+    ///     .layers()
+    ///     .map(|layer| layer.cloned().collect::<Vec<_>>())
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(layers, [[0, 1, 2, 3], [4, 5, 6, 7]]);
+    /// ```
+    pub fn layers(&self) -> impl ExactSizeIterator<Item = impl ExactSizeIterator<Item = &T>> {
+        self.iter_axis(2)
+    }
+
+    /// Get a 2-dimensional view of the layer at `index`, aligned to the offset.
+    /// The view borrows the layer elements, and retains the offset of axes `0`
+    /// and `1`.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let array = CircularArray::new_offset([2, 2, 2], [1, 0, 1], vec![
+    ///     5, 4,
+    ///     7, 6,
+    ///
+    ///     1, 0,
+    ///     3, 2,
+    /// ]);
+    /// let layer = array.layer_view(1);
+    ///
+    /// assert_eq!(layer.shape(), &[2, 2]);
+    /// assert_eq!(layer.get([1, 0]), &5);
+    /// assert_eq!(layer.rows().map(|row| row.cloned().collect::<Vec<_>>()).collect::<Vec<_>>(), [
+    ///     [4, 5],
+    ///     [6, 7],
+    /// ]);
+    /// ```
+    pub fn layer_view(&self, index: usize) -> CircularArrayView<'_, 2, T> {
+        let axis = 2;
+        assert_slice_index!(self, axis, index);
+
+        let raw = (index + self.offset[axis]) % self.shape[axis];
+        let len = self.strides[axis];
+
+        CircularArray::new_offset(
+            [self.shape[0], self.shape[1]],
+            [self.offset[0], self.offset[1]],
+            &self.array.as_ref()[raw * len..(raw + 1) * len],
+        )
+    }
+
+    /// Iterate over 2-dimensional views of all layers of the array, aligned to
+    /// the offset. See [`CircularArray::layer_view`].
+    pub fn layer_views(&self) -> impl ExactSizeIterator<Item = CircularArrayView<'_, 2, T>> {
+        (0..self.n_layers()).map(|i| self.layer_view(i))
+    }
+
+    /// Iterate over the elements of the layers within `range`, aligned to the
+    /// offset. Equivalent to [`CircularIndex::iter_range`] for axis `2`.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::CircularArray;
+    /// let array = CircularArray::new_offset([2, 1, 3], [0, 0, 1], vec![
+    ///     4, 5,
+    ///
+    ///     0, 1,
+    ///
+    ///     2, 3,
+    /// ]);
+    /// assert_eq!(array.slab(1..3).cloned().collect::<Vec<_>>(), &[2, 3, 4, 5]);
+    /// ```
+    pub fn slab(&self, range: Range<usize>) -> impl ExactSizeIterator<Item = &T> {
+        self.iter_range(2, range)
+    }
+
+    /// Iterate over 2-dimensional views of the layers within `range`, aligned to
+    /// the offset. See [`CircularArray::layer_view`].
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let array = CircularArray::new_offset([2, 1, 3], [0, 0, 1], vec![
+    ///     4, 5,
+    ///
+    ///     0, 1,
+    ///
+    ///     2, 3,
+    /// ]);
+    /// let slab = array.slab_views(1..3).map(|layer| *layer.get([1, 0])).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(slab, &[3, 5]);
+    /// ```
+    pub fn slab_views(
+        &self,
+        range: Range<usize>,
+    ) -> impl ExactSizeIterator<Item = CircularArrayView<'_, 2, T>> {
+        assert!(
+            range.end <= self.n_layers(),
+            "range {:?} is out of bounds for axis 2 of length {}",
+            range,
+            self.n_layers()
+        );
+
+        range.map(|i| self.layer_view(i))
+    }
+}
+
+impl<A: AsRef<[T]> + AsMut<[T]>, T: Clone> CircularArray<3, A, T> {
+    /// Iterate mutably over the elements of the layer at `index`, aligned to the
+    /// offset. Equivalent to [`CircularArray::iter_index_mut`] for axis `2`.
+    pub fn layer_mut(&mut self, index: usize) -> impl ExactSizeIterator<Item = &mut T> {
+        self.iter_index_mut(2, index)
+    }
+
+    /// Push layers to the front of the array. Elements must be an exact multiple
+    /// of the layer length. Equivalent to [`CircularMut::push_front`] for axis `2`.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let mut array = CircularArray::new([2, 2, 2], vec![
+    ///     0, 1,
+    ///     2, 3,
+    ///
+    ///     4, 5,
+    ///     6, 7,
+    /// ]);
+    /// array.push_layer(&[8, 9, 10, 11]);
+    ///
+    /// assert_eq!(array.iter().cloned().collect::<Vec<_>>(), &[
+    ///     4,  5,
+    ///     6,  7,
+    ///
+    ///     8,  9,
+    ///    10, 11,
+    /// ]);
+    /// ```
+    pub fn push_layer(&mut self, el: &[T]) {
+        self.push_front(2, el);
+    }
+}
+
+/// Index the array by a tuple of indices, aligned to the offset. Equivalent to
+/// indexing by `[x, y, z]`.
+///
+/// # Example
+/// ```
+/// # use n_circular_array::CircularArray;
+/// let mut array = CircularArray::new_offset([2, 1, 2], [1, 0, 0], vec![
+///     1, 0,
+///
+///     3, 2,
+/// ]);
+/// array[(1, 0, 1)] = 9;
+///
+/// assert_eq!(array[(0, 0, 0)], 0);
+/// assert_eq!(array[(1, 0, 1)], array[[1, 0, 1]]);
+/// ```
+impl<A: AsRef<[T]>, T> Index<(usize, usize, usize)> for CircularArray<3, A, T> {
+    type Output = T;
+
+    fn index(&self, (x, y, z): (usize, usize, usize)) -> &Self::Output {
+        self.get([x, y, z])
+    }
+}
+
+impl<A: AsRef<[T]> + AsMut<[T]>, T: Clone> IndexMut<(usize, usize, usize)>
+    for CircularArray<3, A, T>
+{
+    fn index_mut(&mut self, (x, y, z): (usize, usize, usize)) -> &mut Self::Output {
+        self.get_mut([x, y, z])
+    }
+}
+
+/// Create a [`CircularArrayVec`] from nested arrays of layers, where each inner
+/// array is a layer of rows. The shape is `[X, Y, Z]` (`X` columns, `Y` rows, `Z`
+/// layers).
+///
+/// # Example
+/// ```
+/// # use n_circular_array::{CircularArrayVec, CircularIndex};
+/// let array = CircularArrayVec::from([
+///     [[0, 1], [2, 3]],
+///     [[4, 5], [6, 7]],
+/// ]);
+///
+/// assert_eq!(array.shape(), &[2, 2, 2]);
+/// assert_eq!(array.data(), &[0, 1, 2, 3, 4, 5, 6, 7]);
+/// ```
+impl<const X: usize, const Y: usize, const Z: usize, T> From<[[[T; X]; Y]; Z]>
+    for CircularArrayVec<3, T>
+{
+    fn from(layers: [[[T; X]; Y]; Z]) -> Self {
+        CircularArray::new([X, Y, Z], layers.into_iter().flatten().flatten().collect())
+    }
+}
+
+/// Create a [`CircularArrayVec`] from a `Vec` of layers, where each layer is a `Vec`
+/// of rows. The shape is `[layers[0][0].len(), layers[0].len(), layers.len()]`.
+///
+/// # Panics
+///
+/// Panics if the layers, or the rows, are not of equal length.
+///
+/// # Example
+/// ```
+/// # use n_circular_array::{CircularArrayVec, CircularIndex};
+/// let array = CircularArrayVec::from(vec![
+///     vec![vec![0, 1], vec![2, 3]],
+///     vec![vec![4, 5], vec![6, 7]],
+/// ]);
+///
+/// assert_eq!(array.shape(), &[2, 2, 2]);
+/// assert_eq!(array.data(), &[0, 1, 2, 3, 4, 5, 6, 7]);
+/// ```
+impl<T> From<Vec<Vec<Vec<T>>>> for CircularArrayVec<3, T> {
+    fn from(layers: Vec<Vec<Vec<T>>>) -> Self {
+        let n_rows = layers.first().map_or(0, Vec::len);
+        let n_cols = layers
+            .first()
+            .and_then(|layer| layer.first())
+            .map_or(0, Vec::len);
+        layers.iter().enumerate().for_each(|(i, layer)| {
+            assert!(
+                layer.len() == n_rows,
+                "layer {} of length {} does not match layer length {}",
+                i,
+                layer.len(),
+                n_rows
+            );
+            layer.iter().enumerate().for_each(|(j, row)| {
+                assert!(
+                    row.len() == n_cols,
+                    "row {} of layer {} of length {} does not match row length {}",
+                    j,
+                    i,
+                    row.len(),
+                    n_cols
+                )
+            });
+        });
+
+        CircularArray::new(
+            [n_cols, n_rows, layers.len()],
+            layers.into_iter().flatten().flatten().collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CircularArrayVec, CircularIndex};
+
+    #[test]
+    fn layers() {
+        let m = CircularArrayVec::from_iter_offset([3, 2, 3], [2, 1, 2], 0..18);
+
+        #[rustfmt::skip]
+        assert_eq!(m.layers().map(|layer| layer.cloned().collect::<Vec<_>>()).collect::<Vec<_>>(), [
+            [17, 15, 16, 14, 12, 13],
+            [ 5,  3,  4,  2,  0,  1],
+            [11,  9, 10,  8,  6,  7],
+        ]);
+        #[rustfmt::skip]
+        assert_eq!(m.layer_views().map(|layer| layer.iter().cloned().collect::<Vec<_>>()).collect::<Vec<_>>(), [
+            [17, 15, 16, 14, 12, 13],
+            [ 5,  3,  4,  2,  0,  1],
+            [11,  9, 10,  8,  6,  7],
+        ]);
+    }
+
+    #[test]
+    fn slab() {
+        let m = CircularArrayVec::from_iter_offset([3, 2, 4], [2, 1, 3], 0..24);
+
+        assert!(m.slab(1..3).eq(m.layer(1).chain(m.layer(2))));
+        assert_eq!(m.slab_views(1..4).len(), 3);
+        for (view, i) in m.slab_views(1..4).zip(1..) {
+            assert!(view.iter().eq(m.layer(i)));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn slab_range() {
+        let m = CircularArrayVec::from_iter([2, 2, 3], 0..12);
+        let _ = m.slab_views(2..4);
+    }
+
+    #[test]
+    fn push_layer() {
+        let mut m = CircularArrayVec::from_iter([2, 2, 3], 0..12);
+
+        m.push_layer(&[12, 13, 14, 15]);
+        m.layer_mut(0).for_each(|el| *el = 0);
+        #[rustfmt::skip]
+        assert_eq!(m.iter().cloned().collect::<Vec<_>>(), [
+             0,  0,
+             0,  0,
+
+             8,  9,
+            10, 11,
+
+            12, 13,
+            14, 15,
+        ]);
+        assert_eq!(m.layer_view(2).get([1, 1]), &15);
+    }
+
+    #[test]
+    fn from_nested() {
+        let layers = [[[0, 1, 2], [3, 4, 5]], [[6, 7, 8], [9, 10, 11]]];
+        let expected = CircularArrayVec::from_iter([3, 2, 2], 0..12);
+
+        let m = CircularArrayVec::from(layers);
+        assert_eq!(m.shape(), expected.shape());
+        assert_eq!(m.data(), expected.data());
+
+        let m = CircularArrayVec::from(layers.map(|layer| layer.map(Vec::from).to_vec()).to_vec());
+        assert_eq!(m.shape(), expected.shape());
+        assert_eq!(m.data(), expected.data());
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_nested_ragged() {
+        let _ = CircularArrayVec::<3, _>::from(vec![
+            vec![vec![0, 1], vec![2, 3]],
+            vec![vec![4, 5], vec![6]],
+        ]);
+    }
+
+    #[test]
+    fn index_tuple() {
+        let mut m = CircularArrayVec::from_iter_offset([3, 2, 3], [2, 1, 2], 0..18);
+
+        assert_eq!(m[(1, 0, 2)], m[[1, 0, 2]]);
+        m[(2, 1, 0)] = 99;
+        assert_eq!(m[[2, 1, 0]], 99);
+    }
+}
@@ -1,7 +1,8 @@
 use std::array;
+use std::borrow::Cow;
 use std::ops::{Index, Range};
 
-use crate::array_iter::CircularArrayIterator;
+use crate::array_iter::{iter_indices, CircularArrayIterator, FlatChunks};
 use crate::index::RawIndexAdaptor;
 use crate::index_iter::IndexIterator;
 use crate::span::{BoundSpan, UnboundSpan};
@@ -290,13 +291,22 @@ impl<const N: usize, A, T> CircularArray<N, A, T> {
         debug_assert!(span.len() <= self.shape[axis]);
         array::from_fn(|i| {
             if i == axis {
-                (span + self.offset[i]) % self.shape[i]
+                span.wrapping_add(self.offset[i])
             } else {
                 BoundSpan::new(self.offset[i], self.shape[i], self.shape[i])
             }
         })
     }
 
+    /// Get the raw index of the given signed index, wrapping over the bounds of
+    /// each axis, aligned to the offset.
+    pub(crate) fn wrapped_raw_index(&self, index: [isize; N]) -> [usize; N] {
+        array::from_fn(|i| {
+            let len = self.shape[i];
+            (index[i].rem_euclid(len as isize) as usize + self.offset[i]) % len
+        })
+    }
+
     /// Get the raw spans of the array, bound by the given `span` on the given `axis`.
     pub(crate) fn spans_axis_bound_raw(&self, axis: usize, span: UnboundSpan) -> [UnboundSpan; N] {
         array::from_fn(|i| {
@@ -307,6 +317,25 @@ impl<const N: usize, A, T> CircularArray<N, A, T> {
             }
         })
     }
+
+    /// Increment the offset by `n` on the given `axis`.
+    pub(crate) fn incr_offset(&mut self, axis: usize, n: usize) {
+        self.offset[axis] = self.wrap_index(axis, self.offset[axis] + n);
+    }
+
+    /// Decrement the offset by `n` on the given `axis`.
+    pub(crate) fn decr_offset(&mut self, axis: usize, n: usize) {
+        self.offset[axis] = self.wrap_index(axis, self.shape[axis] + self.offset[axis] - n);
+    }
+
+    /// Wrap `index` into the bounds of the given `axis`, by bitmask where the axis
+    /// length is a power of two.
+    pub(crate) fn wrap_index(&self, axis: usize, index: usize) -> usize {
+        match self.masks[axis] {
+            Some(mask) => index & mask,
+            None => index % self.shape[axis],
+        }
+    }
 }
 
 impl<'a, const N: usize, A: AsRef<[T]>, T: 'a> CircularIndex<'a, N, T> for CircularArray<N, A, T> {
@@ -426,10 +455,10 @@ impl<'a, const N: usize, A: AsRef<[T]>, T: 'a> CircularIndex<'a, N, T> for Circu
             assert_slice_range!(self, i, range);
 
             BoundSpan::new(
-                (range.start + self.offset[i]) % self.shape[i],
+                self.wrap_index(i, range.start + self.offset[i]),
                 range.len(),
                 self.shape[i],
-            ) % self.shape[i]
+            )
         });
 
         let iter = IndexIterator::new_bound_contiguous(spans)
@@ -449,10 +478,10 @@ impl<'a, const N: usize, A: AsRef<[T]>, T: 'a> CircularIndex<'a, N, T> for Circu
             assert_slice_range!(self, i, range);
 
             BoundSpan::new(
-                (range.start + self.offset[i]) % self.shape[i],
+                self.wrap_index(i, range.start + self.offset[i]),
                 range.len(),
                 self.shape[i],
-            ) % self.shape[i]
+            )
         });
 
         let iter = IndexIterator::new_bound_contiguous_ordered(spans)
@@ -482,7 +511,7 @@ impl<'a, const N: usize, A: AsRef<[T]>, T: 'a> CircularIndex<'a, N, T> for Circu
     fn get(&'a self, mut index: [usize; N]) -> &'a T {
         index.iter_mut().enumerate().for_each(|(i, idx)| {
             assert_slice_index!(self, i, *idx);
-            *idx = (*idx + self.offset[i]) % (self.shape[i]);
+            *idx = self.wrap_index(i, *idx + self.offset[i]);
         });
 
         &self.array.as_ref()[self.strides.offset_index(index)]
@@ -493,7 +522,154 @@ impl<'a, const N: usize, A: AsRef<[T]>, T: 'a> CircularIndex<'a, N, T> for Circu
     }
 }
 
-impl<'a, const N: usize, A: AsRef<[T]>, T: 'a> Index<[usize; N]> for CircularArray<N, A, T> {
+impl<const N: usize, A: AsRef<[T]>, T> CircularArray<N, A, T> {
+    /// Iterate over each index of the given `axis`, aligned to the offset. Each
+    /// item is an iterator over the elements of that index, equivalent to
+    /// [`CircularIndex::iter_index`].
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::CircularArray;
+    /// let array = CircularArray::new_offset([3, 2], [1, 0], vec![
+    ///     2, 0, 1,
+    ///     5, 3, 4,
+    /// ]);
+    /// let rows = array
+    ///     .iter_axis(1)
+    ///     .map(|row| row.cloned().collect::<Vec<_>>())
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(rows, [[0, 1, 2], [3, 4, 5]]);
+    /// ```
+    pub fn iter_axis(
+        &self,
+        axis: usize,
+    ) -> impl ExactSizeIterator<Item = impl ExactSizeIterator<Item = &T>> {
+        assert_shape_index!(axis, N);
+
+        (0..self.shape[axis]).map(move |i| self.iter_index(axis, i))
+    }
+
+    /// Iterate over all elements of the array together with their index, aligned
+    /// to the offset. Elements are yielded in the order of [`CircularIndex::iter`].
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::CircularArray;
+    /// let array = CircularArray::new_offset([2, 2], [1, 0], vec![
+    ///     1, 0,
+    ///     3, 2,
+    /// ]);
+    /// assert_eq!(array.indexed_iter().map(|(i, el)| (i, *el)).collect::<Vec<_>>(), [
+    ///     ([0, 0], 0), ([1, 0], 1),
+    ///     ([0, 1], 2), ([1, 1], 3),
+    /// ]);
+    /// ```
+    pub fn indexed_iter(&self) -> impl ExactSizeIterator<Item = ([usize; N], &T)> {
+        iter_indices(self.shape).zip(self.iter())
+    }
+
+    /// Get a reference to the element at the given signed index, aligned to the
+    /// offset. Indices wrap over the bounds of each axis, such that `-1` is the
+    /// last index of an axis.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::CircularArray;
+    /// let array = CircularArray::new_offset([3, 3], [1, 1], vec![
+    ///     8, 6, 7,
+    ///     2, 0, 1,
+    ///     5, 3, 4,
+    /// ]);
+    ///
+    /// assert_eq!(array.get_wrapped([0, 0]), &0);
+    /// assert_eq!(array.get_wrapped([-1, 0]), &2);
+    /// assert_eq!(array.get_wrapped([4, -4]), &7);
+    /// ```
+    pub fn get_wrapped(&self, index: [isize; N]) -> &T {
+        &self.array.as_ref()[self.strides.offset_index(self.wrapped_raw_index(index))]
+    }
+
+    /// Get a reference to the element at the given index by age, such that index
+    /// `0` of each axis is the slice most recently pushed by
+    /// [`CircularMut::push_front`](crate::CircularMut::push_front) (the last index),
+    /// and index `len - 1` the oldest.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularMut};
+    /// let mut array = CircularArray::new([3, 2], vec![
+    ///     0, 1, 2,
+    ///     3, 4, 5,
+    /// ]);
+    /// array.push_front(0, &[6, 7]);
+    ///
+    /// assert_eq!(array.get_back([0, 0]), &7);
+    /// assert_eq!(array.get_back([1, 0]), &5);
+    /// assert_eq!(array.get_back([2, 1]), &1);
+    /// ```
+    pub fn get_back(&self, index: [usize; N]) -> &T {
+        let index = array::from_fn(|i| {
+            assert_slice_index!(self, i, index[i]);
+            self.shape[i] - 1 - index[i]
+        });
+
+        self.get(index)
+    }
+
+    /// Iterate over all elements of the given `axis` and index `k` by age, such
+    /// that `k = 0` is the slice most recently pushed by
+    /// [`CircularMut::push_front`](crate::CircularMut::push_front) (the last index).
+    /// Elements are yielded in the order of [`CircularIndex::iter_index`].
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularMut};
+    /// let mut array = CircularArray::new([3, 2], vec![
+    ///     0, 1, 2,
+    ///     3, 4, 5,
+    /// ]);
+    /// array.push_front(1, &[6, 7, 8]);
+    ///
+    /// assert_eq!(array.iter_index_back(1, 0).cloned().collect::<Vec<_>>(), &[6, 7, 8]);
+    /// assert_eq!(array.iter_index_back(1, 1).cloned().collect::<Vec<_>>(), &[3, 4, 5]);
+    /// ```
+    pub fn iter_index_back(&self, axis: usize, k: usize) -> impl ExactSizeIterator<Item = &T> {
+        assert_shape_index!(axis, N);
+        assert_slice_index!(self, axis, k);
+
+        self.iter_index(axis, self.shape[axis] - 1 - k)
+    }
+
+    /// Iterate over chunks of `k` elements of the array, aligned to the offset,
+    /// in the order of [`CircularIndex::iter`]. The last chunk may contain fewer than
+    /// `k` elements. Chunks within a contiguous section of memory are borrowed,
+    /// otherwise elements are cloned into an owned chunk.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::borrow::Cow;
+    /// # use n_circular_array::CircularArray;
+    /// let array = CircularArray::new_offset([5], [2], vec![3, 4, 0, 1, 2]);
+    /// let chunks = array.iter_flat_chunks(2).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(chunks, [&[0, 1][..], &[2, 3][..], &[4][..]]);
+    /// assert!(matches!(chunks[0], Cow::Borrowed(_)));
+    /// assert!(matches!(chunks[1], Cow::Owned(_)));
+    /// ```
+    pub fn iter_flat_chunks(&self, k: usize) -> impl ExactSizeIterator<Item = Cow<'_, [T]>>
+    where
+        T: Clone,
+    {
+        let runs = IndexIterator::new_bound_contiguous(self.spans())
+            .into_flat_ranges(&self.strides)
+            .collect();
+
+        FlatChunks::new(self.array.as_ref(), runs, k, self.len())
+    }
+}
+
+impl<const N: usize, A: AsRef<[T]>, T> Index<[usize; N]> for CircularArray<N, A, T> {
     type Output = T;
 
     fn index(&self, index: [usize; N]) -> &Self::Output {
@@ -686,6 +862,40 @@ mod tests {
         assert_eq!(m.iter_slice([0..3, 0..3, 1..2]).len(), 9);
     }
 
+    #[test]
+    fn iter_axis() {
+        let shape = [4, 3, 2];
+        let m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..shape.iter().product());
+
+        for axis in 0..3 {
+            assert_eq!(m.iter_axis(axis).len(), m.shape()[axis]);
+            m.iter_axis(axis).enumerate().for_each(|(i, iter)| {
+                assert_eq!(iter.len(), m.slice_len(axis));
+                assert_eq!(
+                    iter.cloned().collect::<Vec<_>>(),
+                    m.iter_index(axis, i).cloned().collect::<Vec<_>>()
+                );
+            });
+        }
+    }
+
+    #[test]
+    fn iter_flat_chunks() {
+        let shape = [4, 3, 2];
+        let m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..shape.iter().product());
+
+        for k in 1..26 {
+            assert_eq!(m.iter_flat_chunks(k).len(), 24usize.div_ceil(k));
+            assert!(m.iter_flat_chunks(k).all(|chunk| chunk.len() <= k));
+            assert_eq!(
+                m.iter_flat_chunks(k)
+                    .flat_map(|chunk| chunk.to_vec())
+                    .collect::<Vec<_>>(),
+                m.iter().cloned().collect::<Vec<_>>()
+            );
+        }
+    }
+
     #[test]
     fn get() {
         let shape = [3, 3, 3];
@@ -696,6 +906,30 @@ mod tests {
         assert_eq!(m.get([2, 2, 2]), &0);
     }
 
+    #[test]
+    fn get_back() {
+        let shape = [4, 3, 2];
+        let m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..shape.iter().product());
+
+        for (index, el) in m.indexed_iter() {
+            assert_eq!(m.get_back([3 - index[0], 2 - index[1], 1 - index[2]]), el);
+        }
+        for (axis, len) in shape.into_iter().enumerate() {
+            for k in 0..len {
+                assert!(m
+                    .iter_index_back(axis, k)
+                    .eq(m.iter_index(axis, len - 1 - k)));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_back_index() {
+        let m = CircularArrayVec::from_iter([3, 3], 0..9);
+        m.get_back([0, 3]);
+    }
+
     #[test]
     fn get_raw() {
         let m = CircularArray::new([3, 3, 3], (0..3 * 3 * 3).collect::<Vec<_>>());
@@ -1,3 +1,6 @@
+use std::borrow::Cow;
+use std::ops::Range;
+
 /// `ExactSizeIterator` implementation for indexing operations.
 pub struct CircularArrayIterator<'a, I: Iterator<Item = &'a T>, T: 'a> {
     iter: I,
@@ -18,7 +21,14 @@ impl<'a, I: Iterator<Item = &'a T>, T: 'a> Iterator for CircularArrayIterator<'a
     type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.iter.next()
+        let item = self.iter.next()?;
+        self.len -= 1;
+
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
     }
 }
 
@@ -27,3 +37,202 @@ impl<'a, I: Iterator<Item = &'a T>, T: 'a> ExactSizeIterator for CircularArrayIt
         self.len
     }
 }
+
+/// `ExactSizeIterator` implementation for mutable indexing operations.
+pub struct CircularArrayIteratorMut<'a, I: Iterator<Item = &'a mut T>, T: 'a> {
+    iter: I,
+    len: usize,
+}
+
+impl<'a, I: Iterator<Item = &'a mut T>, T: 'a> CircularArrayIteratorMut<'a, I, T> {
+    /// Create a new `CircularArrayIteratorMut`. The given `len` **must** match the
+    /// length of the `Iterator` provided.
+    pub(crate) fn new(iter: I, len: usize) -> Self {
+        Self { iter, len }
+    }
+}
+
+impl<'a, I: Iterator<Item = &'a mut T>, T: 'a> Iterator for CircularArrayIteratorMut<'a, I, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        self.len -= 1;
+
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, I: Iterator<Item = &'a mut T>, T: 'a> ExactSizeIterator
+    for CircularArrayIteratorMut<'a, I, T>
+{
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+/// Iterator over chunks of `k` elements of a sequence of contiguous `runs` of
+/// a slice. Chunks within a single run are borrowed, and chunks spanning multiple
+/// runs are cloned.
+pub(crate) struct FlatChunks<'a, T> {
+    array: &'a [T],
+    runs: std::vec::IntoIter<Range<usize>>,
+    run: Range<usize>,
+    k: usize,
+    len: usize,
+}
+
+impl<'a, T: Clone> FlatChunks<'a, T> {
+    /// Create a new `FlatChunks`. The given `len` **must** match the number of
+    /// elements within `runs`.
+    pub(crate) fn new(array: &'a [T], runs: Vec<Range<usize>>, k: usize, len: usize) -> Self {
+        assert!(k > 0, "chunk size must be greater than 0");
+        debug_assert_eq!(runs.iter().map(|run| run.len()).sum::<usize>(), len);
+
+        Self {
+            array,
+            runs: runs.into_iter(),
+            run: 0..0,
+            k,
+            len,
+        }
+    }
+}
+
+impl<'a, T: Clone> Iterator for FlatChunks<'a, T> {
+    type Item = Cow<'a, [T]>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let n = self.k.min(self.len);
+        if n == 0 {
+            return None;
+        }
+        self.len -= n;
+
+        if self.run.is_empty() {
+            self.run = self.runs.next()?;
+        }
+
+        if self.run.len() >= n {
+            let start = self.run.start;
+            self.run.start += n;
+
+            Some(Cow::Borrowed(&self.array[start..start + n]))
+        } else {
+            let mut chunk = Vec::with_capacity(n);
+            while chunk.len() < n {
+                if self.run.is_empty() {
+                    self.run = self.runs.next().expect("Misaligned chunk runs");
+                }
+                let len = self.run.len().min(n - chunk.len());
+                chunk.extend_from_slice(&self.array[self.run.start..self.run.start + len]);
+                self.run.start += len;
+            }
+
+            Some(Cow::Owned(chunk))
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len.div_ceil(self.k);
+        (len, Some(len))
+    }
+}
+
+impl<T: Clone> ExactSizeIterator for FlatChunks<'_, T> {}
+
+/// Iterate over all logical indices of an array of the given `shape`, where axis
+/// `0` is the fastest varying. Indices are computed incrementally.
+pub(crate) fn iter_indices<const N: usize>(
+    shape: [usize; N],
+) -> impl ExactSizeIterator<Item = [usize; N]> {
+    let len = shape.iter().product();
+    let mut index = [0; N];
+
+    (0..len).map(move |_| {
+        let item = index;
+
+        for axis in 0..N {
+            index[axis] += 1;
+            if index[axis] < shape[axis] {
+                break;
+            }
+            index[axis] = 0;
+        }
+
+        item
+    })
+}
+
+/// Split `slice` into mutable sub-slices for each of the given **disjoint** `ranges`.
+/// Sub-slices are returned in the order of `ranges`.
+pub(crate) fn split_ranges_mut<T>(
+    mut slice: &mut [T],
+    ranges: impl Iterator<Item = Range<usize>>,
+) -> Vec<&mut [T]> {
+    let mut ranges = ranges.enumerate().collect::<Vec<_>>();
+    ranges.sort_unstable_by_key(|(_, range)| range.start);
+
+    let mut slices = Vec::with_capacity(ranges.len());
+    let mut consumed = 0;
+    for (i, range) in ranges {
+        debug_assert!(range.start >= consumed, "Overlapping ranges");
+
+        let (_, tail) = std::mem::take(&mut slice).split_at_mut(range.start - consumed);
+        let (head, tail) = tail.split_at_mut(range.len());
+        slice = tail;
+        consumed = range.end;
+
+        slices.push((i, head));
+    }
+    slices.sort_unstable_by_key(|(i, _)| *i);
+
+    slices.into_iter().map(|(_, slice)| slice).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_ranges() {
+        let mut array = (0..10).collect::<Vec<_>>();
+        let slices = split_ranges_mut(&mut array, [6..8, 0..2, 3..4].into_iter());
+
+        assert_eq!(slices, [&mut [6, 7][..], &mut [0, 1][..], &mut [3][..]]);
+    }
+
+    #[test]
+    fn flat_chunks() {
+        let array = (0..10).collect::<Vec<_>>();
+        let chunks = FlatChunks::new(&array, vec![6..10, 0..3, 3..6], 3, 10);
+
+        assert_eq!(chunks.len(), 4);
+        assert_eq!(
+            chunks
+                .map(|chunk| (matches!(chunk, Cow::Borrowed(_)), chunk.to_vec()))
+                .collect::<Vec<_>>(),
+            [
+                (true, vec![6, 7, 8]),
+                (false, vec![9, 0, 1]),
+                (false, vec![2, 3, 4]),
+                (true, vec![5]),
+            ]
+        );
+    }
+
+    #[test]
+    fn indices() {
+        let indices = iter_indices([2, 3]);
+
+        assert_eq!(indices.len(), 6);
+        assert_eq!(
+            indices.collect::<Vec<_>>(),
+            [[0, 0], [1, 0], [0, 1], [1, 1], [0, 2], [1, 2]]
+        );
+    }
+}
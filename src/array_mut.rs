@@ -1,8 +1,11 @@
+use std::array;
 use std::ops::{IndexMut, Range};
 
+use crate::array_iter::{iter_indices, split_ranges_mut, CircularArrayIteratorMut};
 use crate::index::RawIndexAdaptor;
 use crate::index_iter::IndexIterator;
 use crate::span::{BoundSpan, UnboundSpan};
+use crate::strides::Strides;
 use crate::CircularArray;
 
 /// Mutating `CircularArray` operations.
@@ -366,6 +369,41 @@ pub trait CircularMut<'a, const N: usize, T> {
         F: FnMut([Range<usize>; N]) -> &'b [T];
 }
 
+/// Iterate over elements of a buffer of the given `shape`, ordered with `axis` as
+/// the fastest varying axis. Elements are returned in row-major order.
+fn iter_transposed<const N: usize, T>(
+    el: &[T],
+    shape: [usize; N],
+    axis: usize,
+) -> impl ExactSizeIterator<Item = &T> {
+    debug_assert_eq!(el.len(), shape.iter().product::<usize>());
+
+    let mut strides = [1; N];
+    let mut stride = shape[axis];
+    for i in (0..N).filter(|i| *i != axis) {
+        strides[i] = stride;
+        stride *= shape[i];
+    }
+
+    let mut index = [0; N];
+    let mut i = 0;
+    (0..el.len()).map(move |_| {
+        let item = &el[i];
+
+        for axis in 0..N {
+            index[axis] += 1;
+            i += strides[axis];
+            if index[axis] < shape[axis] {
+                break;
+            }
+            i -= strides[axis] * shape[axis];
+            index[axis] = 0;
+        }
+
+        item
+    })
+}
+
 impl<const N: usize, A: AsRef<[T]> + AsMut<[T]>, T: Clone> CircularArray<N, A, T> {
     /// Push a contiguous slice of elements into the array.
     fn push<'a>(&'a mut self, spans: impl RawIndexAdaptor<'a, N>, mut el: &[T]) {
@@ -398,7 +436,7 @@ impl<const N: usize, A: AsRef<[T]> + AsMut<[T]>, T: Clone> CircularArray<N, A, T
     }
 
     /// Push slice(s) retrieved from the given `el_fn` into the array.
-    fn translate<'a, 'b, F>(
+    pub(crate) fn translate_spans<'a, 'b, F>(
         &'a mut self,
         src_spans: impl RawIndexAdaptor<'a, N>,
         dst_spans: impl RawIndexAdaptor<'a, N>,
@@ -411,7 +449,7 @@ impl<const N: usize, A: AsRef<[T]> + AsMut<[T]>, T: Clone> CircularArray<N, A, T
         let src_iter = src_spans.into_ranges(origin);
         let mut dst_iter = dst_spans.into_flat_ranges(&self.strides);
 
-        for mut src_slice in src_iter.map(|range| el_fn(range)) {
+        for mut src_slice in src_iter.map(&mut el_fn) {
             let mut src_len = src_slice.len();
 
             while src_len > 0 {
@@ -425,14 +463,435 @@ impl<const N: usize, A: AsRef<[T]> + AsMut<[T]>, T: Clone> CircularArray<N, A, T
         }
     }
 
-    /// Increment the offset by `n` on the given `axis`.
-    pub(crate) fn incr_offset(&mut self, axis: usize, n: usize) {
-        self.offset[axis] = (self.offset[axis] + n) % self.shape()[axis];
+    /// Iterate mutably over the elements of the given spans. The given `len`
+    /// **must** match the number of elements within the spans.
+    pub(crate) fn iter_spans_mut<'a>(
+        &'a mut self,
+        spans: impl RawIndexAdaptor<'a, N>,
+        len: usize,
+    ) -> impl ExactSizeIterator<Item = &'a mut T> {
+        let CircularArray { array, strides, .. } = self;
+        let ranges = spans.into_flat_ranges(strides);
+
+        let iter = split_ranges_mut(array.as_mut(), ranges)
+            .into_iter()
+            .flat_map(|slice| slice.iter_mut());
+
+        CircularArrayIteratorMut::new(iter, len)
+    }
+
+    /// Get a mutable reference to the element at the given signed index, aligned
+    /// to the offset. Indices wrap over the bounds of each axis. See
+    /// [`CircularArray::get_wrapped`].
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let mut array = CircularArray::new_offset([3, 3], [1, 1], vec![
+    ///     8, 6, 7,
+    ///     2, 0, 1,
+    ///     5, 3, 4,
+    /// ]);
+    /// *array.get_wrapped_mut([-1, 3]) = 9;
+    ///
+    /// assert_eq!(array.get([2, 0]), &9);
+    /// ```
+    pub fn get_wrapped_mut(&mut self, index: [isize; N]) -> &mut T {
+        let index = self.strides.offset_index(self.wrapped_raw_index(index));
+        &mut self.array.as_mut()[index]
+    }
+
+    /// Get mutable references to the elements at each of the given `indices`,
+    /// aligned to the offset. Returns `None` if any index is out of bounds, or
+    /// if any two indices are equal.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let mut array = CircularArray::new_offset([3, 2], [1, 0], vec![
+    ///     2, 0, 1,
+    ///     5, 3, 4,
+    /// ]);
+    /// let [a, b] = array.get_disjoint_mut([[0, 0], [2, 1]]).unwrap();
+    /// std::mem::swap(a, b);
+    ///
+    /// assert_eq!(array.iter().cloned().collect::<Vec<_>>(), &[
+    ///     5, 1, 2,
+    ///     3, 4, 0,
+    /// ]);
+    /// assert!(array.get_disjoint_mut([[1, 1], [1, 1]]).is_none());
+    /// assert!(array.get_disjoint_mut([[3, 0]]).is_none());
+    /// ```
+    pub fn get_disjoint_mut<const K: usize>(
+        &mut self,
+        indices: [[usize; N]; K],
+    ) -> Option<[&mut T; K]> {
+        let mut raw = [0; K];
+        for (raw, index) in raw.iter_mut().zip(indices) {
+            if (0..N).any(|i| index[i] >= self.shape[i]) {
+                return None;
+            }
+            *raw = self.strides.offset_index(array::from_fn(|i| {
+                self.wrap_index(i, index[i] + self.offset[i])
+            }));
+        }
+
+        self.array.as_mut().get_disjoint_mut(raw).ok()
+    }
+
+    /// Push each of the given `(axis, elements)` pairs to the front of its axis, in
+    /// order. Equivalent to [`CircularMut::push_front`] for each pair, however all
+    /// pairs are validated before any elements are pushed, such that an invalid
+    /// pair leaves the array unchanged. The destination ranges of every pair are
+    /// resolved first, and elements are then copied in a single pass over the
+    /// buffer.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let mut array = CircularArray::new([3, 3], vec![
+    ///     0, 1, 2,
+    ///     3, 4, 5,
+    ///     6, 7, 8,
+    /// ]);
+    /// array.push_many([
+    ///     (0, &[9, 10, 11][..]),
+    ///     (1, &[12, 13, 14][..]),
+    /// ]);
+    ///
+    /// assert_eq!(array.iter().cloned().collect::<Vec<_>>(), &[
+    ///      4,  5, 10,
+    ///      7,  8, 11,
+    ///     12, 13, 14,
+    /// ]);
+    /// ```
+    pub fn push_many<const K: usize>(&mut self, pushes: [(usize, &[T]); K]) {
+        for (axis, el) in pushes.iter() {
+            let axis = *axis;
+            let el_len = el.len();
+
+            assert_shape_index!(axis, N);
+            let slice_len = self.slice_len(axis);
+            let n = el_len / slice_len;
+
+            assert_element_len!(axis, el_len, slice_len);
+            assert_slice_len!(self, axis, n);
+        }
+
+        let mut writes: Vec<(Range<usize>, &[T])> = vec![];
+        for (axis, el) in pushes {
+            let n = el.len() / self.slice_len(axis);
+            if n == 0 {
+                continue;
+            }
+
+            if n == self.shape[axis] {
+                writes.push((0..el.len(), el));
+                self.offset = [0; N];
+            } else {
+                let spans = self.spans_axis_bound(axis, BoundSpan::new(0, n, self.shape[axis]));
+                let ranges = IndexIterator::new_bound_contiguous(spans);
+                let mut el = el;
+                for range in ranges.into_flat_ranges(&self.strides) {
+                    let (head, tail) = el.split_at(range.len());
+                    writes.push((range, head));
+                    el = tail;
+                }
+                self.incr_offset(axis, n);
+            }
+        }
+
+        let array = self.array.as_mut();
+        for (range, el) in writes {
+            array[range].clone_from_slice(el);
+        }
+    }
+
+    /// Iterate mutably over contiguous sections of the elements within the given
+    /// `slice`, aligned to the offset. Sections are yielded in ascending memory
+    /// order, each paired with the position of its first element in the order of
+    /// [`CircularIndex::iter_slice`](crate::CircularIndex::iter_slice). This allows
+    /// bulk writes from a source to visit the buffer monotonically. Push and
+    /// translate operations do not write in this order.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let mut array = CircularArray::new_offset([3, 3], [1, 1], vec![0; 9]);
+    /// let src = [1, 2, 3, 4];
+    ///
+    /// for (i, dst) in array.iter_slice_dst_ordered([1..3, 1..3]) {
+    ///     dst.clone_from_slice(&src[i..i + dst.len()]);
+    /// }
+    ///
+    /// assert_eq!(array.iter().cloned().collect::<Vec<_>>(), &[
+    ///     0, 0, 0,
+    ///     0, 1, 2,
+    ///     0, 3, 4,
+    /// ]);
+    /// ```
+    pub fn iter_slice_dst_ordered(
+        &mut self,
+        slice: [Range<usize>; N],
+    ) -> impl Iterator<Item = (usize, &mut [T])> {
+        slice.iter().enumerate().for_each(|(i, range)| {
+            assert_slice_range!(self, i, range);
+        });
+
+        let (ranges, src) = match slice.iter().any(|range| range.is_empty()) {
+            true => (vec![], vec![]),
+            false => {
+                let spans = array::from_fn(|i| {
+                    BoundSpan::new(
+                        (slice[i].start + self.offset[i]) % self.shape[i],
+                        slice[i].len(),
+                        self.shape[i],
+                    )
+                });
+                self.ordered_ranges(spans).unzip()
+            }
+        };
+
+        src.into_iter()
+            .zip(split_ranges_mut(self.array.as_mut(), ranges.into_iter()))
+    }
+
+    /// Get the contiguous buffer ranges of the given spans in ascending memory
+    /// order, each paired with the position of its first element within the spans
+    /// in the order of [`CircularIndex::iter`](crate::CircularIndex::iter).
+    fn ordered_ranges(
+        &self,
+        spans: [BoundSpan; N],
+    ) -> impl Iterator<Item = (Range<usize>, usize)> + 'static {
+        let shape = self.shape;
+        let strides = self.strides;
+        let src_strides = Strides::new(&spans.map(|span| span.len()));
+        let starts = spans.map(|span| span.start());
+
+        IndexIterator::new_bound_contiguous_ordered(spans).flat_map(move |span| {
+            let (start, end) = span.split_bounds();
+            let (start, end) = (*start, *end);
+            let rows: [usize; N] = array::from_fn(|i| match i {
+                0 => 1,
+                _ => end[i] - start[i] + 1,
+            });
+
+            // Split each row of axis 0 where the source wraps.
+            iter_indices(rows).flat_map(move |row| {
+                let raw: [usize; N] = array::from_fn(|i| start[i] + row[i]);
+                let mut src: [usize; N] =
+                    array::from_fn(|i| (raw[i] + shape[i] - starts[i]) % shape[i]);
+
+                let dst = strides.offset_index(raw);
+                let len = end[0] - start[0] + 1;
+                let split = match start[0] < starts[0] && starts[0] <= end[0] {
+                    true => starts[0] - start[0],
+                    false => len,
+                };
+
+                let head = (dst..dst + split, src_strides.offset_index(src));
+                src[0] = 0;
+                let tail = (dst + split..dst + len, src_strides.offset_index(src));
+
+                [head, tail]
+                    .into_iter()
+                    .filter(|(range, _)| !range.is_empty())
+            })
+        })
+    }
+
+    /// Iterate mutably over all elements of the specified `axis` and `index`,
+    /// aligned to the offset.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex, CircularMut};
+    /// let mut array = CircularArray::new_offset([3, 3], [1, 0], vec![
+    ///     2, 0, 1,
+    ///     5, 3, 4,
+    ///     8, 6, 7,
+    /// ]);
+    ///
+    /// array.iter_index_mut(0, 0).for_each(|el| *el += 10);
+    /// assert_eq!(array.iter().cloned().collect::<Vec<_>>(), &[
+    ///     10, 1, 2,
+    ///     13, 4, 5,
+    ///     16, 7, 8,
+    /// ]);
+    /// ```
+    pub fn iter_index_mut(
+        &mut self,
+        axis: usize,
+        index: usize,
+    ) -> impl ExactSizeIterator<Item = &mut T> {
+        assert_shape_index!(axis, N);
+        assert_slice_index!(self, axis, index);
+
+        let spans = self.spans_axis_bound(axis, BoundSpan::new(index, 1, self.shape[axis]));
+        let len = self.slice_len(axis);
+
+        self.iter_spans_mut(IndexIterator::new_bound_contiguous(spans), len)
+    }
+
+    /// Push elements to the front of the given `axis`, aligned to the offset.
+    /// Elements must be an exact multiple of the slice size for the given `axis`,
+    /// and ordered with the given `axis` as the **fastest** varying axis, followed
+    /// by the remaining axes in ascending order. See [`CircularArray::slice_len`].
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex, CircularMut};
+    /// let mut array = CircularArray::new([3, 3], vec![
+    ///     0, 1, 2,
+    ///     3, 4, 5,
+    ///     6, 7, 8,
+    /// ]);
+    ///
+    /// // Push two rows, ordered by column.
+    /// array.push_front_transposed(1, &[9, 12, 10, 13, 11, 14]);
+    /// assert_eq!(array.iter().cloned().collect::<Vec<_>>(), &[
+    ///      6,  7,  8,
+    ///      9, 10, 11,
+    ///     12, 13, 14,
+    /// ]);
+    /// ```
+    pub fn push_front_transposed(&mut self, axis: usize, el: &[T]) {
+        let el_len = el.len();
+        let slice_len = self.slice_len(axis);
+        let n = el_len / slice_len;
+
+        assert_element_len!(axis, el_len, slice_len);
+        assert_slice_len!(self, axis, n);
+
+        let mut shape = self.shape;
+        shape[axis] = n;
+
+        self.push_front_iter(axis, iter_transposed(el, shape, axis));
+    }
+
+    /// Push elements to the back of the given `axis`, aligned to the offset.
+    /// Elements must be an exact multiple of the slice size for the given `axis`,
+    /// and ordered with the given `axis` as the **fastest** varying axis, followed
+    /// by the remaining axes in ascending order. See [`CircularArray::slice_len`].
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex, CircularMut};
+    /// let mut array = CircularArray::new([3, 3], vec![
+    ///     0, 1, 2,
+    ///     3, 4, 5,
+    ///     6, 7, 8,
+    /// ]);
+    ///
+    /// // Push two rows, ordered by column.
+    /// array.push_back_transposed(1, &[9, 12, 10, 13, 11, 14]);
+    /// assert_eq!(array.iter().cloned().collect::<Vec<_>>(), &[
+    ///      9, 10, 11,
+    ///     12, 13, 14,
+    ///      0,  1,  2,
+    /// ]);
+    /// ```
+    pub fn push_back_transposed(&mut self, axis: usize, el: &[T]) {
+        let el_len = el.len();
+        let slice_len = self.slice_len(axis);
+        let n = el_len / slice_len;
+
+        assert_element_len!(axis, el_len, slice_len);
+        assert_slice_len!(self, axis, n);
+
+        let mut shape = self.shape;
+        shape[axis] = n;
+
+        self.push_back_iter(axis, iter_transposed(el, shape, axis));
     }
 
-    /// Decrement the offset by `n` on the given `axis`.
-    pub(crate) fn decr_offset(&mut self, axis: usize, n: usize) {
-        self.offset[axis] = (self.shape()[axis] + self.offset[axis] - n) % self.shape()[axis];
+    /// Iterate mutably over all elements of the array together with their index,
+    /// aligned to the offset. Elements are yielded in the order of
+    /// [`CircularIndex::iter`](crate::CircularIndex::iter).
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let mut array = CircularArray::new_offset([2, 2], [1, 0], vec![
+    ///     1, 0,
+    ///     3, 2,
+    /// ]);
+    /// array.indexed_iter_mut().for_each(|([x, y], el)| *el = x * 10 + y);
+    ///
+    /// assert_eq!(array.iter().cloned().collect::<Vec<_>>(), &[
+    ///      0, 10,
+    ///      1, 11,
+    /// ]);
+    /// ```
+    pub fn indexed_iter_mut(&mut self) -> impl ExactSizeIterator<Item = ([usize; N], &mut T)> {
+        let indices = iter_indices(self.shape);
+        let spans = IndexIterator::new_bound_contiguous(self.spans());
+        let len = self.len();
+
+        indices.zip(self.iter_spans_mut(spans, len))
+    }
+
+    /// Iterate mutably over the elements of two distinct indices `i` and `j` of
+    /// the given `axis` simultaneously, aligned to the offset. Elements of each
+    /// index are yielded in the order of [`CircularArray::iter_index_mut`].
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let mut array = CircularArray::new_offset([3, 3], [0, 1], vec![
+    ///     6, 7, 8,
+    ///     0, 1, 2,
+    ///     3, 4, 5,
+    /// ]);
+    /// let (prev, next) = array.lanes_mut_disjoint(1, 1, 2);
+    /// prev.zip(next).for_each(|(prev, next)| *next -= *prev);
+    ///
+    /// assert_eq!(array.iter().cloned().collect::<Vec<_>>(), &[
+    ///     0, 1, 2,
+    ///     3, 4, 5,
+    ///     3, 3, 3,
+    /// ]);
+    /// ```
+    pub fn lanes_mut_disjoint(
+        &mut self,
+        axis: usize,
+        i: usize,
+        j: usize,
+    ) -> (
+        impl ExactSizeIterator<Item = &mut T>,
+        impl ExactSizeIterator<Item = &mut T>,
+    ) {
+        assert_shape_index!(axis, N);
+        assert_slice_index!(self, axis, i);
+        assert_slice_index!(self, axis, j);
+        assert!(
+            i != j,
+            "lanes of axis {} are not disjoint (index {})",
+            axis,
+            i
+        );
+
+        let len = self.slice_len(axis);
+        let ranges = |index| {
+            let spans = self.spans_axis_bound(axis, BoundSpan::new(index, 1, self.shape[axis]));
+            IndexIterator::new_bound_contiguous(spans)
+                .into_flat_ranges(&self.strides)
+                .collect::<Vec<_>>()
+        };
+        let (ranges_i, ranges_j) = (ranges(i), ranges(j));
+        let n = ranges_i.len();
+
+        let ranges = ranges_i.into_iter().chain(ranges_j);
+        let mut slices_i = split_ranges_mut(self.array.as_mut(), ranges);
+        let slices_j = slices_i.split_off(n);
+
+        let iter_i = slices_i.into_iter().flat_map(|slice| slice.iter_mut());
+        let iter_j = slices_j.into_iter().flat_map(|slice| slice.iter_mut());
+
+        (
+            CircularArrayIteratorMut::new(iter_i, len),
+            CircularArrayIteratorMut::new(iter_j, len),
+        )
     }
 }
 
@@ -442,7 +901,7 @@ impl<'a, const N: usize, A: AsRef<[T]> + AsMut<[T]>, T: Clone + 'a> CircularMut<
     fn get_mut(&mut self, mut index: [usize; N]) -> &mut T {
         index.iter_mut().enumerate().for_each(|(i, idx)| {
             assert_slice_index!(self, i, *idx);
-            *idx = (*idx + self.offset[i]) % (self.shape[i]);
+            *idx = self.wrap_index(i, *idx + self.offset[i]);
         });
 
         &mut self.array.as_mut()[self.strides.offset_index(index)]
@@ -633,7 +1092,7 @@ impl<'a, const N: usize, A: AsRef<[T]> + AsMut<[T]>, T: Clone + 'a> CircularMut<
 
             self.push_iter(IndexIterator::new_unbound(spans), iter);
             self.decr_offset(axis, n);
-        }   
+        }
     }
     
     fn translate_front<'b, F>(
@@ -671,7 +1130,7 @@ impl<'a, const N: usize, A: AsRef<[T]> + AsMut<[T]>, T: Clone + 'a> CircularMut<
                 let src = IndexIterator::new_unbound(self.spans_axis_bound_raw(axis, src_span));
                 let dst = IndexIterator::new_bound(self.spans_axis_bound(axis, dst_span));
 
-                self.translate(src, dst, origin, el_fn);
+                self.translate_spans(src, dst, origin, el_fn);
                 self.incr_offset(axis, n);
             }
         }
@@ -714,14 +1173,14 @@ impl<'a, const N: usize, A: AsRef<[T]> + AsMut<[T]>, T: Clone + 'a> CircularMut<
                 let src = IndexIterator::new_unbound(self.spans_axis_bound_raw(axis, src_span));
                 let dst = IndexIterator::new_bound(self.spans_axis_bound(axis, dst_span));
 
-                self.translate(src, dst, origin, el_fn);
+                self.translate_spans(src, dst, origin, el_fn);
                 self.decr_offset(axis, n);
             }
         }
     }
 }
 
-impl<'a, const N: usize, A: AsRef<[T]> + AsMut<[T]>, T: Clone + 'a> IndexMut<[usize; N]>
+impl<const N: usize, A: AsRef<[T]> + AsMut<[T]>, T: Clone> IndexMut<[usize; N]>
     for CircularArray<N, A, T>
 {
     fn index_mut(&mut self, index: [usize; N]) -> &mut Self::Output {
@@ -957,6 +1416,232 @@ mod tests {
         ]);
     }
 
+    #[test]
+    fn push_transposed() {
+        let shape = [3, 4, 3];
+        let n = shape.iter().product::<usize>();
+
+        for axis in 0..3 {
+            // Elements of 2 slices in row-major order, and transposed order.
+            let mut el_shape = shape;
+            el_shape[axis] = 2;
+            let el = (100..100 + el_shape.iter().product::<usize>()).collect::<Vec<_>>();
+            let mut el_t = vec![0; el.len()];
+
+            let [x_len, y_len, z_len] = el_shape;
+            for z in 0..z_len {
+                for y in 0..y_len {
+                    for x in 0..x_len {
+                        let i = x + x_len * (y + y_len * z);
+                        let i_t = match axis {
+                            0 => i,
+                            1 => y + y_len * (x + x_len * z),
+                            _ => z + z_len * (x + x_len * y),
+                        };
+                        el_t[i_t] = el[i];
+                    }
+                }
+            }
+
+            let mut m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..n);
+            let mut m_t = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..n);
+            m.push_front(axis, &el);
+            m_t.push_front_transposed(axis, &el_t);
+            assert_eq!(m.array, m_t.array);
+            assert_eq!(m.offset, m_t.offset);
+
+            m.push_back(axis, &el);
+            m_t.push_back_transposed(axis, &el_t);
+            assert_eq!(m.array, m_t.array);
+            assert_eq!(m.offset, m_t.offset);
+        }
+    }
+
+    #[test]
+    fn indexed_iter() {
+        let shape = [4, 3, 2];
+        let mut m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..shape.iter().product());
+
+        assert_eq!(m.indexed_iter().len(), 24);
+        m.indexed_iter()
+            .for_each(|(index, el)| assert_eq!(m.get(index), el));
+
+        assert_eq!(m.indexed_iter_mut().len(), 24);
+        m.indexed_iter_mut()
+            .for_each(|([x, y, z], el)| *el = x + 4 * (y + 3 * z));
+        assert_eq!(
+            m.iter().cloned().collect::<Vec<_>>(),
+            (0..24).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn get_wrapped() {
+        let shape = [4, 3, 2];
+        let mut m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..shape.iter().product());
+
+        for (index, el) in m.indexed_iter() {
+            let [x, y, z] = index.map(|i| i as isize);
+            assert_eq!(m.get_wrapped([x, y, z]), el);
+            assert_eq!(m.get_wrapped([x - 4, y + 6, z - 10]), el);
+        }
+
+        *m.get_wrapped_mut([-1, -1, -1]) = usize::MAX;
+        assert_eq!(m.get([3, 2, 1]), &usize::MAX);
+    }
+
+    #[test]
+    fn get_disjoint_mut() {
+        let shape = [4, 3, 2];
+        let mut m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..shape.iter().product());
+        let expected = m.clone();
+
+        let [a, b, c] = m
+            .get_disjoint_mut([[0, 0, 0], [3, 2, 1], [1, 2, 0]])
+            .unwrap();
+        assert_eq!(a, expected.get([0, 0, 0]));
+        assert_eq!(b, expected.get([3, 2, 1]));
+        assert_eq!(c, expected.get([1, 2, 0]));
+        std::mem::swap(a, b);
+        *c = usize::MAX;
+
+        assert_eq!(m.get([0, 0, 0]), expected.get([3, 2, 1]));
+        assert_eq!(m.get([3, 2, 1]), expected.get([0, 0, 0]));
+        assert_eq!(m.get([1, 2, 0]), &usize::MAX);
+
+        assert!(m.get_disjoint_mut([[0, 1, 0], [0, 1, 0]]).is_none());
+        assert!(m.get_disjoint_mut([[0, 3, 0]]).is_none());
+        assert!(m.get_disjoint_mut([]).is_some());
+    }
+
+    #[test]
+    fn iter_slice_dst_ordered() {
+        let shape = [4, 3, 2];
+        let mut m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..shape.iter().product());
+        let slice = [1..4, 0..3, 0..2];
+        let src = (100..118).collect::<Vec<_>>();
+
+        let mut prev = None;
+        for (i, dst) in m.iter_slice_dst_ordered(slice.clone()) {
+            let ptr = dst.as_ptr();
+            assert!(prev.is_none_or(|prev| prev < ptr));
+            prev = Some(ptr);
+
+            dst.clone_from_slice(&src[i..i + dst.len()]);
+        }
+
+        assert_eq!(m.iter_slice(slice).cloned().collect::<Vec<_>>(), src);
+        assert_eq!(m.iter_slice_dst_ordered([0..0, 0..3, 0..2]).count(), 0);
+    }
+
+    #[test]
+    fn push_many() {
+        let shape = [4, 3, 2];
+        let mut m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..shape.iter().product());
+        let mut expected =
+            CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..shape.iter().product());
+
+        let (el_0, el_1, el_2) = (vec![24; 6], vec![25; 8], vec![26; 12]);
+        m.push_many([(0, &el_0[..]), (1, &el_1[..]), (2, &el_2[..]), (0, &[][..])]);
+        expected.push_front(0, &el_0);
+        expected.push_front(1, &el_1);
+        expected.push_front(2, &el_2);
+
+        assert_eq!(m.array, expected.array);
+        assert_eq!(m.offset, expected.offset);
+
+        // Overlapping pushes of multiple slices, and of a whole axis.
+        let el = (100..124).collect::<Vec<_>>();
+        let pushes = [(1, &el[..16]), (0, &el[..12]), (2, &el[..]), (1, &el[..8])];
+        m.push_many(pushes);
+        pushes
+            .iter()
+            .for_each(|(axis, el)| expected.push_front(*axis, el));
+
+        assert_eq!(m.array, expected.array);
+        assert_eq!(m.offset, expected.offset);
+    }
+
+    #[test]
+    fn push_many_invalid() {
+        let mut m = CircularArrayVec::from_iter([3, 3], 0..9);
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            m.push_many([(0, &[9, 10, 11][..]), (1, &[12, 13][..])]);
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(m.array, (0..9).collect::<Vec<_>>());
+        assert_eq!(m.offset, [0, 0]);
+    }
+
+    #[test]
+    fn lanes_mut_disjoint() {
+        let shape = [4, 3, 2];
+        let n = shape.iter().product();
+
+        for axis in 0..3 {
+            let mut m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..n);
+            let mut expected = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..n);
+            let last = m.shape()[axis] - 1;
+
+            let (a, b) = m.lanes_mut_disjoint(axis, last, 0);
+            assert_eq!(a.len(), expected.slice_len(axis));
+            assert_eq!(b.len(), expected.slice_len(axis));
+            a.zip(b).for_each(|(a, b)| std::mem::swap(a, b));
+
+            let a = expected.iter_index(axis, last).cloned().collect::<Vec<_>>();
+            let b = expected.iter_index(axis, 0).cloned().collect::<Vec<_>>();
+            expected
+                .iter_index_mut(axis, 0)
+                .zip(a)
+                .for_each(|(el, a)| *el = a);
+            expected
+                .iter_index_mut(axis, last)
+                .zip(b)
+                .for_each(|(el, b)| *el = b);
+
+            assert_eq!(m.array, expected.array);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn lanes_mut_disjoint_same() {
+        let mut m = CircularArrayVec::from_iter([3, 3], 0..9);
+        let _ = m.lanes_mut_disjoint(0, 1, 1);
+    }
+
+    #[test]
+    fn zero_sized() {
+        let shape = [4, 3, 2];
+        let mut m =
+            CircularArrayVec::from_iter_offset(shape, [1, 2, 1], std::iter::repeat_n((), 24));
+        let src = [(); 24];
+
+        for axis in 0..3 {
+            for n in 0..=shape[axis] {
+                let el = &src[..n * m.slice_len(axis)];
+                m.push_front(axis, el);
+                m.push_back(axis, el);
+                m.push_front_raw(axis, el);
+                m.translate_front(axis, n, [0; 3], |slice: [Range<usize>; 3]| {
+                    &src[..slice.iter().map(|range| range.len()).product()]
+                });
+                m.translate_back(axis, n, [8; 3], |slice: [Range<usize>; 3]| {
+                    &src[..slice.iter().map(|range| range.len()).product()]
+                });
+
+                assert_eq!(m.iter().len(), 24);
+                assert_eq!(m.iter().count(), 24);
+                assert_eq!(m.iter_index(axis, 0).count(), m.slice_len(axis));
+                assert_eq!(m.iter_slice([1..3, 0..2, 1..2]).count(), 4);
+                assert_eq!(m.get([3, 2, 1]), &());
+                *m.get_mut([3, 2, 1]) = ();
+            }
+        }
+        assert!(m.offset()[0] < 4);
+    }
+
     #[cfg(feature = "strides")]
     mod translate_front {
         use super::*;
@@ -112,3 +112,22 @@ macro_rules! assert_origin_bounds {
         );
     };
 }
+
+/// Assert axes are a permutation of the array axes.
+macro_rules! assert_permutation {
+    (
+        $axes:ident,
+        $N:ident
+    ) => {
+        let mut permuted = [false; $N];
+        for axis in $axes {
+            assert_shape_index!(axis, $N);
+            assert!(
+                !permuted[axis],
+                "axes {:?} are not a permutation of the array axes",
+                $axes
+            );
+            permuted[axis] = true;
+        }
+    };
+}
@@ -0,0 +1,155 @@
+use std::ops::{Bound, Range, RangeBounds};
+
+use crate::array_index::CircularIndex;
+use crate::CircularArray;
+
+/// A range of indices of a single axis, convertible to a `Range<usize>` given the
+/// length of the axis. Implemented for all `RangeBounds<usize>`, such as `..`,
+/// `1..`, `..2` and `1..=2`.
+pub trait AxisSlice {
+    /// Convert to a `Range<usize>` of an axis of length `len`.
+    fn to_range(&self, len: usize) -> Range<usize>;
+}
+
+impl<R: RangeBounds<usize>> AxisSlice for R {
+    fn to_range(&self, len: usize) -> Range<usize> {
+        let start = match self.start_bound() {
+            Bound::Included(start) => *start,
+            Bound::Excluded(start) => start.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let end = match self.end_bound() {
+            Bound::Included(end) => end.saturating_add(1),
+            Bound::Excluded(end) => *end,
+            Bound::Unbounded => len,
+        };
+
+        start..end
+    }
+}
+
+/// A slice of `N` axes, convertible to `[Range<usize>; N]` given the shape of an
+/// array. Implemented for arrays of [`AxisSlice`], and tuples of up to 6
+/// [`AxisSlice`] of differing types, such as `(.., 1..=2, 0..)`.
+pub trait IntoSlice<const N: usize> {
+    /// Convert to `[Range<usize>; N]` of an array of the given `shape`.
+    fn into_slice(self, shape: &[usize; N]) -> [Range<usize>; N];
+}
+
+impl<const N: usize, S: AxisSlice> IntoSlice<N> for [S; N] {
+    fn into_slice(self, shape: &[usize; N]) -> [Range<usize>; N] {
+        std::array::from_fn(|i| self[i].to_range(shape[i]))
+    }
+}
+
+/// Implement [`IntoSlice`] for tuples of `$n` [`AxisSlice`].
+macro_rules! impl_into_slice {
+    ($n:literal, $(($s:ident, $i:tt)),+) => {
+        impl<$($s: AxisSlice),+> IntoSlice<$n> for ($($s,)+) {
+            fn into_slice(self, shape: &[usize; $n]) -> [Range<usize>; $n] {
+                [$(self.$i.to_range(shape[$i])),+]
+            }
+        }
+    };
+}
+
+impl_into_slice!(1, (A, 0));
+impl_into_slice!(2, (A, 0), (B, 1));
+impl_into_slice!(3, (A, 0), (B, 1), (C, 2));
+impl_into_slice!(4, (A, 0), (B, 1), (C, 2), (D, 3));
+impl_into_slice!(5, (A, 0), (B, 1), (C, 2), (D, 3), (E, 4));
+impl_into_slice!(6, (A, 0), (B, 1), (C, 2), (D, 3), (E, 4), (F, 5));
+
+impl<const N: usize, A: AsRef<[T]>, T> CircularArray<N, A, T> {
+    /// Convert the given `slice` to `[Range<usize>; N]` of the array shape, for
+    /// use with slicing operations such as
+    /// [`CircularIndex::iter_slice`](crate::CircularIndex::iter_slice). Each
+    /// range **must** lie within the length of its axis. See
+    /// [`CircularArray::iter_slice_bounds`] for iterating a slice directly.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArrayVec, CircularIndex};
+    /// let array = CircularArrayVec::from_iter([3, 3, 2], 0..18);
+    /// let slice = array.to_slice((.., 1..=2, 1..));
+    ///
+    /// assert_eq!(slice, [0..3, 1..3, 1..2]);
+    /// assert_eq!(array.iter_slice(slice).cloned().collect::<Vec<_>>(), &[
+    ///     12, 13, 14,
+    ///     15, 16, 17,
+    /// ]);
+    /// ```
+    pub fn to_slice(&self, slice: impl IntoSlice<N>) -> [Range<usize>; N] {
+        let slice = slice.into_slice(&self.shape);
+        slice.iter().enumerate().for_each(|(i, range)| {
+            assert!(
+                range.start <= range.end && range.end <= self.shape[i],
+                "range {:?} is out of bounds for axis {} of length {}",
+                range,
+                i,
+                self.shape[i]
+            );
+        });
+
+        slice
+    }
+
+    /// Iterate over all elements within the given `slice` of any
+    /// [`RangeBounds<usize>`](std::ops::RangeBounds) per axis, aligned to the
+    /// offset. Equivalent to [`CircularIndex::iter_slice`] of
+    /// [`CircularArray::to_slice`].
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArrayVec, CircularIndex};
+    /// let array = CircularArrayVec::from_iter([3, 3, 2], 0..18);
+    ///
+    /// assert_eq!(array.iter_slice_bounds((.., 1..=2, 1..)).cloned().collect::<Vec<_>>(), &[
+    ///     12, 13, 14,
+    ///     15, 16, 17,
+    /// ]);
+    /// ```
+    pub fn iter_slice_bounds(&self, slice: impl IntoSlice<N>) -> impl ExactSizeIterator<Item = &T> {
+        self.iter_slice(self.to_slice(slice))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::Bound;
+
+    use crate::{CircularArrayVec, CircularIndex};
+
+    #[test]
+    fn to_slice() {
+        let m = CircularArrayVec::from_iter_offset([4, 3, 2], [1, 2, 1], 0..24);
+
+        assert_eq!(m.to_slice([.., .., ..]), [0..4, 0..3, 0..2]);
+        assert_eq!(m.to_slice((1.., ..2, 1..=1)), [1..4, 0..2, 1..2]);
+        assert_eq!(
+            m.to_slice((..=2, 0..3, (Bound::Excluded(0), Bound::Unbounded))),
+            [0..3, 0..3, 1..2]
+        );
+        assert!(m
+            .slice((1.., ..=1, ..))
+            .iter()
+            .eq(m.iter_slice([1..4, 0..2, 0..2])));
+        assert!(m
+            .iter_slice_bounds((.., 1..=2, ..))
+            .eq(m.iter_slice([0..4, 1..3, 0..2])));
+    }
+
+    #[test]
+    #[should_panic(expected = "is out of bounds for axis 0 of length 4")]
+    fn to_slice_excluded_max() {
+        let m = CircularArrayVec::from_iter([4, 3], 0..12);
+        m.to_slice(((Bound::Excluded(usize::MAX), Bound::Unbounded), ..));
+    }
+
+    #[test]
+    #[should_panic(expected = "is out of bounds for axis 1 of length 3")]
+    fn to_slice_inclusive_max() {
+        let m = CircularArrayVec::from_iter([4, 3], 0..12);
+        let _ = m.iter_slice_bounds((.., ..=usize::MAX));
+    }
+}
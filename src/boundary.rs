@@ -0,0 +1,25 @@
+/// The treatment of indices beyond the bounds of an array. See
+/// [`CircularArray::translate_from_bounded`](crate::CircularArray::translate_from_bounded),
+/// and `CircularArray::convolve` (requires feature flag `stencil`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Boundary<T> {
+    /// Indices wrap over the bounds of each axis (periodic).
+    Wrap,
+    /// Indices are clamped to the first or last index of each axis.
+    Clamp,
+    /// Elements beyond the bounds of the array take the given value.
+    Constant(T),
+}
+
+impl<T> Boundary<T> {
+    /// Resolve the signed `index` of an axis of length `len`. Returns `None` where
+    /// the index is out of bounds and the boundary is [`Boundary::Constant`].
+    pub(crate) fn resolve(&self, index: isize, len: usize) -> Option<usize> {
+        match (self, (0..len as isize).contains(&index)) {
+            (_, true) => Some(index as usize),
+            (Boundary::Wrap, false) => Some(index.rem_euclid(len as isize) as usize),
+            (Boundary::Clamp, false) => Some(index.clamp(0, len as isize - 1) as usize),
+            (Boundary::Constant(_), false) => None,
+        }
+    }
+}
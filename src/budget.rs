@@ -0,0 +1,309 @@
+use std::ops::Range;
+
+use crate::array_mut::CircularMut;
+use crate::CircularArray;
+
+/// A resumable position within a bulk operation performed over several calls.
+///
+/// Budgeted operations process a bounded number of elements per call, returning
+/// a `Continuation` until the operation is complete. A `Continuation` **must** be
+/// resumed with the same operation and arguments, without otherwise mutating the
+/// array.
+///
+/// See [`CircularArray::apply_with_budget`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Continuation {
+    /// The number of units (elements or slices) processed.
+    position: usize,
+}
+
+impl Continuation {
+    /// Create a `Continuation` at the start of an operation.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the number of units processed. Units are elements or slices, as
+    /// documented by the operation.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl<const N: usize, A: AsRef<[T]> + AsMut<[T]>, T> CircularArray<N, A, T> {
+    /// Mutate up to `budget` elements of the array by `f`, in raw order, resuming
+    /// from `cont`. Returns a [`Continuation`] if elements remain, or `None` if the
+    /// operation is complete. At least one element is processed per call. See
+    /// [`CircularArray::apply`].
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex, Continuation};
+    /// let mut array = CircularArray::new([3, 2], vec![0, 1, 2, 3, 4, 5]);
+    ///
+    /// let cont = array.apply_with_budget(Continuation::new(), 4, |el| *el *= 10);
+    /// assert_eq!(array.data(), &[0, 10, 20, 30, 4, 5]);
+    ///
+    /// let cont = array.apply_with_budget(cont.unwrap(), 4, |el| *el *= 10);
+    /// assert_eq!(array.data(), &[0, 10, 20, 30, 40, 50]);
+    /// assert!(cont.is_none());
+    /// ```
+    pub fn apply_with_budget<F: FnMut(&mut T)>(
+        &mut self,
+        cont: Continuation,
+        budget: usize,
+        f: F,
+    ) -> Option<Continuation> {
+        let len = self.len();
+        let range = budget_range(cont, budget, len);
+
+        self.array.as_mut()[range.clone()].iter_mut().for_each(f);
+
+        resume(range.end, len)
+    }
+}
+
+impl<const N: usize, A: AsRef<[T]> + AsMut<[T]>, T: Clone> CircularArray<N, A, T> {
+    /// Translate the array by `n` on the given `axis`, inserting up to `budget`
+    /// elements (rounded down to whole slices of `axis`) to the **front** of the
+    /// array, resuming from `cont`. Returns a [`Continuation`] if slices remain, or
+    /// `None` if the translation is complete. At least one slice is inserted per
+    /// call. See [`CircularMut::translate_front`].
+    ///
+    /// The `origin` **must** be the origin prior to the translation. Between calls,
+    /// the array is translated by the slices inserted so far. Where `n` exceeds the
+    /// length of `axis`, slices evicted within the translation are skipped.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::ops::Range;
+    /// # use n_circular_array::{CircularArray, CircularIndex, Continuation};
+    /// let src = [0, 1, 2, 3, 4, 5, 6, 7];
+    /// let el_fn = |[index]: [Range<usize>; 1]| &src[index];
+    ///
+    /// let mut array = CircularArray::new([3], vec![0, 1, 2]);
+    ///
+    /// let cont = array.translate_front_with_budget(0, 3, [0], Continuation::new(), 2, el_fn);
+    /// assert_eq!(array.iter().cloned().collect::<Vec<_>>(), &[2, 3, 4]);
+    ///
+    /// let cont = array.translate_front_with_budget(0, 3, [0], cont.unwrap(), 2, el_fn);
+    /// assert_eq!(array.iter().cloned().collect::<Vec<_>>(), &[3, 4, 5]);
+    /// assert!(cont.is_none());
+    /// ```
+    pub fn translate_front_with_budget<'b, F>(
+        &mut self,
+        axis: usize,
+        n: usize,
+        mut origin: [usize; N],
+        cont: Continuation,
+        budget: usize,
+        mut el_fn: F,
+    ) -> Option<Continuation>
+    where
+        T: 'b,
+        F: FnMut([Range<usize>; N]) -> &'b [T],
+    {
+        assert_shape_index!(axis, N);
+
+        // Slices evicted within the translation are skipped.
+        let len = n.min(self.shape[axis]);
+        let range = self.budget_slices(axis, cont, budget, len);
+        origin[axis] += n - len + range.start;
+        self.translate_front(axis, range.len(), origin, &mut el_fn);
+
+        resume(range.end, len)
+    }
+
+    /// Translate the array by `-n` on the given `axis`, inserting up to `budget`
+    /// elements (rounded down to whole slices of `axis`) to the **back** of the
+    /// array, resuming from `cont`. Returns a [`Continuation`] if slices remain, or
+    /// `None` if the translation is complete. At least one slice is inserted per
+    /// call. See [`CircularMut::translate_back`].
+    ///
+    /// The `origin` **must** be the origin prior to the translation. Between calls,
+    /// the array is translated by the slices inserted so far. Where `n` exceeds the
+    /// length of `axis`, slices evicted within the translation are skipped.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::ops::Range;
+    /// # use n_circular_array::{CircularArray, CircularIndex, Continuation};
+    /// let src = [0, 1, 2, 3, 4, 5, 6, 7];
+    /// let el_fn = |[index]: [Range<usize>; 1]| &src[index];
+    ///
+    /// let mut array = CircularArray::new([3], vec![5, 6, 7]);
+    ///
+    /// let cont = array.translate_back_with_budget(0, 3, [5], Continuation::new(), 2, el_fn);
+    /// assert_eq!(array.iter().cloned().collect::<Vec<_>>(), &[3, 4, 5]);
+    ///
+    /// let cont = array.translate_back_with_budget(0, 3, [5], cont.unwrap(), 2, el_fn);
+    /// assert_eq!(array.iter().cloned().collect::<Vec<_>>(), &[2, 3, 4]);
+    /// assert!(cont.is_none());
+    /// ```
+    pub fn translate_back_with_budget<'b, F>(
+        &mut self,
+        axis: usize,
+        n: usize,
+        mut origin: [usize; N],
+        cont: Continuation,
+        budget: usize,
+        mut el_fn: F,
+    ) -> Option<Continuation>
+    where
+        T: 'b,
+        F: FnMut([Range<usize>; N]) -> &'b [T],
+    {
+        assert_shape_index!(axis, N);
+        assert!(
+            origin[axis] >= n,
+            "translation -{} is out of bounds for axis {}, origin {:?}",
+            n,
+            axis,
+            origin,
+        );
+
+        // Slices evicted within the translation are skipped.
+        let len = n.min(self.shape[axis]);
+        let range = self.budget_slices(axis, cont, budget, len);
+        origin[axis] -= n - len + range.start;
+        self.translate_back(axis, range.len(), origin, &mut el_fn);
+
+        resume(range.end, len)
+    }
+
+    /// Get the range of slices of `axis` processed within `budget` elements,
+    /// resuming from `cont`.
+    fn budget_slices(
+        &self,
+        axis: usize,
+        cont: Continuation,
+        budget: usize,
+        n: usize,
+    ) -> Range<usize> {
+        let slice_len = self.slice_len(axis).max(1);
+        budget_range(cont, budget / slice_len, n)
+    }
+}
+
+/// Get the range of units processed within `budget`, resuming from `cont`. At
+/// least one unit is processed where units remain.
+fn budget_range(cont: Continuation, budget: usize, len: usize) -> Range<usize> {
+    let start = cont.position;
+    assert!(
+        start <= len,
+        "continuation {} is out of bounds for length {}",
+        start,
+        len
+    );
+
+    start..start + budget.max(1).min(len - start)
+}
+
+/// Get a [`Continuation`] at `position`, or `None` if the operation is complete.
+fn resume(position: usize, len: usize) -> Option<Continuation> {
+    (position < len).then_some(Continuation { position })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::Range;
+
+    use crate::strides::Strides;
+    use crate::{CircularArrayVec, CircularIndex, CircularMut, Continuation};
+
+    #[test]
+    fn apply_with_budget() {
+        let shape = [4, 3, 2];
+        let len = shape.iter().product::<usize>();
+
+        for budget in 0..len + 2 {
+            let mut m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..len);
+            let mut cont = Some(Continuation::new());
+            let mut calls = 0;
+
+            while let Some(c) = cont {
+                cont = m.apply_with_budget(c, budget, |el| *el += 100);
+                calls += 1;
+            }
+
+            assert_eq!(calls, len.div_ceil(budget.max(1)));
+            assert!(m.data().iter().cloned().eq(100..100 + len));
+        }
+    }
+
+    #[test]
+    fn translate_with_budget() {
+        let src_shape = [16, 16, 16];
+        let src = (0..src_shape.iter().product()).collect::<Vec<usize>>();
+        let src_strides = Strides::new(&src_shape);
+        let el_fn = |index: [Range<usize>; 3]| &src[src_strides.flatten_range(index)];
+
+        let shape = [4, 3, 2];
+        let origin = [6, 6, 6];
+        let init = || {
+            let mut m = CircularArrayVec::from_iter(shape, 0..shape.iter().product());
+            m.translate_front(0, 6, [0, 6, 6], el_fn);
+            m
+        };
+
+        for axis in 0..3 {
+            for n in 0..6 {
+                for budget in [0, 5, 6, 13] {
+                    let mut m = init();
+                    let mut expected = init();
+                    let mut cont = Some(Continuation::new());
+                    while let Some(c) = cont {
+                        cont = m.translate_front_with_budget(axis, n, origin, c, budget, el_fn);
+                    }
+                    expected.translate_front(axis, n, origin, el_fn);
+                    assert!(m.iter().eq(expected.iter()));
+
+                    let mut m = init();
+                    let mut expected = init();
+                    let mut cont = Some(Continuation::new());
+                    while let Some(c) = cont {
+                        cont = m.translate_back_with_budget(axis, n, origin, c, budget, el_fn);
+                    }
+                    expected.translate_back(axis, n, origin, el_fn);
+                    assert!(m.iter().eq(expected.iter()));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn translate_with_budget_evicted() {
+        let src = (0..64).collect::<Vec<usize>>();
+        let mut m = CircularArrayVec::from_iter([4], 0..4);
+        let mut requested = 0;
+        let mut cont = Some(Continuation::new());
+
+        while let Some(c) = cont {
+            cont = m.translate_front_with_budget(0, 40, [0], c, 1, |[index]| {
+                requested += index.len();
+                &src[index]
+            });
+        }
+        assert_eq!(requested, 4);
+        assert!(m.iter().cloned().eq(40..44));
+
+        let mut requested = 0;
+        let mut cont = Some(Continuation::new());
+        while let Some(c) = cont {
+            cont = m.translate_back_with_budget(0, 30, [40], c, 3, |[index]| {
+                requested += index.len();
+                &src[index]
+            });
+        }
+        assert_eq!(requested, 4);
+        assert!(m.iter().cloned().eq(10..14));
+    }
+
+    #[test]
+    #[should_panic]
+    fn continuation_bounds() {
+        let mut m = CircularArrayVec::from_iter([3, 3], 0..9);
+        let cont = m.apply_with_budget(Continuation::new(), 8, |_| ());
+        CircularArrayVec::from_iter([2, 2], 0..4).apply_with_budget(cont.unwrap(), 1, |_| ());
+    }
+}
@@ -0,0 +1,123 @@
+use std::fmt::{self, Display};
+
+use bytemuck::{Pod, PodCastError};
+
+use crate::{CircularArray, ShapeError};
+
+/// An error reinterpreting bytes as a [`CircularArrayView`](crate::CircularArrayView).
+/// See [`CircularArray::try_from_bytes`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BytesError {
+    /// The bytes are misaligned, or not a multiple of the element size.
+    Cast(PodCastError),
+    /// The number of elements does not match the shape.
+    Shape(ShapeError),
+}
+
+impl Display for BytesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BytesError::Cast(e) => write!(f, "invalid element bytes: {}", e),
+            BytesError::Shape(e) => write!(f, "invalid shape: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for BytesError {}
+
+impl<const N: usize, A: AsRef<[T]>, T: Pod> CircularArray<N, A, T> {
+    /// Reinterpret the raw buffer as bytes, without copying. Elements are in raw
+    /// order, such that the offset must be stored alongside the bytes to restore
+    /// the array.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::CircularArray;
+    /// let array = CircularArray::new_offset([2], [1], vec![1u16, 2]);
+    ///
+    /// assert_eq!(array.as_bytes(), &[1, 0, 2, 0]);
+    /// ```
+    pub fn as_bytes(&self) -> &[u8] {
+        bytemuck::cast_slice(self.array.as_ref())
+    }
+}
+
+impl<const N: usize, A: AsRef<[T]> + AsMut<[T]>, T: Pod> CircularArray<N, A, T> {
+    /// Reinterpret the raw buffer as mutable bytes, without copying, such as for
+    /// reading into the array from a file or socket. See [`CircularArray::as_bytes`].
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let mut array = CircularArray::new([2], vec![0u16; 2]);
+    /// array.as_bytes_mut().copy_from_slice(&[1, 0, 2, 0]);
+    ///
+    /// assert_eq!(array.get([1]), &2);
+    /// ```
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+        bytemuck::cast_slice_mut(self.array.as_mut())
+    }
+}
+
+impl<'a, const N: usize, T: Pod> CircularArray<N, &'a [T], T> {
+    /// Reinterpret `bytes` as a [`CircularArrayView`](crate::CircularArrayView) of
+    /// the given `shape`, without copying. The view has an offset of `[0; N]`;
+    /// restore a stored offset with [`CircularArray::offset_mut`].
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularArrayView, CircularIndex};
+    /// let bytes = CircularArray::new_offset([2, 2], [1, 0], vec![1u8, 0, 3, 2]).as_bytes().to_vec();
+    /// let mut view = CircularArrayView::<2, u8>::try_from_bytes([2, 2], &bytes).unwrap();
+    /// *view.offset_mut() = [1, 0];
+    ///
+    /// assert_eq!(view.iter().cloned().collect::<Vec<_>>(), &[0, 1, 2, 3]);
+    /// ```
+    pub fn try_from_bytes(shape: [usize; N], bytes: &'a [u8]) -> Result<Self, BytesError> {
+        let array = bytemuck::try_cast_slice(bytes).map_err(BytesError::Cast)?;
+
+        CircularArray::try_new(shape, array).map_err(BytesError::Shape)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytemuck::PodCastError;
+
+    use crate::{BytesError, CircularArrayVec, CircularArrayView, CircularIndex, ShapeError};
+
+    #[test]
+    fn bytes() {
+        let shape = [4, 3, 2];
+        let mut m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..24u32);
+        let bytes = m.as_bytes().to_vec();
+        assert_eq!(bytes.len(), 24 * 4);
+
+        let mut view = CircularArrayView::<3, u32>::try_from_bytes(shape, &bytes).unwrap();
+        *view.offset_mut() = *m.offset();
+        assert!(view.iter().eq(m.iter()));
+
+        m.as_bytes_mut().fill(0);
+        assert!(m.iter().all(|el| *el == 0));
+    }
+
+    #[test]
+    fn bytes_errors() {
+        let bytes = vec![0u32; 7];
+        let bytes = bytemuck::cast_slice::<u32, u8>(&bytes);
+
+        assert_eq!(
+            CircularArrayView::<2, u32>::try_from_bytes([3, 2], bytes).err(),
+            Some(BytesError::Shape(ShapeError::Length {
+                expected: 6,
+                found: 7
+            }))
+        );
+        assert_eq!(
+            CircularArrayView::<1, u32>::try_from_bytes([1], &bytes[1..5]).err(),
+            Some(BytesError::Cast(
+                PodCastError::TargetAlignmentGreaterAndInputNotAligned
+            ))
+        );
+    }
+}
@@ -0,0 +1,132 @@
+use std::fmt::{self, Display};
+
+use crate::CircularArray;
+
+/// An error creating a [`CircularArray`]. See [`CircularArray::try_new`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ShapeError {
+    /// The product of the shape overflows `usize`.
+    ShapeOverflow,
+    /// The given axis is of length `0`.
+    ZeroLengthAxis(usize),
+    /// The offset of the given axis is out of bounds of the axis length.
+    Offset(usize),
+    /// The length of the buffer does not match the shape.
+    Length {
+        /// The number of elements of the shape.
+        expected: usize,
+        /// The length of the buffer.
+        found: usize,
+    },
+}
+
+impl Display for ShapeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShapeError::ShapeOverflow => write!(f, "shape overflows usize"),
+            ShapeError::ZeroLengthAxis(axis) => write!(f, "axis {} is of length 0", axis),
+            ShapeError::Offset(axis) => write!(f, "offset of axis {} is out of bounds", axis),
+            ShapeError::Length { expected, found } => {
+                write!(f, "expected {} elements (found {})", expected, found)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ShapeError {}
+
+impl<const N: usize, A: AsRef<[T]>, T> CircularArray<N, A, T> {
+    /// Create a new `CircularArray` from the given buffer, returning an error
+    /// rather than panicking where the shape is invalid. Unlike
+    /// [`CircularArray::new`], axes of length `0` are rejected, such that the
+    /// array is never empty.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, ShapeError};
+    /// assert!(CircularArray::try_new([3, 2], vec![0; 6]).is_ok());
+    /// assert_eq!(
+    ///     CircularArray::try_new([3, 0], Vec::<u8>::new()).err(),
+    ///     Some(ShapeError::ZeroLengthAxis(1))
+    /// );
+    /// assert_eq!(
+    ///     CircularArray::try_new([usize::MAX, 2], Vec::<u8>::new()).err(),
+    ///     Some(ShapeError::ShapeOverflow)
+    /// );
+    /// ```
+    pub fn try_new(shape: [usize; N], array: A) -> Result<Self, ShapeError> {
+        Self::try_new_offset(shape, [0; N], array)
+    }
+
+    /// Create a new `CircularArray` from the given buffer and `offset`, returning
+    /// an error rather than panicking where the shape or offset is invalid. See
+    /// [`CircularArray::try_new`].
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, ShapeError};
+    /// assert!(CircularArray::try_new_offset([3, 2], [2, 1], vec![0; 6]).is_ok());
+    /// assert_eq!(
+    ///     CircularArray::try_new_offset([3, 2], [3, 0], vec![0; 6]).err(),
+    ///     Some(ShapeError::Offset(0))
+    /// );
+    /// ```
+    pub fn try_new_offset(
+        shape: [usize; N],
+        offset: [usize; N],
+        array: A,
+    ) -> Result<Self, ShapeError> {
+        if let Some(axis) = shape.iter().position(|len| *len == 0) {
+            return Err(ShapeError::ZeroLengthAxis(axis));
+        }
+
+        let len = shape
+            .iter()
+            .try_fold(1usize, |acc, len| acc.checked_mul(*len))
+            .ok_or(ShapeError::ShapeOverflow)?;
+        if array.as_ref().len() != len {
+            return Err(ShapeError::Length {
+                expected: len,
+                found: array.as_ref().len(),
+            });
+        }
+
+        if let Some(axis) = (0..N).find(|axis| offset[*axis] >= shape[*axis]) {
+            return Err(ShapeError::Offset(axis));
+        }
+
+        Ok(CircularArray::new_offset(shape, offset, array))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CircularArray, CircularArrayVec, ShapeError};
+
+    #[test]
+    fn try_new() {
+        let m = CircularArray::try_new_offset([4, 3, 2], [1, 2, 1], Vec::from_iter(0..24)).unwrap();
+        assert_eq!(
+            m.data(),
+            CircularArrayVec::from_iter_offset([4, 3, 2], [1, 2, 1], 0..24).data()
+        );
+
+        let try_new =
+            |shape: [usize; 2], len: usize| CircularArray::try_new(shape, vec![0u8; len]).err();
+        assert_eq!(try_new([2, 0], 0), Some(ShapeError::ZeroLengthAxis(1)));
+        assert_eq!(try_new([usize::MAX, 3], 0), Some(ShapeError::ShapeOverflow));
+        assert_eq!(
+            try_new([2, 3], 5),
+            Some(ShapeError::Length {
+                expected: 6,
+                found: 5
+            })
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn new_overflow() {
+        CircularArray::new([usize::MAX, 2], Vec::<u8>::new());
+    }
+}
@@ -0,0 +1,243 @@
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
+use crate::array_index::CircularIndex;
+use crate::{CircularArray, CircularArrayVec};
+
+impl<const N: usize, A: AsRef<[T]>, T> CircularArray<N, A, T> {
+    /// Create a new [`CircularArrayVec`] of the [`Ordering`] of each element against
+    /// the element of `other` at the same index, aligned to the offset of each
+    /// array. Arrays **must** be of equal shape.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::cmp::Ordering;
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let a = CircularArray::new_offset([3], [1], vec![2, 0, 1]);
+    /// let b = CircularArray::new([3], vec![1, 1, 1]);
+    ///
+    /// assert_eq!(a.cmp_map(&b).iter().cloned().collect::<Vec<_>>(), &[
+    ///     Ordering::Less, Ordering::Equal, Ordering::Greater
+    /// ]);
+    /// ```
+    pub fn cmp_map<B: AsRef<[T]>>(
+        &self,
+        other: &CircularArray<N, B, T>,
+    ) -> CircularArrayVec<N, Ordering>
+    where
+        T: Ord,
+    {
+        self.zip_map(other, |a, b| a.cmp(b))
+    }
+
+    /// Create a new [`CircularArrayVec`] of the partial [`Ordering`] of each element
+    /// against the element of `other` at the same index, aligned to the offset of
+    /// each array. Arrays **must** be of equal shape.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::cmp::Ordering;
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let a = CircularArray::new([3], vec![0.0, 1.0, f32::NAN]);
+    /// let b = CircularArray::new([3], vec![0.5, 0.5, 0.5]);
+    ///
+    /// assert_eq!(a.partial_cmp_map(&b).iter().cloned().collect::<Vec<_>>(), &[
+    ///     Some(Ordering::Less), Some(Ordering::Greater), None
+    /// ]);
+    /// ```
+    pub fn partial_cmp_map<B: AsRef<[T]>>(
+        &self,
+        other: &CircularArray<N, B, T>,
+    ) -> CircularArrayVec<N, Option<Ordering>>
+    where
+        T: PartialOrd,
+    {
+        self.zip_map(other, |a, b| a.partial_cmp(b))
+    }
+
+    /// Create a new [`CircularArrayVec`] mapping each pair of elements of this
+    /// array and `other` by `f`, aligned to the offset of each array.
+    fn zip_map<B: AsRef<[T]>, U, F: FnMut(&T, &T) -> U>(
+        &self,
+        other: &CircularArray<N, B, T>,
+        mut f: F,
+    ) -> CircularArrayVec<N, U> {
+        assert!(
+            self.shape == other.shape,
+            "shape {:?} does not match shape {:?}",
+            self.shape,
+            other.shape
+        );
+
+        CircularArrayVec::from_iter(
+            self.shape,
+            self.iter().zip(other.iter()).map(|(a, b)| f(a, b)),
+        )
+    }
+
+    /// Iterate over all elements, aligned to the offset, yielding nothing for an
+    /// empty array.
+    fn iter_or_empty(&self) -> impl Iterator<Item = &T> {
+        (!self.is_empty())
+            .then(|| self.iter())
+            .into_iter()
+            .flatten()
+    }
+}
+
+/// Compare the logical contents of two arrays, aligned to the offset of each
+/// array. Arrays of differing shape are unequal.
+///
+/// # Example
+/// ```
+/// # use n_circular_array::CircularArray;
+/// let a = CircularArray::new_offset([3], [1], vec![2, 0, 1]);
+/// let b = CircularArray::new([3], vec![0, 1, 2]);
+///
+/// assert_eq!(a, b);
+/// assert_eq!(a, [0, 1, 2][..]);
+/// ```
+impl<const N: usize, A, B, T, U> PartialEq<CircularArray<N, B, U>> for CircularArray<N, A, T>
+where
+    A: AsRef<[T]>,
+    B: AsRef<[U]>,
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &CircularArray<N, B, U>) -> bool {
+        self.shape == other.shape && (self.is_empty() || self.iter().eq(other.iter()))
+    }
+}
+
+impl<const N: usize, A: AsRef<[T]>, T: Eq> Eq for CircularArray<N, A, T> {}
+
+/// Compare the logical contents of the array against a slice of equal length,
+/// aligned to the offset.
+impl<const N: usize, A, T, U> PartialEq<[U]> for CircularArray<N, A, T>
+where
+    A: AsRef<[T]>,
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &[U]) -> bool {
+        self.len() == other.len() && (self.is_empty() || self.iter().eq(other.iter()))
+    }
+}
+
+/// Compare the logical contents of the array against a slice of equal length,
+/// aligned to the offset.
+impl<const N: usize, A, T, U> PartialEq<&[U]> for CircularArray<N, A, T>
+where
+    A: AsRef<[T]>,
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &&[U]) -> bool {
+        *self == **other
+    }
+}
+
+/// Hash the shape and the logical contents of the array, aligned to the offset,
+/// consistent with [`PartialEq`].
+impl<const N: usize, A: AsRef<[T]>, T: Hash> Hash for CircularArray<N, A, T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.shape.hash(state);
+        self.iter_or_empty().for_each(|el| el.hash(state));
+    }
+}
+
+/// Lexicographically compare the logical contents of two arrays, aligned to the
+/// offset of each array. Arrays of equal contents are ordered by shape.
+///
+/// # Example
+/// ```
+/// # use n_circular_array::CircularArray;
+/// let a = CircularArray::new_offset([3], [1], vec![2, 0, 1]);
+/// let b = CircularArray::new([3], vec![0, 2, 1]);
+///
+/// assert!(a < b);
+/// ```
+impl<const N: usize, A, B, T> PartialOrd<CircularArray<N, B, T>> for CircularArray<N, A, T>
+where
+    A: AsRef<[T]>,
+    B: AsRef<[T]>,
+    T: PartialOrd,
+{
+    fn partial_cmp(&self, other: &CircularArray<N, B, T>) -> Option<Ordering> {
+        match self.iter_or_empty().partial_cmp(other.iter_or_empty()) {
+            Some(Ordering::Equal) => Some(self.shape.cmp(&other.shape)),
+            ord => ord,
+        }
+    }
+}
+
+impl<const N: usize, A: AsRef<[T]>, T: Ord> Ord for CircularArray<N, A, T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter_or_empty()
+            .cmp(other.iter_or_empty())
+            .then_with(|| self.shape.cmp(&other.shape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+    use std::collections::HashSet;
+
+    use crate::{CircularArrayVec, CircularIndex};
+
+    #[test]
+    fn cmp_map() {
+        let shape = [4, 3, 2];
+        let a = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..24);
+        let b = CircularArrayVec::from_iter_offset(shape, [3, 0, 1], 0..24);
+        let map = a.cmp_map(&b);
+
+        assert_eq!(map.shape(), &shape);
+        map.indexed_iter()
+            .for_each(|(index, ord)| assert_eq!(*ord, a.get(index).cmp(b.get(index))));
+        assert!(a.cmp_map(&a).iter().all(|ord| *ord == Ordering::Equal));
+    }
+
+    #[test]
+    #[should_panic]
+    fn cmp_map_shape() {
+        let a = CircularArrayVec::from_iter([2, 3], 0..6);
+        let b = CircularArrayVec::from_iter([3, 2], 0..6);
+        a.cmp_map(&b);
+    }
+
+    #[test]
+    fn eq() {
+        let shape = [4, 3, 2];
+        let a = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..24);
+        let b = CircularArrayVec::from_iter(shape, a.iter().cloned());
+        let c = CircularArrayVec::from_iter_offset(shape, [3, 0, 1], 0..24);
+
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert_eq!(a, b.data().as_slice());
+        assert_ne!(a, a.data().as_slice());
+        assert_ne!(a, CircularArrayVec::from_iter([2, 6, 2], b.iter().cloned()));
+        assert_ne!(a, &b.data()[1..]);
+        assert_eq!(
+            CircularArrayVec::<2, u8>::from_iter([0, 2], 0..0),
+            CircularArrayVec::<2, u8>::from_iter([0, 2], 0..0)
+        );
+    }
+
+    #[test]
+    fn hash_ord() {
+        let shape = [4, 3, 2];
+        let a = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..24);
+        let b = CircularArrayVec::from_iter(shape, a.iter().cloned());
+        let c = CircularArrayVec::from_iter_offset(shape, [3, 0, 1], 0..24);
+        let d = CircularArrayVec::from_iter([2, 6, 2], a.iter().cloned());
+
+        let set = HashSet::from([a.clone(), b.clone(), c.clone(), d.clone()]);
+        assert_eq!(set.len(), 3);
+        assert!(set.contains(&b));
+
+        assert_eq!(a.cmp(&b), Ordering::Equal);
+        assert_eq!(a.cmp(&c), a.iter().cmp(c.iter()));
+        assert_eq!(a.cmp(&d), Ordering::Greater);
+        assert_eq!(a.partial_cmp(&d), Some(Ordering::Greater));
+    }
+}
@@ -0,0 +1,301 @@
+use crate::index::RawIndexAdaptor;
+use crate::index_iter::IndexIterator;
+use crate::span::BoundSpan;
+use crate::CircularArray;
+
+impl<const N: usize, A: AsRef<[T]> + AsMut<[T]>, T: Copy> CircularArray<N, A, T> {
+    /// Push elements to the front of the given `axis`, copying contiguous runs with
+    /// `copy_from_slice`. Equivalent to [`CircularMut::push_front`](crate::CircularMut::push_front)
+    /// for `Copy` elements.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let mut array = CircularArray::new([3, 2], vec![
+    ///     0, 1, 2,
+    ///     3, 4, 5,
+    /// ]);
+    /// array.push_front_copy(0, &[6, 7]);
+    ///
+    /// assert_eq!(array.iter().cloned().collect::<Vec<_>>(), &[
+    ///     1, 2, 6,
+    ///     4, 5, 7,
+    /// ]);
+    /// ```
+    pub fn push_front_copy(&mut self, axis: usize, el: &[T]) {
+        let n = self.copy_slice_n(axis, el.len());
+
+        if n == self.shape[axis] {
+            self.array.as_mut().copy_from_slice(el);
+            self.offset = [0; N];
+        } else if n != 0 {
+            let spans = self.spans_axis_bound(axis, BoundSpan::new(0, n, self.shape[axis]));
+
+            self.push_copy(IndexIterator::new_bound_contiguous(spans), el);
+            self.incr_offset(axis, n);
+        }
+    }
+
+    /// Push elements to the back of the given `axis`, copying contiguous runs with
+    /// `copy_from_slice`. Equivalent to [`CircularMut::push_back`](crate::CircularMut::push_back)
+    /// for `Copy` elements.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let mut array = CircularArray::new([3, 2], vec![
+    ///     0, 1, 2,
+    ///     3, 4, 5,
+    /// ]);
+    /// array.push_back_copy(0, &[6, 7]);
+    ///
+    /// assert_eq!(array.iter().cloned().collect::<Vec<_>>(), &[
+    ///     6, 0, 1,
+    ///     7, 3, 4,
+    /// ]);
+    /// ```
+    pub fn push_back_copy(&mut self, axis: usize, el: &[T]) {
+        let n = self.copy_slice_n(axis, el.len());
+
+        if n == self.shape[axis] {
+            self.array.as_mut().copy_from_slice(el);
+            self.offset = [0; N];
+        } else if n != 0 {
+            let span = BoundSpan::new(self.shape[axis] - n, n, self.shape[axis]);
+            let spans = self.spans_axis_bound(axis, span);
+
+            self.push_copy(IndexIterator::new_bound_contiguous(spans), el);
+            self.decr_offset(axis, n);
+        }
+    }
+
+    /// Overwrite all elements of the array from `src`, in logical order (aligned
+    /// to the offset), copying contiguous runs with `copy_from_slice`. The offset
+    /// is retained. See also [`CircularArray::copy_from_slice_normalized`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `src` does not match the array length.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let mut array = CircularArray::new_offset([3, 2], [1, 0], vec![0; 6]);
+    /// array.copy_from_slice_logical(&[
+    ///     0, 1, 2,
+    ///     3, 4, 5,
+    /// ]);
+    ///
+    /// assert_eq!(array.offset(), &[1, 0]);
+    /// assert_eq!(array.data(), &[
+    ///     2, 0, 1,
+    ///     5, 3, 4,
+    /// ]);
+    /// ```
+    pub fn copy_from_slice_logical(&mut self, src: &[T]) {
+        self.assert_copy_len(src.len());
+
+        if !self.is_empty() {
+            let spans = IndexIterator::new_bound_contiguous(self.spans());
+            self.push_copy(spans, src);
+        }
+    }
+
+    /// Overwrite all elements of the array from `src`, in logical order, resetting
+    /// the offset to `[0; N]`. The buffer is copied from `src` in a single
+    /// `copy_from_slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `src` does not match the array length.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let mut array = CircularArray::new_offset([3, 2], [1, 0], vec![0; 6]);
+    /// array.copy_from_slice_normalized(&[
+    ///     0, 1, 2,
+    ///     3, 4, 5,
+    /// ]);
+    ///
+    /// assert_eq!(array.offset(), &[0, 0]);
+    /// assert_eq!(array.data(), &[
+    ///     0, 1, 2,
+    ///     3, 4, 5,
+    /// ]);
+    /// ```
+    pub fn copy_from_slice_normalized(&mut self, src: &[T]) {
+        self.assert_copy_len(src.len());
+
+        self.array.as_mut().copy_from_slice(src);
+        self.offset = [0; N];
+    }
+
+    /// Assert `len` matches the array length.
+    fn assert_copy_len(&self, len: usize) {
+        assert!(
+            len == self.len(),
+            "slice length {} does not match array length {}",
+            len,
+            self.len()
+        );
+    }
+
+    /// Get the number of slices of `axis` within `el_len` elements, asserting
+    /// `el_len` is a valid push.
+    fn copy_slice_n(&self, axis: usize, el_len: usize) -> usize {
+        assert_shape_index!(axis, N);
+        let slice_len = self.slice_len(axis);
+        let n = el_len / slice_len;
+
+        assert_element_len!(axis, el_len, slice_len);
+        assert_slice_len!(self, axis, n);
+
+        n
+    }
+
+    /// Copy a contiguous slice of elements into the array.
+    fn push_copy<'a>(&'a mut self, spans: impl RawIndexAdaptor<'a, N>, mut el: &[T]) {
+        let CircularArray { array, strides, .. } = self;
+
+        for range in spans.into_flat_ranges(strides) {
+            let (head, tail) = el.split_at(range.len());
+            array.as_mut()[range].copy_from_slice(head);
+            el = tail;
+        }
+    }
+}
+
+impl<const N: usize, A: AsRef<[T]>, T: Copy> CircularArray<N, A, T> {
+    /// Copy the elements of the array into `dst`, aligned to the offset, copying
+    /// contiguous runs with `copy_from_slice`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `dst` does not match the array length.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::CircularArray;
+    /// let array = CircularArray::new_offset([3, 2], [1, 0], vec![
+    ///     2, 0, 1,
+    ///     5, 3, 4,
+    /// ]);
+    /// let mut dst = [0; 6];
+    /// array.copy_into_slice(&mut dst);
+    ///
+    /// assert_eq!(dst, [
+    ///     0, 1, 2,
+    ///     3, 4, 5,
+    /// ]);
+    /// ```
+    pub fn copy_into_slice(&self, mut dst: &mut [T]) {
+        assert!(
+            dst.len() == self.len(),
+            "slice length {} does not match array length {}",
+            dst.len(),
+            self.len()
+        );
+        if self.is_empty() {
+            return;
+        }
+
+        let spans = IndexIterator::new_bound_contiguous(self.spans());
+        for range in spans.into_flat_ranges(&self.strides) {
+            let (head, tail) = dst.split_at_mut(range.len());
+            head.copy_from_slice(&self.array.as_ref()[range]);
+            dst = tail;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CircularArrayVec, CircularIndex, CircularMut};
+
+    #[test]
+    fn push_copy() {
+        let shape = [4, 3, 2];
+        let len = shape.iter().product::<usize>();
+
+        for axis in 0..3 {
+            for n in 0..=shape[axis] {
+                let mut m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..len);
+                let mut expected = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..len);
+
+                for i in 0..4 {
+                    let el = (0..n * m.slice_len(axis))
+                        .map(|j| len * (i + 1) + j)
+                        .collect::<Vec<_>>();
+                    match i % 2 {
+                        0 => {
+                            m.push_front_copy(axis, &el);
+                            expected.push_front(axis, &el);
+                        }
+                        _ => {
+                            m.push_back_copy(axis, &el);
+                            expected.push_back(axis, &el);
+                        }
+                    }
+
+                    assert_eq!(m.offset(), expected.offset());
+                    assert!(m.iter().eq(expected.iter()));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn copy_from_slice_logical() {
+        let shape = [4, 3, 2];
+        let src = (100..124).collect::<Vec<_>>();
+
+        for offset in [[0, 0, 0], [1, 2, 1], [3, 0, 1]] {
+            let mut m = CircularArrayVec::from_iter_offset(shape, offset, 0..24);
+            m.copy_from_slice_logical(&src);
+            assert_eq!(m.offset(), &offset);
+            assert!(m.iter().eq(src.iter()));
+
+            let mut m = CircularArrayVec::from_iter_offset(shape, offset, 0..24);
+            m.copy_from_slice_normalized(&src);
+            assert_eq!(m.offset(), &[0; 3]);
+            assert!(m.iter().eq(src.iter()));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn copy_from_slice_logical_len() {
+        let mut m = CircularArrayVec::from_iter([3, 3], 0..9);
+        m.copy_from_slice_logical(&[0; 10]);
+    }
+
+    #[test]
+    fn copy_into_slice() {
+        let shape = [4, 3, 2];
+        let mut dst = [0; 24];
+
+        for offset in [[0, 0, 0], [1, 2, 1], [3, 0, 1]] {
+            let m = CircularArrayVec::from_iter_offset(shape, offset, 0..24);
+            m.copy_into_slice(&mut dst);
+            assert!(dst.iter().eq(m.iter()));
+        }
+
+        let e = CircularArrayVec::<2, u8>::from_iter([0, 2], 0..0);
+        e.copy_into_slice(&mut []);
+    }
+
+    #[test]
+    #[should_panic]
+    fn copy_into_slice_len() {
+        let m = CircularArrayVec::from_iter([3, 3], 0..9);
+        m.copy_into_slice(&mut [0; 8]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn push_copy_len() {
+        let mut m = CircularArrayVec::from_iter([3, 3], 0..9);
+        m.push_front_copy(0, &[0, 1]);
+    }
+}
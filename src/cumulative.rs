@@ -0,0 +1,132 @@
+use std::ops::{Add, Sub};
+
+use crate::array_index::CircularIndex;
+use crate::{CircularArray, CircularArrayVec};
+
+impl<const N: usize, A: AsRef<[T]>, T: Clone> CircularArray<N, A, T> {
+    /// Create a new [`CircularArrayVec`] of the cumulative sums of the given `axis`,
+    /// such that each element is the sum of all elements of its lane up to and
+    /// including its own index, aligned to the offset. The new array has an offset
+    /// of `[0; N]`.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let array = CircularArray::new_offset([3, 2], [1, 0], vec![
+    ///     2, 0, 1,
+    ///     5, 3, 4,
+    /// ]);
+    ///
+    /// assert_eq!(array.cumsum_axis(0).data(), &[
+    ///     0, 1, 3,
+    ///     3, 7, 12,
+    /// ]);
+    /// assert_eq!(array.cumsum_axis(1).data(), &[
+    ///     0, 1, 2,
+    ///     3, 5, 7,
+    /// ]);
+    /// ```
+    pub fn cumsum_axis(&self, axis: usize) -> CircularArrayVec<N, T>
+    where
+        T: Add<Output = T>,
+    {
+        assert_shape_index!(axis, N);
+
+        let mut cumsum = self.map(T::clone);
+        let stride = self.strides[axis];
+        let len = self.shape[axis];
+        let array = &mut cumsum.array;
+
+        for i in (0..array.len()).filter(|i| (i / stride) % len != 0) {
+            array[i] = array[i - stride].clone() + array[i].clone();
+        }
+
+        cumsum
+    }
+
+    /// Create a new [`CircularArrayVec`] of the differences between adjacent slices
+    /// of the given `axis`, such that each element is the element at the next index
+    /// of its lane less its own, aligned to the offset. The new array has a length
+    /// of one less on `axis` (or `0` if `axis` is empty), and an offset of `[0; N]`.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let array = CircularArray::new_offset([3, 2], [1, 0], vec![
+    ///     4, 0, 1,
+    ///     9, 3, 5,
+    /// ]);
+    /// let diff = array.diff_axis(0);
+    ///
+    /// assert_eq!(diff.shape(), &[2, 2]);
+    /// assert_eq!(diff.data(), &[
+    ///     1, 3,
+    ///     2, 4,
+    /// ]);
+    /// ```
+    pub fn diff_axis(&self, axis: usize) -> CircularArrayVec<N, T>
+    where
+        T: Sub<Output = T>,
+    {
+        assert_shape_index!(axis, N);
+
+        let elements = self.iter().collect::<Vec<_>>();
+        let stride = self.strides[axis];
+        let len = self.shape[axis];
+        let array = (0..elements.len())
+            .filter(|i| (i / stride) % len != 0)
+            .map(|i| elements[i].clone() - elements[i - stride].clone())
+            .collect();
+
+        let mut shape = self.shape;
+        shape[axis] = len.saturating_sub(1);
+
+        CircularArray::new(shape, array)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CircularArrayVec, CircularIndex};
+
+    #[test]
+    fn cumsum_axis() {
+        let shape = [4, 3, 2];
+        let m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], (0..24).map(|i| i * i));
+
+        for axis in 0..3 {
+            let cumsum = m.cumsum_axis(axis);
+            assert_eq!(cumsum.shape(), &shape);
+            assert_eq!(cumsum.offset(), &[0; 3]);
+
+            for (index, sum) in cumsum.indexed_iter() {
+                let mut slice = index.map(|i| i..i + 1);
+                slice[axis] = 0..index[axis] + 1;
+                assert_eq!(*sum, m.iter_slice(slice).sum::<i32>());
+            }
+        }
+    }
+
+    #[test]
+    fn diff_axis() {
+        let shape = [4, 3, 2];
+        let m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], (0..24).map(|i| i * i));
+
+        for axis in 0..3 {
+            let diff = m.diff_axis(axis);
+            let mut expected = shape;
+            expected[axis] -= 1;
+            assert_eq!(diff.shape(), &expected);
+
+            for (index, delta) in diff.indexed_iter() {
+                let mut next = index;
+                next[axis] += 1;
+                assert_eq!(*delta, m.get(next) - m.get(index));
+            }
+        }
+
+        let m = CircularArrayVec::from_iter([1, 3], 0..3);
+        assert_eq!(m.diff_axis(0).shape(), &[0, 3]);
+        assert!(m.diff_axis(0).data().is_empty());
+    }
+}
@@ -0,0 +1,112 @@
+use crate::{CircularArray, CircularArrayView};
+
+/// A cursor over a [`CircularArray`] caching the last resolved index.
+///
+/// Resolving an index aligned to the offset requires a modulo and multiplication
+/// per axis. A `CursorAccessor` retains the last resolved index together with its
+/// raw index and flat position, such that axes for which the index is unchanged,
+/// or changed by one, are resolved incrementally. This suits sequential or near
+/// sequential access, such as walking a path through the array.
+///
+/// See [`CircularArray::cursor`].
+pub struct CursorAccessor<'a, const N: usize, T> {
+    /// The array accessed.
+    array: CircularArrayView<'a, N, T>,
+    /// The last resolved index, aligned to the offset.
+    index: [usize; N],
+    /// The raw index of the last resolved index.
+    raw: [usize; N],
+    /// The flat position of the last resolved index.
+    flat: usize,
+}
+
+impl<const N: usize, A: AsRef<[T]>, T> CircularArray<N, A, T> {
+    /// Create a [`CursorAccessor`] over the array, positioned at index `[0; N]`.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::CircularArray;
+    /// let array = CircularArray::new_offset([3, 3], [1, 1], vec![
+    ///     8, 6, 7,
+    ///     2, 0, 1,
+    ///     5, 3, 4,
+    /// ]);
+    /// let mut cursor = array.cursor();
+    ///
+    /// assert_eq!(cursor.get([0, 0]), &0);
+    /// assert_eq!(cursor.get([1, 0]), &1);
+    /// assert_eq!(cursor.get([2, 0]), &2);
+    /// assert_eq!(cursor.get([2, 1]), &5);
+    /// ```
+    pub fn cursor(&self) -> CursorAccessor<'_, N, T> {
+        let raw = self.offset;
+        let flat = self.strides.offset_index(raw);
+
+        CursorAccessor {
+            array: self.view(),
+            index: [0; N],
+            raw,
+            flat,
+        }
+    }
+}
+
+impl<'a, const N: usize, T> CursorAccessor<'a, N, T> {
+    /// Get the last resolved index, aligned to the offset.
+    pub fn index(&self) -> &[usize; N] {
+        &self.index
+    }
+
+    /// Get a reference to the element at the given index, aligned to the offset,
+    /// moving the cursor to `index`.
+    pub fn get(&mut self, index: [usize; N]) -> &'a T {
+        let array = &self.array;
+
+        for (i, idx) in index.into_iter().enumerate() {
+            assert_slice_index!(array, i, idx);
+
+            let prev = self.index[i];
+            if idx == prev {
+                continue;
+            }
+
+            let len = array.shape[i];
+            let stride = array.strides[i];
+            let raw = self.raw[i];
+            let next = if idx == prev + 1 {
+                (raw + 1) % len
+            } else if idx + 1 == prev {
+                (raw + len - 1) % len
+            } else {
+                (idx + array.offset[i]) % len
+            };
+
+            self.flat = self.flat + next * stride - raw * stride;
+            self.raw[i] = next;
+        }
+        self.index = index;
+
+        &self.array.array[self.flat]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CircularArrayVec, CircularIndex};
+
+    #[test]
+    fn get() {
+        let shape = [4, 3, 2];
+        let m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..shape.iter().product());
+        let mut cursor = m.cursor();
+
+        for (index, el) in m.indexed_iter() {
+            assert_eq!(cursor.get(index), el);
+        }
+
+        for index in [[3, 2, 1], [2, 2, 1], [0, 0, 0], [3, 0, 1], [1, 2, 0]] {
+            assert_eq!(cursor.get(index), m.get(index));
+            assert_eq!(cursor.index(), &index);
+        }
+    }
+}
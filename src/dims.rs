@@ -0,0 +1,94 @@
+use crate::array_index::CircularIndex;
+use crate::array_mut::CircularMut;
+use crate::CircularArray;
+
+/// Implement convenience constructors and accessors for arrays of `$n` dimensions,
+/// taking lengths and indices as separate arguments in axis order.
+macro_rules! impl_dims {
+    ($n:literal, $new:ident, $get:ident, $get_mut:ident, [$($len:ident),+], [$($i:ident),+]) => {
+        impl<A: AsRef<[T]>, T> CircularArray<$n, A, T> {
+            #[doc = concat!(
+                "Create a new `", stringify!($n), "` dimensional `CircularArray` from the ",
+                "given buffer, with the length of each axis passed in axis order. See ",
+                "[`CircularArray::new`]."
+            )]
+            pub fn $new($($len: usize,)+ array: A) -> Self {
+                CircularArray::new([$($len),+], array)
+            }
+
+            #[doc = concat!(
+                "Get a reference to the element at the given index of a `", stringify!($n),
+                "` dimensional array, aligned to the offset. See [`CircularIndex::get`]."
+            )]
+            pub fn $get(&self, $($i: usize),+) -> &T {
+                CircularIndex::get(self, [$($i),+])
+            }
+        }
+
+        impl<A: AsRef<[T]> + AsMut<[T]>, T: Clone> CircularArray<$n, A, T> {
+            #[doc = concat!(
+                "Get a mutable reference to the element at the given index of a `",
+                stringify!($n), "` dimensional array, aligned to the offset. See ",
+                "[`CircularMut::get_mut`]."
+            )]
+            pub fn $get_mut(&mut self, $($i: usize),+) -> &mut T {
+                CircularMut::get_mut(self, [$($i),+])
+            }
+        }
+    };
+}
+
+impl_dims!(1, new_1d, get_1d, get_1d_mut, [x_len], [x]);
+impl_dims!(2, new_2d, get_2d, get_2d_mut, [x_len, y_len], [x, y]);
+impl_dims!(
+    3,
+    new_3d,
+    get_3d,
+    get_3d_mut,
+    [x_len, y_len, z_len],
+    [x, y, z]
+);
+impl_dims!(
+    4,
+    new_4d,
+    get_4d,
+    get_4d_mut,
+    [x_len, y_len, z_len, w_len],
+    [x, y, z, w]
+);
+impl_dims!(
+    5,
+    new_5d,
+    get_5d,
+    get_5d_mut,
+    [x_len, y_len, z_len, w_len, v_len],
+    [x, y, z, w, v]
+);
+impl_dims!(
+    6,
+    new_6d,
+    get_6d,
+    get_6d_mut,
+    [x_len, y_len, z_len, w_len, v_len, u_len],
+    [x, y, z, w, v, u]
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::{CircularArray, CircularIndex, CircularMut};
+
+    #[test]
+    fn dims() {
+        let mut m = CircularArray::new_3d(4, 3, 2, Vec::from_iter(0..24));
+        m.push_front(1, &[24, 25, 26, 27, 28, 29, 30, 31]);
+
+        assert_eq!(m.shape(), &[4, 3, 2]);
+        assert_eq!(m.get_3d(1, 2, 1), m.get([1, 2, 1]));
+        *m.get_3d_mut(3, 0, 1) = 99;
+        assert_eq!(m.get([3, 0, 1]), &99);
+
+        let m = CircularArray::new_6d(1, 2, 1, 2, 1, 2, Vec::from_iter(0..8));
+        assert_eq!(m.get_6d(0, 1, 0, 1, 0, 1), &7);
+        assert_eq!(CircularArray::new_1d(3, [0, 1, 2]).get_1d(2), &2);
+    }
+}
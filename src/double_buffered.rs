@@ -0,0 +1,188 @@
+use crate::array_index::CircularIndex;
+use crate::{CircularArray, Neighborhood};
+
+/// A pair of equally shaped [`CircularArray`]s, a *front* array read by each step
+/// and a *back* array written by each step, swapped after each step.
+///
+/// This is the common structure of cellular automata and other simulations where
+/// each element is a function of the previous state.
+pub struct DoubleBuffered<const N: usize, A, T> {
+    /// The array read by each step.
+    front: CircularArray<N, A, T>,
+    /// The array written by each step.
+    back: CircularArray<N, A, T>,
+}
+
+impl<const N: usize, A: AsRef<[T]> + AsMut<[T]>, T: Clone> DoubleBuffered<N, A, T> {
+    /// Create a new `DoubleBuffered` from a `front` array and a `back` array. The
+    /// shape of each array **must** match. The contents of `back` are overwritten
+    /// by the first step.
+    pub fn new(front: CircularArray<N, A, T>, back: CircularArray<N, A, T>) -> Self {
+        assert!(
+            front.shape == back.shape,
+            "shape {:?} does not match shape {:?}",
+            front.shape,
+            back.shape
+        );
+
+        DoubleBuffered { front, back }
+    }
+
+    /// Get a reference to the front array.
+    pub fn front(&self) -> &CircularArray<N, A, T> {
+        &self.front
+    }
+
+    /// Get a mutable reference to the front array.
+    pub fn front_mut(&mut self) -> &mut CircularArray<N, A, T> {
+        &mut self.front
+    }
+
+    /// Get a reference to the back array.
+    pub fn back(&self) -> &CircularArray<N, A, T> {
+        &self.back
+    }
+
+    /// Swap the front and back arrays.
+    pub fn swap(&mut self) {
+        std::mem::swap(&mut self.front, &mut self.back);
+    }
+
+    /// Write the result of `f` for each index of the back array, aligned to the
+    /// offset, then swap the front and back arrays. `f` is passed the index and
+    /// the front array.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex, DoubleBuffered};
+    /// let front = CircularArray::new([4], vec![1, 2, 3, 4]);
+    /// let back = CircularArray::new([4], vec![0; 4]);
+    /// let mut buffers = DoubleBuffered::new(front, back);
+    ///
+    /// // Sum each element with the following element.
+    /// buffers.step(|[x], front| front.get([x]) + front.get_wrapped([x as isize + 1]));
+    /// assert_eq!(buffers.front().iter().cloned().collect::<Vec<_>>(), &[3, 5, 7, 5]);
+    /// ```
+    pub fn step<F: FnMut([usize; N], &CircularArray<N, A, T>) -> T>(&mut self, mut f: F) {
+        let DoubleBuffered { front, back } = self;
+        back.indexed_iter_mut()
+            .for_each(|(index, el)| *el = f(index, front));
+
+        self.swap();
+    }
+
+    /// Write the result of `f` for each element of the back array, aligned to the
+    /// offset, then swap the front and back arrays. `f` is passed the element of
+    /// the front array at the same index, and the elements of its `neighborhood`
+    /// of `radius`, wrapping over the bounds of each axis. See
+    /// [`CircularArray::iter_neighbors_wrapped`].
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex, DoubleBuffered, Neighborhood};
+    /// // A glider in Conway's game of life.
+    /// let front = CircularArray::new([5, 5], vec![
+    ///     0, 1, 0, 0, 0,
+    ///     0, 0, 1, 0, 0,
+    ///     1, 1, 1, 0, 0,
+    ///     0, 0, 0, 0, 0,
+    ///     0, 0, 0, 0, 0,
+    /// ]);
+    /// let back = CircularArray::new([5, 5], vec![0; 25]);
+    /// let mut life = DoubleBuffered::new(front, back);
+    ///
+    /// for _ in 0..4 {
+    ///     life.step_neighbors(1, Neighborhood::Moore, |cell, neighbors| {
+    ///         match (cell, neighbors.iter().copied().sum::<u8>()) {
+    ///             (1, 2) | (_, 3) => 1,
+    ///             _ => 0,
+    ///         }
+    ///     });
+    /// }
+    ///
+    /// assert_eq!(life.front().iter().cloned().collect::<Vec<_>>(), &[
+    ///     0, 0, 0, 0, 0,
+    ///     0, 0, 1, 0, 0,
+    ///     0, 0, 0, 1, 0,
+    ///     0, 1, 1, 1, 0,
+    ///     0, 0, 0, 0, 0,
+    /// ]);
+    /// ```
+    pub fn step_neighbors<F: FnMut(&T, &[&T]) -> T>(
+        &mut self,
+        radius: usize,
+        neighborhood: Neighborhood,
+        mut f: F,
+    ) {
+        let DoubleBuffered { front, back } = self;
+        let mut neighbors = vec![];
+
+        back.indexed_iter_mut().for_each(|(index, el)| {
+            neighbors.clear();
+            neighbors.extend(
+                front
+                    .iter_neighbors_wrapped(index, radius, neighborhood)
+                    .map(|(_, el)| el),
+            );
+
+            *el = f(front.get(index), &neighbors);
+        });
+
+        self.swap();
+    }
+
+    /// Drop the `DoubleBuffered`, returning the front and back arrays.
+    pub fn into_parts(self) -> (CircularArray<N, A, T>, CircularArray<N, A, T>) {
+        (self.front, self.back)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CircularArrayVec, CircularIndex, DoubleBuffered, Neighborhood};
+
+    #[test]
+    fn step() {
+        let shape = [4, 3, 2];
+        let front = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..shape.iter().product());
+        let back = CircularArrayVec::from_iter_offset(shape, [3, 0, 1], 0..shape.iter().product());
+        let expected = front.iter().map(|el| el * 4).collect::<Vec<_>>();
+        let mut buffers = DoubleBuffered::new(front, back);
+
+        buffers.step(|index, front| front.get(index) * 2);
+        buffers.step(|index, front| front.get(index) * 2);
+        assert_eq!(
+            buffers.front().iter().cloned().collect::<Vec<_>>(),
+            expected
+        );
+    }
+
+    #[test]
+    fn step_neighbors() {
+        let shape = [4, 3, 2];
+        let front = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..shape.iter().product());
+        let back = CircularArrayVec::new(shape, vec![0; 24]);
+        let mut buffers = DoubleBuffered::new(front, back);
+
+        buffers.step_neighbors(1, Neighborhood::VonNeumann, |_, neighbors| {
+            neighbors.iter().copied().sum()
+        });
+
+        let (front, back) = buffers.into_parts();
+        for (index, el) in front.indexed_iter() {
+            let expected = back
+                .iter_neighbors_wrapped(index, 1, Neighborhood::VonNeumann)
+                .map(|(_, el)| el)
+                .sum::<usize>();
+            assert_eq!(*el, expected);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn new_shape() {
+        let front = CircularArrayVec::from_iter([2, 3], 0..6);
+        let back = CircularArrayVec::from_iter([3, 2], 0..6);
+        DoubleBuffered::new(front, back);
+    }
+}
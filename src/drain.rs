@@ -0,0 +1,128 @@
+use std::mem;
+
+use crate::index::RawIndexAdaptor;
+use crate::index_iter::IndexIterator;
+use crate::span::BoundSpan;
+use crate::CircularArray;
+
+impl<const N: usize, A: AsRef<[T]> + AsMut<[T]>, T: Default> CircularArray<N, A, T> {
+    /// Drain the oldest `n` slices of the given `axis` (the first `n` indices),
+    /// returning an iterator of the elements aligned to the offset. Drained elements
+    /// are replaced with `T::default()`, and become the newest slices of the
+    /// `axis`, as per [`CircularArray::advance_front`].
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let mut array = CircularArray::new([3, 2], vec![
+    ///     1, 2, 3,
+    ///     4, 5, 6,
+    /// ]);
+    ///
+    /// assert_eq!(array.drain_front(0, 2).collect::<Vec<_>>(), &[1, 2, 4, 5]);
+    /// assert_eq!(array.iter().cloned().collect::<Vec<_>>(), &[
+    ///     3, 0, 0,
+    ///     6, 0, 0,
+    /// ]);
+    /// ```
+    pub fn drain_front(&mut self, axis: usize, n: usize) -> std::vec::IntoIter<T> {
+        assert_shape_index!(axis, N);
+        assert_slice_len!(self, axis, n);
+
+        let mut drained = vec![];
+        if n != 0 {
+            let spans = self.spans_axis_bound(axis, BoundSpan::new(0, n, self.shape[axis]));
+            drained = self.drain_spans(IndexIterator::new_bound_contiguous(spans));
+            self.incr_offset(axis, n);
+        }
+
+        drained.into_iter()
+    }
+
+    /// Drain the newest `n` slices of the given `axis` (the last `n` indices),
+    /// returning an iterator of the elements aligned to the offset. Drained elements
+    /// are replaced with `T::default()`, and become the oldest slices of the
+    /// `axis`, as per [`CircularArray::advance_back`].
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let mut array = CircularArray::new([3, 2], vec![
+    ///     1, 2, 3,
+    ///     4, 5, 6,
+    /// ]);
+    ///
+    /// assert_eq!(array.drain_back(1, 1).collect::<Vec<_>>(), &[4, 5, 6]);
+    /// assert_eq!(array.iter().cloned().collect::<Vec<_>>(), &[
+    ///     0, 0, 0,
+    ///     1, 2, 3,
+    /// ]);
+    /// ```
+    pub fn drain_back(&mut self, axis: usize, n: usize) -> std::vec::IntoIter<T> {
+        assert_shape_index!(axis, N);
+        assert_slice_len!(self, axis, n);
+
+        let mut drained = vec![];
+        if n != 0 {
+            let span = BoundSpan::new(self.shape[axis] - n, n, self.shape[axis]);
+            let spans = self.spans_axis_bound(axis, span);
+            drained = self.drain_spans(IndexIterator::new_bound_contiguous(spans));
+            self.decr_offset(axis, n);
+        }
+
+        drained.into_iter()
+    }
+
+    /// Take the elements of the given `spans`, replacing them with `T::default()`.
+    fn drain_spans<'a>(&'a mut self, spans: impl RawIndexAdaptor<'a, N>) -> Vec<T> {
+        let CircularArray { array, strides, .. } = self;
+
+        let mut drained = vec![];
+        for range in spans.into_flat_ranges(strides) {
+            drained.extend(array.as_mut()[range].iter_mut().map(mem::take));
+        }
+
+        drained
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CircularArrayVec, CircularIndex};
+
+    #[test]
+    fn drain() {
+        let shape = [4, 3, 2];
+        let len = shape.iter().product::<usize>();
+
+        for axis in 0..3 {
+            for n in 1..=shape[axis] {
+                let mut m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 1..len + 1);
+                let mut expected = m.clone();
+
+                let mut slice = [0..4, 0..3, 0..2];
+                slice[axis] = 0..n;
+                let front = m.iter_slice(slice.clone()).cloned().collect::<Vec<_>>();
+                assert_eq!(m.drain_front(axis, n).collect::<Vec<_>>(), front);
+                expected.advance_front(axis, n);
+                assert!(m.iter().eq(expected.iter()));
+
+                slice[axis] = shape[axis] - n..shape[axis];
+                let back = m.iter_slice(slice).cloned().collect::<Vec<_>>();
+                assert_eq!(m.drain_back(axis, n).collect::<Vec<_>>(), back);
+                expected.advance_back(axis, n);
+                assert!(m.iter().eq(expected.iter()));
+            }
+        }
+    }
+
+    #[test]
+    fn drain_none() {
+        let mut m = CircularArrayVec::from_iter_offset([3, 2], [1, 1], 1..7);
+        assert_eq!(m.drain_front(0, 0).len(), 0);
+        assert_eq!(m.drain_back(1, 0).len(), 0);
+        assert!(m
+            .iter()
+            .eq(CircularArrayVec::from_iter_offset([3, 2], [1, 1], 1..7).iter()));
+    }
+}
@@ -0,0 +1,239 @@
+use std::fmt::{self, Display, Write};
+use std::str::FromStr;
+
+use crate::array_index::CircularIndex;
+use crate::array_iter::iter_indices;
+use crate::strides::Strides;
+use crate::{CircularArray, CircularArrayVec};
+
+/// The header of the dump format, including the format version.
+const HEADER: &str = "n_circular_array dump v1";
+
+/// An error parsing a dump. See [`CircularArray::from_debug_str`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DumpError {
+    /// The header is missing, or of an unsupported version.
+    Header,
+    /// The shape is missing, or not of `N` lengths.
+    Shape,
+    /// The offset is missing, not of `N` indices, or out of bounds of the shape.
+    Offset,
+    /// The element at the given logical position could not be parsed.
+    Element(usize),
+    /// The number of elements does not match the shape.
+    Length {
+        /// The number of elements of the shape.
+        expected: usize,
+        /// The number of elements found.
+        found: usize,
+    },
+}
+
+impl Display for DumpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DumpError::Header => write!(f, "expected header `{}`", HEADER),
+            DumpError::Shape => write!(f, "invalid shape"),
+            DumpError::Offset => write!(f, "invalid offset"),
+            DumpError::Element(i) => write!(f, "invalid element at position {}", i),
+            DumpError::Length { expected, found } => {
+                write!(f, "expected {} elements (found {})", expected, found)
+            }
+        }
+    }
+}
+
+impl std::error::Error for DumpError {}
+
+impl<const N: usize, A: AsRef<[T]>, T: Display> CircularArray<N, A, T> {
+    /// Create a stable textual dump of the array, for snapshot testing. The dump
+    /// holds a versioned header, the shape, the offset, and the elements in logical
+    /// order. Each line holds a row (axis `0`), with slices of axes `>= 2` separated
+    /// by a blank line. Elements **must** not display whitespace to be parsed by
+    /// [`CircularArray::from_debug_str`].
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let array = CircularArray::new_offset([3, 2], [1, 0], vec![
+    ///     2, 0, 1,
+    ///     5, 3, 4,
+    /// ]);
+    ///
+    /// assert_eq!(array.to_debug_string(), "\
+    /// n_circular_array dump v1
+    /// shape 3 2
+    /// offset 1 0
+    /// 0 1 2
+    /// 3 4 5
+    /// ");
+    /// ```
+    pub fn to_debug_string(&self) -> String {
+        let mut dump = String::new();
+        writeln!(dump, "{}", HEADER).unwrap();
+        writeln!(dump, "shape{}", Joined(&self.shape)).unwrap();
+        writeln!(dump, "offset{}", Joined(&self.offset)).unwrap();
+
+        let row_len = self.shape.first().copied().unwrap_or(1);
+        let layer_len = self.shape.iter().take(2).product::<usize>();
+
+        if self.is_empty() {
+            return dump;
+        }
+
+        for (i, el) in self.iter().enumerate() {
+            if N > 2 && i != 0 && i % layer_len == 0 {
+                dump.push('\n');
+            }
+            match i % row_len {
+                0 => write!(dump, "{}", el),
+                _ => write!(dump, " {}", el),
+            }
+            .unwrap();
+            if (i + 1) % row_len == 0 {
+                dump.push('\n');
+            }
+        }
+
+        dump
+    }
+}
+
+impl<const N: usize, T: FromStr> CircularArrayVec<N, T> {
+    /// Parse a dump created by [`CircularArray::to_debug_string`], restoring the
+    /// shape, offset and elements.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArrayVec, CircularIndex};
+    /// let array = CircularArrayVec::<2, i32>::from_debug_str("\
+    /// n_circular_array dump v1
+    /// shape 3 2
+    /// offset 1 0
+    /// 0 1 2
+    /// 3 4 5
+    /// ").unwrap();
+    ///
+    /// assert_eq!(array.offset(), &[1, 0]);
+    /// assert_eq!(array.data(), &[
+    ///     2, 0, 1,
+    ///     5, 3, 4,
+    /// ]);
+    /// ```
+    pub fn from_debug_str(s: &str) -> Result<Self, DumpError> {
+        let mut lines = s.lines();
+        if lines.next().map(str::trim) != Some(HEADER) {
+            return Err(DumpError::Header);
+        }
+
+        let shape = parse_line::<N>(lines.next(), "shape").ok_or(DumpError::Shape)?;
+        let len = shape
+            .iter()
+            .try_fold(1usize, |acc, len| acc.checked_mul(*len))
+            .ok_or(DumpError::Shape)?;
+        let offset = parse_line::<N>(lines.next(), "offset").ok_or(DumpError::Offset)?;
+        if offset.iter().zip(shape).any(|(i, len)| *i >= len.max(1)) {
+            return Err(DumpError::Offset);
+        }
+
+        let mut elements = lines
+            .flat_map(str::split_whitespace)
+            .enumerate()
+            .map(|(i, el)| el.parse().map(Some).map_err(|_| DumpError::Element(i)))
+            .collect::<Result<Vec<Option<T>>, _>>()?;
+
+        if elements.len() != len {
+            return Err(DumpError::Length {
+                expected: len,
+                found: elements.len(),
+            });
+        }
+
+        // Elements are dumped in logical order, and are placed by raw index.
+        let strides = Strides::new(&shape);
+        let array = iter_indices(shape)
+            .map(|raw| {
+                let index = std::array::from_fn(|i| (raw[i] + shape[i] - offset[i]) % shape[i]);
+                elements[strides.offset_index(index)]
+                    .take()
+                    .expect("Duplicate logical index")
+            })
+            .collect();
+
+        Ok(CircularArray::new_offset(shape, offset, array))
+    }
+}
+
+/// Parse a line of `N` whitespace separated integers, following the given `key`.
+fn parse_line<const N: usize>(line: Option<&str>, key: &str) -> Option<[usize; N]> {
+    let mut tokens = line?.split_whitespace();
+    if tokens.next()? != key {
+        return None;
+    }
+
+    let values = tokens
+        .map(|t| t.parse().ok())
+        .collect::<Option<Vec<usize>>>()?;
+    values.try_into().ok()
+}
+
+/// Display a slice of integers, each prefixed by a space.
+struct Joined<'a>(&'a [usize]);
+
+impl Display for Joined<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.iter().try_for_each(|i| write!(f, " {}", i))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CircularArrayVec, DumpError};
+
+    #[test]
+    fn debug_string() {
+        let shape = [4, 3, 2];
+        let m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..shape.iter().product());
+        let dump = m.to_debug_string();
+
+        assert_eq!(dump.lines().count(), 3 + 6 + 1);
+        let p = CircularArrayVec::<3, usize>::from_debug_str(&dump).unwrap();
+        assert_eq!(p.offset(), m.offset());
+        assert_eq!(p.data(), m.data());
+        assert_eq!(p.to_debug_string(), dump);
+
+        let e = CircularArrayVec::from_iter([0, 2], 0..0);
+        let p = CircularArrayVec::<2, usize>::from_debug_str(&e.to_debug_string()).unwrap();
+        assert_eq!(p.shape(), &[0, 2]);
+    }
+
+    #[test]
+    fn debug_string_errors() {
+        let parse = |s: &str| CircularArrayVec::<2, i32>::from_debug_str(s).err();
+
+        assert_eq!(parse("n_circular_array dump v0\n"), Some(DumpError::Header));
+        assert_eq!(
+            parse("n_circular_array dump v1\nshape 2\n"),
+            Some(DumpError::Shape)
+        );
+        assert_eq!(
+            parse("n_circular_array dump v1\nshape 18446744073709551615 2\n"),
+            Some(DumpError::Shape)
+        );
+        assert_eq!(
+            parse("n_circular_array dump v1\nshape 2 1\noffset 2 0\n"),
+            Some(DumpError::Offset)
+        );
+        assert_eq!(
+            parse("n_circular_array dump v1\nshape 2 1\noffset 0 0\n0 x\n"),
+            Some(DumpError::Element(1))
+        );
+        assert_eq!(
+            parse("n_circular_array dump v1\nshape 2 1\noffset 0 0\n0\n"),
+            Some(DumpError::Length {
+                expected: 2,
+                found: 1
+            })
+        );
+    }
+}
@@ -0,0 +1,257 @@
+//! Object safe traits of a [`CircularArray`], erasing the dimensionality `N`.
+//!
+//! Methods share names with [`CircularIndex`] and [`CircularMut`], so the traits
+//! are exported from this module rather than the crate root, such that a glob
+//! import of the crate does not make calls on a concrete array ambiguous.
+//!
+//! ```
+//! use n_circular_array::*;
+//!
+//! let array = CircularArray::new([3], vec![0, 1, 2]);
+//! let erased: &dyn erased::CircularRead<i32> = &array;
+//!
+//! assert!(array.iter().eq(erased.iter()));
+//! ```
+
+use std::ops::Range;
+
+use crate::array_index::CircularIndex;
+use crate::array_mut::CircularMut;
+use crate::CircularArray;
+
+/// A boxed iterator over the elements of a [`CircularRead`] array.
+pub type ErasedIter<'a, T> = Box<dyn ExactSizeIterator<Item = &'a T> + 'a>;
+
+/// Object safe read operations of a [`CircularArray`], erasing the dimensionality
+/// `N`.
+///
+/// Indices and slices are passed as slices of length `N`, and iterators are boxed.
+/// This allows arrays of differing dimensionality to be stored as
+/// `dyn CircularRead<T>`. Prefer [`CircularIndex`] where `N` is known, as it does
+/// not allocate. Methods share names with [`CircularIndex`], such that calls on a
+/// concrete array with both traits in scope must be qualified.
+///
+/// # Example
+/// ```
+/// # use n_circular_array::CircularArray;
+/// # use n_circular_array::erased::CircularRead;
+/// let arrays: Vec<Box<dyn CircularRead<i32>>> = vec![
+///     Box::new(CircularArray::new([3], vec![0, 1, 2])),
+///     Box::new(CircularArray::new_offset([2, 2], [1, 0], vec![1, 0, 3, 2])),
+/// ];
+///
+/// assert_eq!(arrays[0].ndim(), 1);
+/// assert_eq!(arrays[1].shape(), &[2, 2]);
+/// assert_eq!(arrays[1].get(&[0, 1]), &2);
+/// assert_eq!(arrays[1].iter().cloned().collect::<Vec<_>>(), &[0, 1, 2, 3]);
+/// ```
+pub trait CircularRead<T> {
+    /// Get the number of dimensions `N` of the array.
+    fn ndim(&self) -> usize;
+
+    /// Get the array shape. See [`CircularArray::shape`].
+    fn shape(&self) -> &[usize];
+
+    /// Get the array offset. See [`CircularArray::offset`].
+    fn offset(&self) -> &[usize];
+
+    /// Get the number of elements in the array.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if the array contains no elements.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get a reference to the element at the given index, aligned to the offset.
+    /// See [`CircularIndex::get`].
+    fn get(&self, index: &[usize]) -> &T;
+
+    /// Get a reference to the element at the given index. This does **not**
+    /// account for the offset. See [`CircularIndex::get_raw`].
+    fn get_raw(&self, index: &[usize]) -> &T;
+
+    /// Iterate over all elements, aligned to the offset. See [`CircularIndex::iter`].
+    fn iter(&self) -> ErasedIter<'_, T>;
+
+    /// Iterate over all elements in raw order. See [`CircularIndex::iter_raw`].
+    fn iter_raw(&self) -> ErasedIter<'_, T>;
+
+    /// Iterate over all elements of the specified `axis` and `index`, aligned to
+    /// the offset. See [`CircularIndex::iter_index`].
+    fn iter_index(&self, axis: usize, index: usize) -> ErasedIter<'_, T>;
+
+    /// Iterate over all elements of the specified `axis` and `range`, aligned to
+    /// the offset. See [`CircularIndex::iter_range`].
+    fn iter_range(&self, axis: usize, range: Range<usize>) -> ErasedIter<'_, T>;
+
+    /// Iterate over all elements within the given `slice`, aligned to the offset.
+    /// See [`CircularIndex::iter_slice`].
+    fn iter_slice(&self, slice: &[Range<usize>]) -> ErasedIter<'_, T>;
+
+    /// Call `visitor` with each contiguous run of elements within the given `slice`,
+    /// aligned to the offset. See [`CircularArray::visit_regions`].
+    fn visit_regions(&self, slice: &[Range<usize>], visitor: &mut dyn FnMut(&[usize], &[T]));
+}
+
+/// Object safe write operations of a [`CircularArray`], erasing the dimensionality
+/// `N`. See [`CircularRead`].
+///
+/// # Example
+/// ```
+/// # use n_circular_array::CircularArray;
+/// # use n_circular_array::erased::{CircularRead, CircularWrite};
+/// let mut arrays: Vec<Box<dyn CircularWrite<i32>>> = vec![
+///     Box::new(CircularArray::new([3], vec![0, 1, 2])),
+///     Box::new(CircularArray::new([2, 2], vec![0, 1, 2, 3])),
+/// ];
+///
+/// for array in arrays.iter_mut() {
+///     let el = vec![9; array.len() / array.shape()[0]];
+///     array.push_front(0, &el);
+/// }
+///
+/// assert_eq!(arrays[0].iter().cloned().collect::<Vec<_>>(), &[1, 2, 9]);
+/// assert_eq!(arrays[1].iter().cloned().collect::<Vec<_>>(), &[1, 9, 3, 9]);
+/// ```
+pub trait CircularWrite<T>: CircularRead<T> {
+    /// Get a mutable reference to the element at the given index, aligned to the
+    /// offset. See [`CircularMut::get_mut`].
+    fn get_mut(&mut self, index: &[usize]) -> &mut T;
+
+    /// Push elements to the front of the given `axis`. See [`CircularMut::push_front`].
+    fn push_front(&mut self, axis: usize, el: &[T]);
+
+    /// Push elements to the back of the given `axis`. See [`CircularMut::push_back`].
+    fn push_back(&mut self, axis: usize, el: &[T]);
+}
+
+impl<const N: usize, A: AsRef<[T]>, T> CircularRead<T> for CircularArray<N, A, T> {
+    fn ndim(&self) -> usize {
+        N
+    }
+
+    fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+
+    fn offset(&self) -> &[usize] {
+        &self.offset
+    }
+
+    fn len(&self) -> usize {
+        CircularArray::len(self)
+    }
+
+    fn get(&self, index: &[usize]) -> &T {
+        CircularIndex::get(self, to_array(index))
+    }
+
+    fn get_raw(&self, index: &[usize]) -> &T {
+        CircularIndex::get_raw(self, to_array(index))
+    }
+
+    fn iter(&self) -> ErasedIter<'_, T> {
+        Box::new(CircularIndex::iter(self))
+    }
+
+    fn iter_raw(&self) -> ErasedIter<'_, T> {
+        Box::new(CircularIndex::iter_raw(self))
+    }
+
+    fn iter_index(&self, axis: usize, index: usize) -> ErasedIter<'_, T> {
+        Box::new(CircularIndex::iter_index(self, axis, index))
+    }
+
+    fn iter_range(&self, axis: usize, range: Range<usize>) -> ErasedIter<'_, T> {
+        Box::new(CircularIndex::iter_range(self, axis, range))
+    }
+
+    fn iter_slice(&self, slice: &[Range<usize>]) -> ErasedIter<'_, T> {
+        Box::new(CircularIndex::iter_slice(self, to_array(slice)))
+    }
+
+    fn visit_regions(&self, slice: &[Range<usize>], visitor: &mut dyn FnMut(&[usize], &[T])) {
+        CircularArray::visit_regions(self, to_array(slice), |index, run| visitor(&index, run));
+    }
+}
+
+impl<const N: usize, A: AsRef<[T]> + AsMut<[T]>, T: Clone> CircularWrite<T>
+    for CircularArray<N, A, T>
+{
+    fn get_mut(&mut self, index: &[usize]) -> &mut T {
+        CircularMut::get_mut(self, to_array(index))
+    }
+
+    fn push_front(&mut self, axis: usize, el: &[T]) {
+        CircularMut::push_front(self, axis, el);
+    }
+
+    fn push_back(&mut self, axis: usize, el: &[T]) {
+        CircularMut::push_back(self, axis, el);
+    }
+}
+
+/// Convert an index or slice of length `N` into an array.
+fn to_array<const N: usize, I: Clone>(index: &[I]) -> [I; N] {
+    assert!(
+        index.len() == N,
+        "index of length {} does not match dimensionality {}",
+        index.len(),
+        N
+    );
+
+    std::array::from_fn(|i| index[i].clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CircularRead, CircularWrite};
+    use crate::{CircularArrayVec, CircularIndex};
+
+    #[test]
+    fn erased() {
+        let shape = [4, 3, 2];
+        let m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..shape.iter().product());
+        let expected =
+            CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..shape.iter().product());
+        let mut erased: Box<dyn CircularWrite<usize>> = Box::new(m);
+
+        assert_eq!(erased.ndim(), 3);
+        assert_eq!(erased.shape(), &shape);
+        assert_eq!(erased.offset(), &[1, 2, 1]);
+        assert!(erased.iter().eq(CircularIndex::iter(&expected)));
+        assert!(erased.iter_raw().eq(CircularIndex::iter_raw(&expected)));
+        assert!(erased
+            .iter_range(1, 1..3)
+            .eq(CircularIndex::iter_range(&expected, 1, 1..3)));
+        assert!(erased
+            .iter_slice(&[1..3, 0..2, 1..2])
+            .eq(CircularIndex::iter_slice(&expected, [1..3, 0..2, 1..2])));
+        expected
+            .indexed_iter()
+            .for_each(|(index, el)| assert_eq!(erased.get(&index), el));
+
+        *erased.get_mut(&[0, 0, 0]) = usize::MAX;
+        assert_eq!(erased.get(&[0, 0, 0]), &usize::MAX);
+
+        let mut visited = vec![];
+        erased.visit_regions(&[1..3, 0..2, 1..2], &mut |_, run| {
+            visited.extend_from_slice(run)
+        });
+        assert!(visited
+            .iter()
+            .eq(CircularIndex::iter_slice(&expected, [1..3, 0..2, 1..2])));
+
+        erased.push_back(2, &[0; 12]);
+        assert!(erased.iter_index(2, 0).all(|el| *el == 0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn erased_index_len() {
+        let m = CircularArrayVec::from_iter([3, 3], 0..9);
+        let erased: &dyn CircularRead<i32> = &m;
+        erased.get(&[0]);
+    }
+}
@@ -0,0 +1,277 @@
+use std::array;
+use std::ops::Range;
+
+use crate::index::RawIndexAdaptor;
+use crate::index_iter::IndexIterator;
+use crate::span::BoundSpan;
+use crate::CircularArray;
+
+impl<const N: usize, A: AsRef<[T]> + AsMut<[T]>, T: Clone> CircularArray<N, A, T> {
+    /// Fill the array with clones of `value`.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let mut array = CircularArray::new_offset([2, 2], [1, 0], vec![
+    ///     1, 0,
+    ///     3, 2,
+    /// ]);
+    /// array.fill(9);
+    ///
+    /// assert_eq!(array.iter().cloned().collect::<Vec<_>>(), &[
+    ///     9, 9,
+    ///     9, 9,
+    /// ]);
+    /// ```
+    pub fn fill(&mut self, value: T) {
+        self.array.as_mut().fill(value);
+    }
+
+    /// Fill the elements within the given `slice` with clones of `value`, aligned
+    /// to the offset.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let mut array = CircularArray::new_offset([3, 3], [1, 1], vec![
+    ///     8, 6, 7,
+    ///     2, 0, 1,
+    ///     5, 3, 4,
+    /// ]);
+    /// array.fill_region([1..3, 0..2], 9);
+    ///
+    /// assert_eq!(array.iter().cloned().collect::<Vec<_>>(), &[
+    ///     0, 9, 9,
+    ///     3, 9, 9,
+    ///     6, 7, 8,
+    /// ]);
+    /// ```
+    pub fn fill_region(&mut self, slice: [Range<usize>; N], value: T) {
+        self.for_each_region_mut(slice, |slice| slice.fill(value.clone()));
+    }
+
+    /// Fill the array with the result of `f` for the index of each element,
+    /// aligned to the offset.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let mut array = CircularArray::new_offset([2, 2], [1, 0], vec![0; 4]);
+    /// array.fill_with(|[x, y]| x * 10 + y);
+    ///
+    /// assert_eq!(array.iter().cloned().collect::<Vec<_>>(), &[
+    ///      0, 10,
+    ///      1, 11,
+    /// ]);
+    /// ```
+    pub fn fill_with<F: FnMut([usize; N]) -> T>(&mut self, mut f: F) {
+        self.indexed_iter_mut()
+            .for_each(|(index, el)| *el = f(index));
+    }
+}
+
+impl<const N: usize, A: AsRef<[T]> + AsMut<[T]>, T> CircularArray<N, A, T> {
+    /// Mutate each element of the array by `f`, in raw order.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let mut array = CircularArray::new_offset([2, 2], [1, 0], vec![
+    ///     1.0, 0.0,
+    ///     3.0, 2.0,
+    /// ]);
+    /// array.apply(|el| *el *= 0.5);
+    ///
+    /// assert_eq!(array.iter().cloned().collect::<Vec<_>>(), &[
+    ///     0.0, 0.5,
+    ///     1.0, 1.5,
+    /// ]);
+    /// ```
+    pub fn apply<F: FnMut(&mut T)>(&mut self, f: F) {
+        self.array.as_mut().iter_mut().for_each(f);
+    }
+
+    /// Mutate each element within the given `slice` by `f`, aligned to the offset.
+    /// Elements are visited in raw order.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let mut array = CircularArray::new_offset([3, 3], [1, 1], vec![
+    ///     8, 6, 7,
+    ///     2, 0, 1,
+    ///     5, 3, 4,
+    /// ]);
+    /// array.apply_region([1..3, 0..2], |el| *el *= 10);
+    ///
+    /// assert_eq!(array.iter().cloned().collect::<Vec<_>>(), &[
+    ///     0, 10, 20,
+    ///     3, 40, 50,
+    ///     6,  7,  8,
+    /// ]);
+    /// ```
+    pub fn apply_region<F: FnMut(&mut T)>(&mut self, slice: [Range<usize>; N], mut f: F) {
+        self.for_each_region_mut(slice, |slice| slice.iter_mut().for_each(&mut f));
+    }
+
+    /// Mutate each element of the array by `f`, paired with the element of `other`
+    /// at the same index, aligned to the offset of each array. Arrays **must** be
+    /// of equal shape.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let mut a = CircularArray::new_offset([3], [1], vec![2, 0, 1]);
+    /// let b = CircularArray::new_offset([3], [2], vec![10, 20, 0]);
+    /// a.zip_apply(&b, |a, b| *a += b);
+    ///
+    /// assert_eq!(a.iter().cloned().collect::<Vec<_>>(), &[0, 11, 22]);
+    /// ```
+    pub fn zip_apply<B: AsRef<[U]>, U, F: FnMut(&mut T, &U)>(
+        &mut self,
+        other: &CircularArray<N, B, U>,
+        mut f: F,
+    ) {
+        assert!(
+            self.shape == other.shape,
+            "shape {:?} does not match shape {:?}",
+            self.shape,
+            other.shape
+        );
+
+        let spans = self.spans();
+        let other_data = other.array.as_ref();
+        let mut other_ranges =
+            IndexIterator::new_bound_contiguous(other.spans()).into_flat_ranges(&other.strides);
+        let mut other_range = 0..0;
+
+        let CircularArray { array, strides, .. } = self;
+        for mut range in IndexIterator::new_bound_contiguous(spans).into_flat_ranges(strides) {
+            // Split ranges of both arrays at the boundaries of either.
+            while !range.is_empty() {
+                if other_range.is_empty() {
+                    other_range = other_ranges.next().expect("Misaligned zipped elements");
+                }
+                let len = range.len().min(other_range.len());
+
+                array.as_mut()[range.start..range.start + len]
+                    .iter_mut()
+                    .zip(&other_data[other_range.start..other_range.start + len])
+                    .for_each(|(a, b)| f(a, b));
+
+                range.start += len;
+                other_range.start += len;
+            }
+        }
+    }
+
+    /// Call `f` for each contiguous slice of the buffer within the given `slice`,
+    /// aligned to the offset. Slices are visited in raw order.
+    fn for_each_region_mut<F: FnMut(&mut [T])>(&mut self, slice: [Range<usize>; N], mut f: F) {
+        slice.iter().enumerate().for_each(|(i, range)| {
+            assert_slice_range!(self, i, range);
+        });
+
+        if slice.iter().any(|range| range.is_empty()) {
+            return;
+        }
+
+        let spans = array::from_fn(|i| {
+            BoundSpan::new(
+                (slice[i].start + self.offset[i]) % self.shape[i],
+                slice[i].len(),
+                self.shape[i],
+            )
+        });
+
+        let CircularArray { array, strides, .. } = self;
+        IndexIterator::new_bound_contiguous_ordered(spans)
+            .into_flat_ranges(strides)
+            .for_each(|range| f(&mut array.as_mut()[range]));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CircularArrayVec, CircularIndex};
+
+    #[test]
+    fn fill_region() {
+        let shape = [4, 3, 2];
+        let mut m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..shape.iter().product());
+        let expected = m
+            .indexed_iter()
+            .map(
+                |([x, y, z], el)| match (1..4).contains(&x) && y < 2 && z == 1 {
+                    true => usize::MAX,
+                    false => *el,
+                },
+            )
+            .collect::<Vec<_>>();
+
+        m.fill_region([1..4, 0..2, 1..2], usize::MAX);
+        assert_eq!(m.iter().cloned().collect::<Vec<_>>(), expected);
+
+        m.fill_region([1..1, 0..3, 0..2], 0);
+        assert_eq!(m.iter().cloned().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn apply_region() {
+        let shape = [4, 3, 2];
+        let mut m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..shape.iter().product());
+        let expected = m
+            .indexed_iter()
+            .map(
+                |([x, y, z], el)| match x < 3 && (1..3).contains(&y) && z == 0 {
+                    true => el * 10,
+                    false => *el,
+                },
+            )
+            .collect::<Vec<_>>();
+
+        m.apply_region([0..3, 1..3, 0..1], |el| *el *= 10);
+        assert_eq!(m.iter().cloned().collect::<Vec<_>>(), expected);
+
+        m.apply(|el| *el += 1);
+        assert_eq!(
+            m.iter().cloned().collect::<Vec<_>>(),
+            expected.iter().map(|el| el + 1).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn zip_apply() {
+        let shape = [4, 3, 2];
+        let mut a = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..shape.iter().product());
+        let b = CircularArrayVec::from_iter_offset(shape, [3, 0, 1], 0..shape.iter().product());
+        let expected = a
+            .iter()
+            .zip(b.iter())
+            .map(|(a, b)| a * 100 + b)
+            .collect::<Vec<_>>();
+
+        a.zip_apply(&b, |a, b| *a = *a * 100 + b);
+        assert_eq!(a.iter().cloned().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn zip_apply_shape() {
+        let mut a = CircularArrayVec::from_iter([2, 3], 0..6);
+        let b = CircularArrayVec::from_iter([3, 2], 0..6);
+        a.zip_apply(&b, |a, b| *a += b);
+    }
+
+    #[test]
+    fn fill_with() {
+        let shape = [4, 3, 2];
+        let mut m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..shape.iter().product());
+        m.fill_with(|[x, y, z]| x + y * 4 + z * 12);
+
+        assert_eq!(
+            m.iter().cloned().collect::<Vec<_>>(),
+            (0..24).collect::<Vec<_>>()
+        );
+    }
+}
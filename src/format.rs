@@ -0,0 +1,163 @@
+use std::fmt::{self, Debug, Display};
+
+use crate::array_index::CircularIndex;
+use crate::{CircularArray, CircularArrayView};
+
+/// Formats the logical contents of the array as nested lists, with the outermost
+/// axis first. Use the alternate flag (`{:#?}`) for a line per row.
+impl<const N: usize, A: AsRef<[T]>, T: Debug> Debug for CircularArray<N, A, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let elements = match self.is_empty() {
+            true => vec![],
+            false => self.iter().collect::<Vec<_>>(),
+        };
+
+        f.debug_struct("CircularArray")
+            .field("shape", &self.shape)
+            .field("offset", &self.offset)
+            .field(
+                "contents",
+                &Nested {
+                    elements: &elements,
+                    shape: &self.shape,
+                },
+            )
+            .finish()
+    }
+}
+
+/// Logical elements of an array of the given `shape`, formatted as nested lists.
+struct Nested<'a, T> {
+    elements: &'a [&'a T],
+    shape: &'a [usize],
+}
+
+impl<T: Debug> Debug for Nested<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.shape.split_last() {
+            Some((len, shape)) if !shape.is_empty() => {
+                let chunk = shape.iter().product::<usize>();
+                f.debug_list()
+                    .entries((0..*len).map(|i| Nested {
+                        elements: &self.elements[i * chunk..(i + 1) * chunk],
+                        shape,
+                    }))
+                    .finish()
+            }
+            // Rows are formatted compactly, such that `{:#?}` breaks per row.
+            _ => {
+                f.write_str("[")?;
+                for (i, el) in self.elements.iter().enumerate() {
+                    match i {
+                        0 => write!(f, "{:?}", el)?,
+                        _ => write!(f, ", {:?}", el)?,
+                    }
+                }
+                f.write_str("]")
+            }
+        }
+    }
+}
+
+/// A grid rendering of the logical contents of a [`CircularArray`] of up to 3
+/// dimensions, with columns right aligned. Each line holds a row (axis `0`), and
+/// layers (axis `2`) are separated by a blank line.
+///
+/// See [`CircularArray::display_grid`].
+pub struct Grid<'a, const N: usize, T> {
+    /// The array.
+    array: CircularArrayView<'a, N, T>,
+}
+
+impl<const N: usize, A: AsRef<[T]>, T: Display> CircularArray<N, A, T> {
+    /// Get a [`Grid`] rendering of the array, implementing [`Display`]. `N` **must**
+    /// be `<= 3`.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::CircularArray;
+    /// let array = CircularArray::new_offset([3, 2], [1, 0], vec![
+    ///     2,  0, 1,
+    ///     5, 30, 4,
+    /// ]);
+    ///
+    /// assert_eq!(array.display_grid().to_string(), " 0  1  2\n30  4  5");
+    /// ```
+    pub fn display_grid(&self) -> Grid<'_, N, T> {
+        const { assert!(N <= 3, "grid rendering requires N <= 3") };
+
+        Grid { array: self.view() }
+    }
+}
+
+impl<const N: usize, T: Display> Display for Grid<'_, N, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cells = match self.array.is_empty() {
+            true => vec![],
+            false => self
+                .array
+                .iter()
+                .map(|el| el.to_string())
+                .collect::<Vec<_>>(),
+        };
+        let width = cells
+            .iter()
+            .map(|cell| cell.chars().count())
+            .max()
+            .unwrap_or(0);
+
+        let shape = self.array.shape();
+        let row_len = shape.first().copied().unwrap_or(1).max(1);
+        let layer_len = row_len * shape.get(1).copied().unwrap_or(1).max(1);
+
+        for (i, cell) in cells.iter().enumerate() {
+            if i != 0 {
+                match (i % layer_len, i % row_len) {
+                    (0, _) => f.write_str("\n\n")?,
+                    (_, 0) => f.write_str("\n")?,
+                    _ => f.write_str(" ")?,
+                }
+            }
+            write!(f, "{:>width$}", cell, width = width)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::CircularArrayVec;
+
+    #[test]
+    fn debug() {
+        let m = CircularArrayVec::from_iter_offset([3, 2, 2], [1, 0, 1], 0..12);
+
+        assert_eq!(
+            format!("{:?}", m),
+            "CircularArray { shape: [3, 2, 2], offset: [1, 0, 1], \
+            contents: [[[7, 8, 6], [10, 11, 9]], [[1, 2, 0], [4, 5, 3]]] }"
+        );
+        assert!(format!("{:#?}", m).contains("\n            [10, 11, 9],\n"));
+        assert_eq!(
+            format!("{:?}", CircularArrayVec::from_iter([0, 2], 0..0)),
+            "CircularArray { shape: [0, 2], offset: [0, 0], contents: [[], []] }"
+        );
+    }
+
+    #[test]
+    fn display_grid() {
+        let m = CircularArrayVec::from_iter_offset([3, 2, 2], [1, 0, 1], 0..12);
+
+        assert_eq!(
+            m.display_grid().to_string(),
+            " 7  8  6\n10 11  9\n\n 1  2  0\n 4  5  3"
+        );
+        assert_eq!(
+            CircularArrayVec::from_iter([3], 0..3)
+                .display_grid()
+                .to_string(),
+            "0 1 2"
+        );
+    }
+}
@@ -0,0 +1,141 @@
+use crate::array_index::CircularIndex;
+use crate::array_mut::CircularMut;
+use crate::{CircularArray, CircularArrayVec};
+
+/// A [`CircularArray`] of `N` dimensions paired with a rolling history of its
+/// previous states, stored as a [`CircularArrayVec`] of `M = N + 1` dimensions.
+///
+/// Axis `N` (the outermost axis) of the history indexes committed states, from
+/// oldest to newest. See [`CircularArray::with_history`].
+pub struct CircularHistory<const N: usize, const M: usize, A, T> {
+    /// The live array.
+    array: CircularArray<N, A, T>,
+    /// The committed states of the live array.
+    history: CircularArrayVec<M, T>,
+    /// Buffer of the live array elements, aligned to the offset.
+    buffer: Vec<T>,
+}
+
+impl<const N: usize, A: AsRef<[T]>, T: Clone> CircularArray<N, A, T> {
+    /// Convert the array into a [`CircularHistory`] retaining the last `k` states
+    /// of the array. `M` **must** equal `N + 1`. The history is initialized with
+    /// `k` copies of the current state.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularHistory, CircularIndex, CircularMut};
+    /// let array = CircularArray::new([2, 2], vec![
+    ///     0, 1,
+    ///     2, 3,
+    /// ]);
+    /// let mut history: CircularHistory<2, 3, _, _> = array.with_history(2);
+    ///
+    /// history.array_mut().push_front(1, &[4, 5]);
+    /// history.commit();
+    ///
+    /// assert_eq!(history.history().shape(), &[2, 2, 2]);
+    /// assert_eq!(history.history().iter().cloned().collect::<Vec<_>>(), &[
+    ///     0, 1,
+    ///     2, 3,
+    ///
+    ///     2, 3,
+    ///     4, 5,
+    /// ]);
+    /// ```
+    pub fn with_history<const M: usize>(self, k: usize) -> CircularHistory<N, M, A, T> {
+        const { assert!(M == N + 1, "history dimensionality must be N + 1") };
+        assert!(k > 0, "history length must be greater than 0");
+
+        let buffer = self.iter().cloned().collect::<Vec<_>>();
+        let shape = std::array::from_fn(|i| match i < N {
+            true => self.shape[i],
+            false => k,
+        });
+        let history = CircularArrayVec::from_iter(
+            shape,
+            buffer.iter().cloned().cycle().take(buffer.len() * k),
+        );
+
+        CircularHistory {
+            array: self,
+            history,
+            buffer,
+        }
+    }
+}
+
+impl<const N: usize, const M: usize, A: AsRef<[T]>, T: Clone> CircularHistory<N, M, A, T> {
+    /// Get a reference to the live array.
+    pub fn array(&self) -> &CircularArray<N, A, T> {
+        &self.array
+    }
+
+    /// Get a mutable reference to the live array.
+    pub fn array_mut(&mut self) -> &mut CircularArray<N, A, T> {
+        &mut self.array
+    }
+
+    /// Get a reference to the history of committed states.
+    pub fn history(&self) -> &CircularArrayVec<M, T> {
+        &self.history
+    }
+
+    /// Get the number of states retained.
+    pub fn depth(&self) -> usize {
+        self.history.shape[N]
+    }
+
+    /// Push the current state of the live array to the front of the history,
+    /// evicting the oldest state.
+    pub fn commit(&mut self) {
+        self.buffer.clear();
+        self.buffer.extend(self.array.iter().cloned());
+
+        self.history.push_front(N, &self.buffer);
+    }
+
+    /// Drop the history, returning the live array and the history of committed
+    /// states.
+    pub fn into_parts(self) -> (CircularArray<N, A, T>, CircularArrayVec<M, T>) {
+        (self.array, self.history)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CircularArrayVec, CircularHistory, CircularIndex, CircularMut};
+
+    #[test]
+    fn commit() {
+        let m = CircularArrayVec::from_iter_offset([3, 2], [1, 1], 0..6);
+        let mut h: CircularHistory<2, 3, _, _> = m.with_history(3);
+
+        assert_eq!(h.depth(), 3);
+        for i in 0..4 {
+            h.array_mut().push_front(0, &[i, i]);
+            h.commit();
+
+            assert_eq!(
+                h.history().iter_index(2, 2).collect::<Vec<_>>(),
+                h.array().iter().collect::<Vec<_>>()
+            );
+        }
+
+        let (array, history) = h.into_parts();
+        #[rustfmt::skip]
+        assert_eq!(history.iter().cloned().collect::<Vec<_>>(), &[
+            3, 0, 1,
+            0, 0, 1,
+
+            0, 1, 2,
+            0, 1, 2,
+
+            1, 2, 3,
+            1, 2, 3,
+        ]);
+        assert_eq!(
+            array.iter().cloned().collect::<Vec<_>>(),
+            &[1, 2, 3, 1, 2, 3]
+        );
+    }
+}
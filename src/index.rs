@@ -84,7 +84,7 @@ impl<const N: usize> PartialEq<([usize; N], [usize; N])> for RawIndexSpan<N> {
 
 impl<const N: usize> Default for RawIndexSpan<N> {
     fn default() -> Self {
-        RawIndexSpan([UnboundSpan::default(); N])
+        RawIndexSpan([UnboundSpan::new(0, 0); N])
     }
 }
 
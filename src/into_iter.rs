@@ -0,0 +1,137 @@
+use crate::array_index::CircularIndex;
+use crate::erased::ErasedIter;
+use crate::{CircularArray, CircularArrayBox, CircularArrayVec};
+
+/// Consume the array, yielding elements in logical order (aligned to the offset).
+///
+/// # Example
+/// ```
+/// # use n_circular_array::CircularArrayVec;
+/// let array = CircularArrayVec::from_iter_offset([3, 2], [1, 0], 0..6);
+///
+/// assert_eq!(array.into_iter().collect::<Vec<_>>(), &[
+///     1, 2, 0,
+///     4, 5, 3,
+/// ]);
+/// ```
+impl<const N: usize, T> IntoIterator for CircularArrayVec<N, T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_vec().into_iter()
+    }
+}
+
+/// Consume the array, yielding elements in logical order (aligned to the offset).
+impl<const N: usize, T> IntoIterator for CircularArrayBox<N, T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_vec().into_iter()
+    }
+}
+
+/// Iterate over all elements, aligned to the offset. The iterator is boxed, such
+/// that [`CircularIndex::iter`] is preferred where the method may be called.
+///
+/// # Example
+/// ```
+/// # use n_circular_array::CircularArrayVec;
+/// let array = CircularArrayVec::from_iter_offset([3], [1], 0..3);
+/// let mut elements = vec![];
+/// for el in &array {
+///     elements.push(*el);
+/// }
+///
+/// assert_eq!(elements, &[1, 2, 0]);
+/// ```
+impl<'a, const N: usize, A: AsRef<[T]>, T> IntoIterator for &'a CircularArray<N, A, T> {
+    type Item = &'a T;
+    type IntoIter = ErasedIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        match self.is_empty() {
+            true => Box::new(std::iter::empty()),
+            false => Box::new(CircularIndex::iter(self)),
+        }
+    }
+}
+
+impl<const N: usize, A: AsRef<[T]> + Into<Vec<T>>, T> CircularArray<N, A, T> {
+    /// Consume the array, returning its elements in a `Vec`, aligned to the offset.
+    /// Elements are rotated in place, moving contiguous slices for each axis of a
+    /// non-zero offset. See also [`CircularArray::to_vec`].
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::CircularArray;
+    /// let array = CircularArray::new_offset([3, 2], [1, 1], vec![
+    ///     5, 3, 4,
+    ///     2, 0, 1,
+    /// ]);
+    ///
+    /// assert_eq!(array.into_vec(), &[
+    ///     0, 1, 2,
+    ///     3, 4, 5,
+    /// ]);
+    /// ```
+    pub fn into_vec(self) -> Vec<T> {
+        let mut array = self.array.into();
+        if array.is_empty() {
+            return array;
+        }
+
+        for axis in (0..N).filter(|axis| self.offset[*axis] != 0) {
+            let stride = self.strides[axis];
+            array
+                .chunks_exact_mut(stride * self.shape[axis])
+                .for_each(|chunk| chunk.rotate_left(stride * self.offset[axis]));
+        }
+
+        array
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CircularArrayBox, CircularArrayVec, CircularIndex};
+
+    #[test]
+    fn into_iter() {
+        let shape = [4, 3, 2];
+        let m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..shape.iter().product());
+        let expected = m.iter().cloned().collect::<Vec<_>>();
+
+        assert!((&m).into_iter().eq(expected.iter()));
+        assert_eq!(m.into_iter().collect::<Vec<_>>(), expected);
+
+        let b = CircularArrayBox::from_iter_offset([3], (0..3).map(|i| i.to_string()), [2]);
+        assert_eq!(b.into_iter().collect::<Vec<_>>(), &["2", "0", "1"]);
+
+        let e = CircularArrayVec::<2, u8>::from_iter([0, 2], 0..0);
+        assert_eq!((&e).into_iter().count(), 0);
+        assert_eq!(e.into_iter().count(), 0);
+    }
+
+    #[test]
+    fn into_vec() {
+        let shape = [4, 3, 2];
+        for offset in [[0, 0, 0], [1, 0, 0], [0, 2, 1], [3, 1, 1]] {
+            let m =
+                CircularArrayVec::from_iter_offset(shape, offset, (0..24).map(|i| i.to_string()));
+            let expected = m.iter().cloned().collect::<Vec<_>>();
+
+            assert_eq!(m.to_vec(), expected);
+            assert_eq!(m.into_vec(), expected);
+        }
+
+        let b = CircularArrayBox::from_iter_offset([3], 0..3, [2]);
+        assert_eq!(b.into_vec(), &[2, 0, 1]);
+
+        let e = CircularArrayVec::<2, u8>::from_iter_offset([0, 2], [0, 1], 0..0);
+        assert!(e.to_vec().is_empty());
+        assert!(e.into_vec().is_empty());
+    }
+}
@@ -0,0 +1,338 @@
+use std::collections::VecDeque;
+use std::ops::Range;
+
+use crate::array_mut::CircularMut;
+use crate::index::RawIndexAdaptor;
+use crate::index_iter::IndexIterator;
+use crate::span::BoundSpan;
+use crate::CircularArray;
+
+/// A journaled mutation of a [`Journaled`] array.
+struct JournalEntry<const N: usize, T> {
+    /// The raw ranges of the buffer written by the mutation.
+    ranges: Vec<Range<usize>>,
+    /// The elements of `ranges` before the mutation.
+    evicted: Vec<T>,
+    /// The elements of `ranges` after the mutation.
+    inserted: Vec<T>,
+    /// The offset before the mutation.
+    offset_before: [usize; N],
+    /// The offset after the mutation.
+    offset_after: [usize; N],
+}
+
+/// A [`CircularArray`] recording the elements evicted by, and the offset change
+/// of, each push and translation, such that mutations may be rolled back with
+/// [`Journaled::undo`] and reapplied with [`Journaled::redo`].
+///
+/// Each mutation stores only the slices it overwrites, rather than a snapshot of
+/// the array. The journal retains at most `capacity` mutations, dropping the
+/// oldest. Any new mutation clears mutations available to redo.
+///
+/// See [`CircularArray::into_journaled`].
+pub struct Journaled<const N: usize, A, T> {
+    /// The journaled array.
+    array: CircularArray<N, A, T>,
+    /// Mutations available to undo, from oldest to newest.
+    undo: VecDeque<JournalEntry<N, T>>,
+    /// Mutations available to redo, from newest to oldest.
+    redo: Vec<JournalEntry<N, T>>,
+    /// The maximum number of mutations retained.
+    capacity: usize,
+}
+
+impl<const N: usize, A: AsRef<[T]> + AsMut<[T]>, T: Clone> CircularArray<N, A, T> {
+    /// Convert the array into a [`Journaled`] array, retaining the last `capacity`
+    /// mutations for [`Journaled::undo`].
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let array = CircularArray::new([3, 2], vec![
+    ///     0, 1, 2,
+    ///     3, 4, 5,
+    /// ]);
+    /// let mut journaled = array.into_journaled(8);
+    ///
+    /// journaled.push_front(0, &[6, 7]);
+    /// journaled.push_back(1, &[8, 9, 10]);
+    /// assert_eq!(journaled.array().iter().cloned().collect::<Vec<_>>(), &[
+    ///      8,  9, 10,
+    ///      1,  2,  6,
+    /// ]);
+    ///
+    /// assert_eq!(journaled.undo(2), 2);
+    /// assert_eq!(journaled.array().iter().cloned().collect::<Vec<_>>(), &[
+    ///     0, 1, 2,
+    ///     3, 4, 5,
+    /// ]);
+    ///
+    /// assert_eq!(journaled.redo(1), 1);
+    /// assert_eq!(journaled.array().iter().cloned().collect::<Vec<_>>(), &[
+    ///     1, 2, 6,
+    ///     4, 5, 7,
+    /// ]);
+    /// ```
+    pub fn into_journaled(self, capacity: usize) -> Journaled<N, A, T> {
+        Journaled {
+            array: self,
+            undo: VecDeque::new(),
+            redo: vec![],
+            capacity,
+        }
+    }
+}
+
+impl<const N: usize, A: AsRef<[T]> + AsMut<[T]>, T: Clone> Journaled<N, A, T> {
+    /// Get a reference to the journaled array.
+    pub fn array(&self) -> &CircularArray<N, A, T> {
+        &self.array
+    }
+
+    /// Drop the journal, returning the array.
+    pub fn into_inner(self) -> CircularArray<N, A, T> {
+        self.array
+    }
+
+    /// Get the number of mutations available to undo.
+    pub fn undo_len(&self) -> usize {
+        self.undo.len()
+    }
+
+    /// Get the number of mutations available to redo.
+    pub fn redo_len(&self) -> usize {
+        self.redo.len()
+    }
+
+    /// Clear all mutations available to undo and redo.
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+    }
+
+    /// Push elements to the front of the given `axis`, aligned to the offset. See
+    /// [`CircularMut::push_front`].
+    pub fn push_front(&mut self, axis: usize, el: &[T]) {
+        let n = self.assert_push(axis, el);
+        let ranges = self.ranges(axis, 0, n);
+
+        self.record(ranges, |array| array.push_front(axis, el));
+    }
+
+    /// Push elements to the back of the given `axis`, aligned to the offset. See
+    /// [`CircularMut::push_back`].
+    pub fn push_back(&mut self, axis: usize, el: &[T]) {
+        let n = self.assert_push(axis, el);
+        let start = self.array.shape[axis] - n;
+        let ranges = self.ranges(axis, start, n);
+
+        self.record(ranges, |array| array.push_back(axis, el));
+    }
+
+    /// Translate the array by `n` on the given `axis`, inserting elements to the
+    /// front of the array. See [`CircularMut::translate_front`].
+    pub fn translate_front<'b, F>(&mut self, axis: usize, n: usize, origin: [usize; N], el_fn: F)
+    where
+        T: 'b,
+        F: FnMut([Range<usize>; N]) -> &'b [T],
+    {
+        assert_shape_index!(axis, N);
+        let len = n.min(self.array.shape[axis]);
+        let ranges = self.ranges(axis, 0, len);
+
+        self.record(ranges, |array| {
+            array.translate_front(axis, n, origin, el_fn)
+        });
+    }
+
+    /// Translate the array by `-n` on the given `axis`, inserting elements to the
+    /// back of the array. See [`CircularMut::translate_back`].
+    pub fn translate_back<'b, F>(&mut self, axis: usize, n: usize, origin: [usize; N], el_fn: F)
+    where
+        T: 'b,
+        F: FnMut([Range<usize>; N]) -> &'b [T],
+    {
+        assert_shape_index!(axis, N);
+        let len = n.min(self.array.shape[axis]);
+        let start = self.array.shape[axis] - len;
+        let ranges = self.ranges(axis, start, len);
+
+        self.record(ranges, |array| array.translate_back(axis, n, origin, el_fn));
+    }
+
+    /// Undo up to `n` mutations, from newest to oldest. Returns the number of
+    /// mutations undone.
+    pub fn undo(&mut self, n: usize) -> usize {
+        let n = n.min(self.undo.len());
+        for _ in 0..n {
+            let entry = self.undo.pop_back().unwrap();
+            self.write(&entry.ranges, &entry.evicted, entry.offset_before);
+            self.redo.push(entry);
+        }
+
+        n
+    }
+
+    /// Redo up to `n` undone mutations, from oldest to newest. Returns the number of
+    /// mutations redone.
+    pub fn redo(&mut self, n: usize) -> usize {
+        let n = n.min(self.redo.len());
+        for _ in 0..n {
+            let entry = self.redo.pop().unwrap();
+            self.write(&entry.ranges, &entry.inserted, entry.offset_after);
+            self.undo.push_back(entry);
+        }
+
+        n
+    }
+
+    /// Assert the elements `el` are a valid push to `axis`, returning the number of
+    /// slices pushed.
+    fn assert_push(&self, axis: usize, el: &[T]) -> usize {
+        assert_shape_index!(axis, N);
+        let array = &self.array;
+        let el_len = el.len();
+        let slice_len = array.slice_len(axis);
+        assert_element_len!(axis, el_len, slice_len);
+
+        let n = el_len / slice_len.max(1);
+        assert_slice_len!(array, axis, n);
+
+        n
+    }
+
+    /// Get the raw ranges of the buffer of `n` slices of `axis` from `start`,
+    /// aligned to the offset.
+    fn ranges(&self, axis: usize, start: usize, n: usize) -> Vec<Range<usize>> {
+        let array = &self.array;
+        if n == 0 || array.is_empty() {
+            vec![]
+        } else {
+            let span = BoundSpan::new(start, n, array.shape[axis]);
+            IndexIterator::new_bound_contiguous(array.spans_axis_bound(axis, span))
+                .into_flat_ranges(&array.strides)
+                .collect()
+        }
+    }
+
+    /// Apply the mutation `f` writing to the given raw `ranges`, and journal the
+    /// elements and offset before and after.
+    fn record<F: FnOnce(&mut CircularArray<N, A, T>)>(&mut self, ranges: Vec<Range<usize>>, f: F) {
+        if ranges.is_empty() {
+            return;
+        }
+
+        let offset_before = self.array.offset;
+        let evicted = self.read(&ranges);
+        f(&mut self.array);
+        let inserted = self.read(&ranges);
+
+        self.redo.clear();
+        if self.capacity == 0 {
+            return;
+        }
+        if self.undo.len() == self.capacity {
+            self.undo.pop_front();
+        }
+        self.undo.push_back(JournalEntry {
+            ranges,
+            evicted,
+            inserted,
+            offset_before,
+            offset_after: self.array.offset,
+        });
+    }
+
+    /// Read the elements of the given raw `ranges`.
+    fn read(&self, ranges: &[Range<usize>]) -> Vec<T> {
+        let data = self.array.array.as_ref();
+        ranges
+            .iter()
+            .flat_map(|range| data[range.clone()].iter().cloned())
+            .collect()
+    }
+
+    /// Write `el` to the given raw `ranges`, and set the array `offset`.
+    fn write(&mut self, ranges: &[Range<usize>], el: &[T], offset: [usize; N]) {
+        let data = self.array.array.as_mut();
+        let mut i = 0;
+        for range in ranges {
+            data[range.clone()].clone_from_slice(&el[i..i + range.len()]);
+            i += range.len();
+        }
+        self.array.offset = offset;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CircularArrayVec, CircularIndex, CircularMut};
+
+    #[test]
+    fn undo_redo() {
+        let shape = [4, 3, 2];
+        let mut m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..24);
+        let mut j = m.clone().into_journaled(usize::MAX);
+        let mut states = vec![m.clone()];
+
+        let source = (0..1000).collect::<Vec<_>>();
+        let el_fn = |[x, y, z]: [std::ops::Range<usize>; 3]| {
+            let start = 100 + x.start + y.start * 10 + z.start * 100;
+            &source[start..start + x.len()]
+        };
+        let mut record = |m: &CircularArrayVec<3, usize>, undo_len: usize| {
+            if undo_len == states.len() {
+                states.push(m.clone());
+            }
+        };
+        for (axis, len) in shape.into_iter().enumerate() {
+            for n in 0..=len {
+                let el = (0..n * m.slice_len(axis))
+                    .map(|i| 100 * (axis + 1) + i)
+                    .collect::<Vec<_>>();
+                m.push_front(axis, &el);
+                j.push_front(axis, &el);
+                record(&m, j.undo_len());
+                m.push_back(axis, &el);
+                j.push_back(axis, &el);
+                record(&m, j.undo_len());
+            }
+
+            m.translate_front(axis, 2, [0; 3], el_fn);
+            j.translate_front(axis, 2, [0; 3], el_fn);
+            record(&m, j.undo_len());
+            m.translate_back(axis, 5, [5; 3], el_fn);
+            j.translate_back(axis, 5, [5; 3], el_fn);
+            record(&m, j.undo_len());
+        }
+        assert_eq!(j.undo_len() + 1, states.len());
+
+        for state in states.iter().rev() {
+            assert_eq!(j.array().offset(), state.offset());
+            assert!(j.array().iter().eq(state.iter()));
+            j.undo(1);
+        }
+        assert_eq!(j.undo(1), 0);
+
+        assert_eq!(j.redo(usize::MAX), states.len() - 1);
+        assert_eq!(j.array().offset(), m.offset());
+        assert!(j.array().iter().eq(m.iter()));
+    }
+
+    #[test]
+    fn capacity() {
+        let mut j = CircularArrayVec::from_iter([3, 3], 0..9).into_journaled(2);
+        for i in 0..4 {
+            j.push_front(1, &[i, i, i]);
+        }
+        assert_eq!(j.undo(4), 2);
+        assert_eq!(
+            j.array().iter().cloned().collect::<Vec<_>>(),
+            &[6, 7, 8, 0, 0, 0, 1, 1, 1]
+        );
+
+        j.push_back(0, &[9, 9, 9]);
+        assert_eq!(j.redo_len(), 0);
+        assert_eq!(j.redo(1), 0);
+    }
+}
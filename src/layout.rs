@@ -0,0 +1,94 @@
+use serde::Serialize;
+
+use crate::index::RawIndexAdaptor;
+use crate::index_iter::IndexIterator;
+use crate::CircularArray;
+
+/// A report of the memory layout of a [`CircularArray`].
+///
+/// See [`CircularArray::layout_report`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LayoutReport {
+    /// The length of elements for each axis.
+    pub shape: Vec<usize>,
+    /// The strides of each axis.
+    pub strides: Vec<usize>,
+    /// The offset of each axis.
+    pub offset: Vec<usize>,
+    /// The number of contiguous runs of memory visited when iterating over the
+    /// array aligned to the offset.
+    pub contiguous_runs: usize,
+    /// The logical index at which each axis wraps to the start of the buffer, or
+    /// `None` if the axis is not offset.
+    pub wrap_points: Vec<Option<usize>>,
+    /// The number of elements in the array.
+    pub len: usize,
+    /// The size in bytes of the array elements.
+    pub footprint: usize,
+}
+
+impl<const N: usize, A: AsRef<[T]>, T> CircularArray<N, A, T> {
+    /// Get a [`LayoutReport`] of the memory layout of the array.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::CircularArrayVec;
+    /// let array = CircularArrayVec::from_iter_offset([3, 3], [1, 0], 0..9u32);
+    /// let report = array.layout_report();
+    ///
+    /// assert_eq!(report.strides, [1, 3]);
+    /// assert_eq!(report.contiguous_runs, 6);
+    /// assert_eq!(report.wrap_points, [Some(2), None]);
+    /// assert_eq!(report.footprint, 36);
+    /// ```
+    pub fn layout_report(&self) -> LayoutReport {
+        let contiguous_runs = IndexIterator::new_bound_contiguous(self.spans())
+            .into_flat_ranges(&self.strides)
+            .count();
+        let wrap_points = self
+            .offset
+            .iter()
+            .zip(self.shape)
+            .map(|(offset, len)| match offset {
+                0 => None,
+                offset => Some(len - offset),
+            })
+            .collect();
+
+        LayoutReport {
+            shape: self.shape.to_vec(),
+            strides: self.strides.to_vec(),
+            offset: self.offset.to_vec(),
+            contiguous_runs,
+            wrap_points,
+            len: self.len(),
+            footprint: self.len() * std::mem::size_of::<T>(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::CircularArrayVec;
+
+    #[test]
+    fn layout_report() {
+        let m = CircularArrayVec::from_iter([4, 3, 2], 0..24u8);
+        let report = m.layout_report();
+
+        assert_eq!(report.shape, [4, 3, 2]);
+        assert_eq!(report.strides, [1, 4, 12]);
+        assert_eq!(report.offset, [0, 0, 0]);
+        assert_eq!(report.contiguous_runs, 1);
+        assert_eq!(report.wrap_points, [None, None, None]);
+        assert_eq!(report.len, 24);
+        assert_eq!(report.footprint, 24);
+
+        let m = CircularArrayVec::from_iter_offset([4, 3, 2], [0, 1, 1], 0..24u16);
+        let report = m.layout_report();
+
+        assert_eq!(report.contiguous_runs, 4);
+        assert_eq!(report.wrap_points, [None, Some(2), Some(1)]);
+        assert_eq!(report.footprint, 48);
+    }
+}
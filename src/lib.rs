@@ -9,9 +9,10 @@
 //! - `N` dimensional translation over a source array.
 //! - Element iteration in sequentual or contiguous order.
 //! - Support for external types through `AsRef<[T]>` and `AsMut<[T]>`.
+//! - Borrowed views over externally owned memory.
 //! - Optimized for contiguous memory.
 //! - Thorough testing for arrays of smaller dimensionality.
-//! - No external dependencies.
+//! - No external dependencies without optional features.
 //!
 //! ## Mutation
 //!
@@ -224,8 +225,9 @@
 //! ]);
 //! ```
 //!
-//! `n_circular_array` resizing or reshaping functionality can be achieved by using
-//! [`CircularIndex::iter_slice`] and collecting into a new array.
+//! Arrays of equal length can be reshaped with [`CircularArray::reshape`]. Resizing
+//! functionality can be achieved by using [`CircularIndex::iter_slice`] and collecting
+//! into a new array.
 //!
 //! ```
 //! # use n_circular_array::{CircularArrayVec, CircularIndex};
@@ -332,7 +334,15 @@
 //!
 //! Feature | Description
 //! ---|---
-//! `strides` | Exports [`Strides`](strides::Strides) for flattening `N` dimensional indices during translation.
+//! `allocator_api` | Adds `CircularArray::from_iter_in` taking a custom allocator. Requires nightly.
+//! `bytemuck` | Adds zero-copy `as_bytes`, `as_bytes_mut` and `try_from_bytes` for `Pod` elements. Depends on `bytemuck`.
+//! `strides` | Exports [`Strides`](strides::Strides) for flattening and unflattening `N` dimensional indices and ranges during translation.
+//! `layout` | Exports a serializable `LayoutReport` of the array memory layout. Depends on `serde`.
+//! `ops` | Implements `AddAssign`, `SubAssign`, `MulAssign`, `DivAssign` and `RemAssign` between arrays of equal shape, and with scalars.
+//! `rolling` | Exports `RollingSum` and `RollingMinMax`, maintaining the sum, mean, minimum and maximum of each lane of an axis as slices are pushed.
+//! `scratch` | Adds variants of bulk operations writing into caller provided buffers, avoiding allocation.
+//! `spans` | Exports the `BoundSpan` and `UnboundSpan` axis span types and their algebra.
+//! `stencil` | Adds `CircularArray::convolve` over numeric elements with a `Boundary` mode.
 //!
 //! ## Performance
 //!
@@ -342,24 +352,96 @@
 //! outermost dimension(s). This will allow `n_circular_array` to take contiguous
 //! slices of memory where possible, which can result in operations being reduced to
 //! as little as a single iteration over a contiguous slice, or a single call to
-//! `copy_from_slice` during mutation.
+//! `copy_from_slice` during mutation. Axes of power of two length wrap indices
+//! by bitmask rather than modulo.
+//!
+//! Where the axis numbering of an array is fixed, `CircularArray::into_ordered` stores
+//! the array in an explicit memory order, such that the most frequently pushed axis
+//! may be made outermost without renumbering axes.
 //!
 //! External types implementing `AsRef<[T]>` and `AsMut<[T]>` can improve performance
 //! over `Vec<T>` or `Box<T>`. If necessary, `AsRef<[T]>` and `AsMut<[T]>` can be delegated
-//! to `unsafe` methods, although this is discouraged.
+//! to `unsafe` methods, although this is discouraged. Where indices are validated
+//! ahead of time, `get_unchecked`, `get_unchecked_mut` and `iter_slice_unchecked`
+//! skip per-axis bounds assertions for random-access heavy inner loops.
 //!
 //! Finally, for smaller arrays, avoiding a circular array and simply copying (or cloning)
 //! an array window may outperform `n_circular_array`. Benchmark if unsure whether
 //! your use case benefits from `n_circular_array`.
 //!
+#![cfg_attr(feature = "allocator_api", feature(allocator_api))]
+
 #[macro_use]
 mod assertions;
 
+mod absolute;
+mod aligned;
 mod array;
 mod array_iter;
 
+mod array_2d;
+mod array_3d;
+
 mod array_index;
 mod array_mut;
+mod axis_slice;
+mod boundary;
+mod budget;
+#[cfg(feature = "bytemuck")]
+mod bytes;
+mod checked;
+mod cmp;
+mod copy;
+mod cumulative;
+mod cursor;
+mod dims;
+mod double_buffered;
+mod drain;
+mod dump;
+mod fill;
+mod format;
+mod into_iter;
+
+mod history;
+mod journal;
+
+#[cfg(feature = "layout")]
+mod layout;
+mod log_window;
+mod mirror;
+mod neighbors;
+#[cfg(feature = "ops")]
+mod ops;
+mod order;
+mod ordered;
+mod owned;
+mod permuted;
+mod plan;
+mod preview;
+mod push_fn;
+mod reduce;
+mod region;
+mod resize;
+#[cfg(feature = "rolling")]
+mod rolling;
+#[cfg(feature = "scratch")]
+mod scratch;
+mod scrolling;
+mod search;
+mod select;
+mod set;
+mod snapshot;
+mod split;
+#[cfg(feature = "stencil")]
+mod stencil;
+mod step;
+mod text;
+mod tiles;
+mod timed;
+mod translate;
+mod unchecked;
+mod visit;
+mod windows;
 
 mod index;
 mod index_iter;
@@ -369,9 +451,48 @@ mod span_iter;
 
 mod strides;
 
-pub use array::{CircularArray, CircularArrayBox, CircularArrayVec};
+pub mod erased;
+
+pub use absolute::AbsoluteAxes;
+pub use aligned::{AlignedBuffer, CircularArrayAligned};
+pub use array::{
+    CircularArray, CircularArrayBox, CircularArrayVec, CircularArrayView, CircularArrayViewMut,
+};
 pub use array_index::CircularIndex;
 pub use array_mut::CircularMut;
+pub use axis_slice::{AxisSlice, IntoSlice};
+pub use boundary::Boundary;
+pub use budget::Continuation;
+#[cfg(feature = "bytemuck")]
+pub use bytes::BytesError;
+pub use checked::ShapeError;
+pub use cursor::CursorAccessor;
+pub use double_buffered::DoubleBuffered;
+pub use dump::DumpError;
+pub use format::Grid;
+pub use history::CircularHistory;
+pub use journal::Journaled;
+pub use log_window::LogWindow;
+pub use mirror::MirrorTarget;
+pub use neighbors::Neighborhood;
+pub use order::Order;
+pub use ordered::OrderedArray;
+pub use permuted::PermutedView;
+pub use plan::PushPlan;
+pub use preview::PushPreview;
+pub use region::CircularArrayRegion;
+pub use resize::Retain;
+pub use scrolling::{ChunkSource, ScrollingWindow};
+pub use set::CircularArraySet;
+pub use snapshot::SnapshotElement;
+pub use split::CircularArrayRegionMut;
+pub use timed::TimedAxis;
 
+#[cfg(feature = "layout")]
+pub use layout::LayoutReport;
+#[cfg(feature = "rolling")]
+pub use rolling::{RollingMinMax, RollingSum};
+#[cfg(feature = "spans")]
+pub use span::{BoundSpan, UnboundSpan};
 #[cfg(feature = "strides")]
 pub use strides::Strides;
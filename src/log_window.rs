@@ -0,0 +1,220 @@
+use std::borrow::Cow;
+use std::collections::VecDeque;
+use std::fmt;
+
+use crate::array_index::CircularIndex;
+use crate::array_mut::CircularMut;
+use crate::CircularArray;
+
+/// A rolling window of text lines stored within a 1-dimensional [`CircularArray`]
+/// of bytes.
+///
+/// Lines are written through [`LogWindow::push_line`] or [`fmt::Write`], where
+/// `'\n'` terminates the current line. Line boundaries are retained alongside
+/// the array such that, where the array wraps, the oldest lines are evicted
+/// whole. A single line exceeding the capacity of the array retains only its
+/// trailing bytes.
+pub struct LogWindow<A> {
+    /// The byte array.
+    array: CircularArray<1, A, u8>,
+    /// The byte length of each line, from oldest to newest.
+    lines: VecDeque<usize>,
+    /// The byte length of all lines.
+    len: usize,
+    /// Whether the newest line is unterminated.
+    open: bool,
+}
+
+impl<A: AsRef<[u8]> + AsMut<[u8]>> LogWindow<A> {
+    /// Create a new, empty `LogWindow` over the given `array`. The capacity of
+    /// the window is the length of the array. Existing elements are ignored.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::fmt::Write;
+    /// # use n_circular_array::{CircularArray, LogWindow};
+    /// let mut log = LogWindow::new(CircularArray::new([16], vec![0; 16]));
+    /// writeln!(log, "step {}", 1).unwrap();
+    /// writeln!(log, "step {}", 2).unwrap();
+    /// writeln!(log, "step {}", 3).unwrap();
+    ///
+    /// assert_eq!(log.lines().collect::<Vec<_>>(), &["step 2", "step 3"]);
+    /// ```
+    pub fn new(array: CircularArray<1, A, u8>) -> Self {
+        LogWindow {
+            array,
+            lines: VecDeque::new(),
+            len: 0,
+            open: false,
+        }
+    }
+
+    /// Get the capacity of the window in bytes.
+    pub fn capacity(&self) -> usize {
+        self.array.shape[0]
+    }
+
+    /// Get the byte length of all lines.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the window contains no lines.
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    /// Get the number of lines within the window.
+    pub fn line_count(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Push a complete line to the window, evicting the oldest lines as
+    /// necessary. An unterminated line is terminated before `line` is pushed.
+    pub fn push_line(&mut self, line: &str) {
+        self.open = false;
+        self.append(line.as_bytes());
+        self.open = false;
+    }
+
+    /// Remove all lines from the window.
+    pub fn clear(&mut self) {
+        self.lines.clear();
+        self.len = 0;
+        self.open = false;
+    }
+
+    /// Iterate over the lines of the window, from oldest to newest. Lines not
+    /// wrapping the array are borrowed. Invalid UTF-8 (where a line was truncated
+    /// within a `char`) is replaced with [`char::REPLACEMENT_CHARACTER`].
+    pub fn lines(&self) -> impl ExactSizeIterator<Item = Cow<'_, str>> {
+        let capacity = self.capacity();
+        let offset = self.array.offset[0];
+        let data = self.array.array.as_ref();
+        let mut start = capacity - self.len;
+
+        self.lines.iter().map(move |len| {
+            let range = start..start + len;
+            let raw = (range.start + offset) % capacity;
+            start = range.end;
+
+            match raw + len <= capacity {
+                true => String::from_utf8_lossy(&data[raw..raw + len]),
+                false => {
+                    let bytes = self.array.iter_range(0, range).cloned().collect::<Vec<_>>();
+                    Cow::Owned(String::from_utf8_lossy(&bytes).into_owned())
+                }
+            }
+        })
+    }
+
+    /// Get a reference to the byte array.
+    pub fn array(&self) -> &CircularArray<1, A, u8> {
+        &self.array
+    }
+
+    /// Drop the line boundaries, returning the byte array.
+    pub fn into_array(self) -> CircularArray<1, A, u8> {
+        self.array
+    }
+
+    /// Append `bytes` to the newest line, starting a new line if the newest line
+    /// is terminated.
+    fn append(&mut self, bytes: &[u8]) {
+        let capacity = self.capacity();
+        let n = bytes.len().min(capacity);
+        self.array.push_front(0, &bytes[bytes.len() - n..]);
+
+        match self.lines.back_mut() {
+            Some(line) if self.open => *line += bytes.len(),
+            _ => self.lines.push_back(bytes.len()),
+        }
+        self.len += bytes.len();
+        self.open = true;
+
+        while self.len > capacity && self.lines.len() > 1 {
+            self.len -= self.lines.pop_front().expect("Missing line");
+        }
+        if self.len > capacity {
+            self.lines[0] = capacity;
+            self.len = capacity;
+        }
+    }
+}
+
+impl<A: AsRef<[u8]> + AsMut<[u8]>> fmt::Write for LogWindow<A> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for part in s.split_inclusive('\n') {
+            match part.strip_suffix('\n') {
+                Some(line) => {
+                    self.append(line.as_bytes());
+                    self.open = false;
+                }
+                None => self.append(part.as_bytes()),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Write;
+
+    use crate::{CircularArrayVec, LogWindow};
+
+    #[test]
+    fn push_line() {
+        let mut log = LogWindow::new(CircularArrayVec::new([20], vec![0; 20]));
+        let mut expected: Vec<String> = vec![];
+
+        for i in 0..50 {
+            let line = "x".repeat(i % 7) + &i.to_string();
+            log.push_line(&line);
+            expected.push(line);
+
+            let mut len = 0;
+            let retained = expected
+                .iter()
+                .rev()
+                .take_while(|line| {
+                    len += line.len();
+                    len <= 20
+                })
+                .count();
+
+            assert_eq!(
+                log.lines().collect::<Vec<_>>(),
+                expected[expected.len() - retained..]
+            );
+            assert!(log.len() <= log.capacity());
+        }
+    }
+
+    #[test]
+    fn write() {
+        let mut log = LogWindow::new(CircularArrayVec::new([8], vec![0; 8]));
+        write!(log, "a").unwrap();
+        write!(log, "b\ncd").unwrap();
+        assert_eq!(log.lines().collect::<Vec<_>>(), &["ab", "cd"]);
+
+        writeln!(log, "efg").unwrap();
+        write!(log, "h").unwrap();
+        assert_eq!(log.lines().collect::<Vec<_>>(), &["ab", "cdefg", "h"]);
+
+        write!(log, "i").unwrap();
+        assert_eq!(log.lines().collect::<Vec<_>>(), &["cdefg", "hi"]);
+
+        write!(log, "{}", 123456789).unwrap();
+        assert_eq!(log.line_count(), 1);
+        assert_eq!(log.lines().collect::<Vec<_>>(), &["23456789"]);
+
+        log.push_line("new");
+        assert_eq!(log.lines().collect::<Vec<_>>(), &["new"]);
+
+        log.clear();
+        assert!(log.is_empty());
+        assert_eq!(log.lines().count(), 0);
+    }
+}
@@ -0,0 +1,172 @@
+use std::array;
+use std::ops::Range;
+
+use crate::index::RawIndexAdaptor;
+use crate::index_iter::IndexIterator;
+use crate::span::BoundSpan;
+use crate::CircularArray;
+
+/// An external linear buffer mirroring the elements of a [`CircularArray`].
+///
+/// The mirror holds the elements of the array in raw order, such that the array
+/// offset must be applied when reading the mirror (see [`CircularArray::offset`]).
+/// Pushes then invalidate only the pushed slices of the mirror, and a wrapping
+/// region of the array is resolved into contiguous linear writes.
+///
+/// See [`CircularArray::sync_to`] and [`CircularArray::sync_region_to`].
+pub trait MirrorTarget<T> {
+    /// Write the elements `el` to the mirror, starting at flat index `offset`.
+    fn write(&mut self, offset: usize, el: &[T]);
+}
+
+impl<T: Clone> MirrorTarget<T> for [T] {
+    fn write(&mut self, offset: usize, el: &[T]) {
+        self[offset..offset + el.len()].clone_from_slice(el);
+    }
+}
+
+impl<T: Clone> MirrorTarget<T> for Vec<T> {
+    fn write(&mut self, offset: usize, el: &[T]) {
+        self.as_mut_slice().write(offset, el);
+    }
+}
+
+impl<const N: usize, A: AsRef<[T]>, T> CircularArray<N, A, T> {
+    /// Write all elements of the array to the given `target`, in raw order.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex, MirrorTarget};
+    /// let array = CircularArray::new_offset([3, 2], [1, 0], vec![
+    ///     2, 0, 1,
+    ///     5, 3, 4,
+    /// ]);
+    /// let mut mirror = vec![0; 6];
+    /// array.sync_to(&mut mirror);
+    ///
+    /// assert_eq!(mirror, &[
+    ///     2, 0, 1,
+    ///     5, 3, 4,
+    /// ]);
+    /// ```
+    pub fn sync_to<M: MirrorTarget<T> + ?Sized>(&self, target: &mut M) {
+        target.write(0, self.array.as_ref());
+    }
+
+    /// Write the elements within the given `slice` to the given `target`, aligned
+    /// to the offset. Elements are written to their raw position within the mirror,
+    /// such that only the given `slice` of the mirror is updated. Writes are
+    /// ordered by raw index. Following a push of `n` slices to the front (back) of
+    /// an axis, the pushed slices are the last (first) `n` slices of the axis.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex, CircularMut, MirrorTarget};
+    /// let mut array = CircularArray::new([3, 2], vec![
+    ///     0, 1, 2,
+    ///     3, 4, 5,
+    /// ]);
+    /// let mut mirror = vec![0; 6];
+    /// array.sync_to(&mut mirror);
+    ///
+    /// array.push_front(0, &[6, 7]);
+    /// array.sync_region_to([2..3, 0..2], &mut mirror);
+    ///
+    /// assert_eq!(mirror, &[
+    ///     6, 1, 2,
+    ///     7, 4, 5,
+    /// ]);
+    /// ```
+    pub fn sync_region_to<M: MirrorTarget<T> + ?Sized>(
+        &self,
+        slice: [Range<usize>; N],
+        target: &mut M,
+    ) {
+        slice.iter().enumerate().for_each(|(i, range)| {
+            assert!(
+                range.start <= range.end && range.end <= self.shape[i],
+                "range {:?} is out of bounds for axis {} of length {}",
+                range,
+                i,
+                self.shape[i]
+            );
+        });
+
+        if slice.iter().any(|range| range.is_empty()) {
+            return;
+        }
+
+        let spans = array::from_fn(|i| {
+            BoundSpan::new(
+                (slice[i].start + self.offset[i]) % self.shape[i],
+                slice[i].len(),
+                self.shape[i],
+            )
+        });
+
+        let data = self.array.as_ref();
+        IndexIterator::new_bound_contiguous_ordered(spans)
+            .into_flat_ranges(&self.strides)
+            .for_each(|range| target.write(range.start, &data[range]));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CircularArrayVec, CircularMut, MirrorTarget};
+
+    /// A mirror recording the length of each write.
+    struct Recorder(Vec<usize>, Vec<usize>);
+
+    impl MirrorTarget<usize> for Recorder {
+        fn write(&mut self, offset: usize, el: &[usize]) {
+            self.0.write(offset, el);
+            self.1.push(el.len());
+        }
+    }
+
+    #[test]
+    fn sync_to() {
+        let shape = [4, 3, 2];
+        let len = shape.iter().product::<usize>();
+        let mut m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..len);
+        let mut mirror = vec![0; len];
+
+        m.sync_to(&mut mirror);
+        assert_eq!(m.data(), &mirror);
+
+        for axis in 0..3 {
+            let axis_len = m.shape()[axis];
+            let mut slice = m.shape().map(|len| 0..len);
+
+            m.push_front(axis, &vec![len + axis; 2 * m.slice_len(axis)]);
+            slice[axis] = axis_len - 2..axis_len;
+            m.sync_region_to(slice.clone(), mirror.as_mut_slice());
+            assert_eq!(m.data(), &mirror);
+
+            m.push_back(axis, &vec![len + axis + 3; m.slice_len(axis)]);
+            slice[axis] = 0..1;
+            m.sync_region_to(slice, mirror.as_mut_slice());
+            assert_eq!(m.data(), &mirror);
+        }
+    }
+
+    #[test]
+    fn sync_region_to() {
+        let shape = [4, 3, 2];
+        let len = shape.iter().product::<usize>();
+        let m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..len);
+        let mut recorder = Recorder(vec![usize::MAX; len], vec![]);
+
+        m.sync_region_to([1..4, 1..3, 0..1], &mut recorder);
+        assert_eq!(recorder.1, &[1, 2, 1, 2]);
+        for (index, el) in m.indexed_iter() {
+            let expected = match index[0] >= 1 && index[1] >= 1 && index[2] == 0 {
+                true => *el,
+                false => usize::MAX,
+            };
+            let raw = std::array::from_fn(|i| (index[i] + m.offset()[i]) % shape[i]);
+            assert_eq!(recorder.0[m.strides.offset_index(raw)], expected);
+        }
+    }
+}
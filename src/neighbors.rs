@@ -0,0 +1,182 @@
+use crate::array_index::CircularIndex;
+use crate::array_iter::iter_indices;
+use crate::CircularArray;
+
+/// The neighborhood of an index. See [`CircularArray::iter_neighbors`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Neighborhood {
+    /// Indices within a Chebyshev distance of `radius` (a square or cube).
+    Moore,
+    /// Indices within a Manhattan distance of `radius` (a diamond or octahedron).
+    VonNeumann,
+}
+
+impl Neighborhood {
+    /// Returns `true` if the given offset from the center is within the
+    /// neighborhood of `radius`.
+    fn contains(&self, delta: &[isize], radius: usize) -> bool {
+        match self {
+            Neighborhood::Moore => true,
+            Neighborhood::VonNeumann => {
+                delta.iter().map(|d| d.unsigned_abs()).sum::<usize>() <= radius
+            }
+        }
+    }
+}
+
+/// Iterate over the offsets of the given `neighborhood` of `radius`, excluding
+/// the center.
+fn iter_deltas<const N: usize>(
+    radius: usize,
+    neighborhood: Neighborhood,
+) -> impl Iterator<Item = [isize; N]> {
+    iter_indices([radius * 2 + 1; N])
+        .map(move |index| index.map(|i| i as isize - radius as isize))
+        .filter(move |delta| delta.iter().any(|d| *d != 0) && neighborhood.contains(delta, radius))
+}
+
+impl<const N: usize, A: AsRef<[T]>, T> CircularArray<N, A, T> {
+    /// Iterate over the indices and elements of the `neighborhood` of `radius`
+    /// about the given `index`, aligned to the offset. The element at `index` is
+    /// excluded, as are indices outside the bounds of the array. Neighbors are
+    /// yielded with axis `0` varying fastest.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, Neighborhood};
+    /// let array = CircularArray::new_offset([3, 3], [1, 1], vec![
+    ///     8, 6, 7,
+    ///     2, 0, 1,
+    ///     5, 3, 4,
+    /// ]);
+    ///
+    /// let neighbors = array.iter_neighbors([0, 1], 1, Neighborhood::VonNeumann);
+    /// assert_eq!(neighbors.collect::<Vec<_>>(), &[
+    ///     ([0, 0], &0), ([1, 1], &4), ([0, 2], &6),
+    /// ]);
+    /// ```
+    pub fn iter_neighbors(
+        &self,
+        index: [usize; N],
+        radius: usize,
+        neighborhood: Neighborhood,
+    ) -> impl Iterator<Item = ([usize; N], &T)> {
+        index.iter().enumerate().for_each(|(i, idx)| {
+            assert_slice_index!(self, i, *idx);
+        });
+
+        iter_deltas(radius, neighborhood).filter_map(move |delta: [isize; N]| {
+            let mut neighbor = [0; N];
+            for i in 0..N {
+                neighbor[i] = index[i]
+                    .checked_add_signed(delta[i])
+                    .filter(|idx| *idx < self.shape[i])?;
+            }
+
+            Some((neighbor, self.get(neighbor)))
+        })
+    }
+
+    /// Iterate over the indices and elements of the `neighborhood` of `radius`
+    /// about the given `index`, aligned to the offset, wrapping over the bounds
+    /// of each axis. The element at `index` is excluded. Where the neighborhood
+    /// exceeds the length of an axis, indices are yielded more than once.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, Neighborhood};
+    /// let array = CircularArray::new_offset([3, 3], [1, 1], vec![
+    ///     8, 6, 7,
+    ///     2, 0, 1,
+    ///     5, 3, 4,
+    /// ]);
+    ///
+    /// let neighbors = array.iter_neighbors_wrapped([0, 1], 1, Neighborhood::VonNeumann);
+    /// assert_eq!(neighbors.collect::<Vec<_>>(), &[
+    ///     ([0, 0], &0), ([2, 1], &5), ([1, 1], &4), ([0, 2], &6),
+    /// ]);
+    /// ```
+    pub fn iter_neighbors_wrapped(
+        &self,
+        index: [usize; N],
+        radius: usize,
+        neighborhood: Neighborhood,
+    ) -> impl Iterator<Item = ([usize; N], &T)> {
+        index.iter().enumerate().for_each(|(i, idx)| {
+            assert_slice_index!(self, i, *idx);
+        });
+
+        iter_deltas(radius, neighborhood).map(move |delta: [isize; N]| {
+            let neighbor: [usize; N] = std::array::from_fn(|i| {
+                (index[i] as isize + delta[i]).rem_euclid(self.shape[i] as isize) as usize
+            });
+
+            (neighbor, self.get(neighbor))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CircularArrayVec, CircularIndex, Neighborhood};
+
+    #[test]
+    fn iter_neighbors() {
+        let shape = [4, 3, 2];
+        let m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..shape.iter().product());
+
+        assert_eq!(
+            m.iter_neighbors([0, 0, 0], 1, Neighborhood::Moore).count(),
+            7
+        );
+        assert_eq!(
+            m.iter_neighbors([1, 1, 0], 1, Neighborhood::Moore).count(),
+            17
+        );
+        assert_eq!(
+            m.iter_neighbors([1, 1, 0], 1, Neighborhood::VonNeumann)
+                .count(),
+            5
+        );
+        assert_eq!(
+            m.iter_neighbors([1, 1, 0], 2, Neighborhood::VonNeumann)
+                .count(),
+            14
+        );
+
+        for (index, el) in m.iter_neighbors([1, 1, 0], 2, Neighborhood::Moore) {
+            assert_eq!(m.get(index), el);
+            assert!(index != [1, 1, 0]);
+        }
+    }
+
+    #[test]
+    fn iter_neighbors_wrapped() {
+        let shape = [4, 3, 2];
+        let m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..shape.iter().product());
+
+        let neighbors = m
+            .iter_neighbors_wrapped([0, 0, 0], 1, Neighborhood::Moore)
+            .collect::<Vec<_>>();
+        assert_eq!(neighbors.len(), 26);
+        for (index, el) in neighbors {
+            assert_eq!(m.get(index), el);
+        }
+
+        let neighbors = m
+            .iter_neighbors_wrapped([3, 2, 1], 1, Neighborhood::VonNeumann)
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            neighbors,
+            &[
+                [3, 2, 0],
+                [3, 1, 1],
+                [2, 2, 1],
+                [0, 2, 1],
+                [3, 0, 1],
+                [3, 2, 0],
+            ]
+        );
+    }
+}
@@ -0,0 +1,92 @@
+use std::ops::{AddAssign, DivAssign, MulAssign, RemAssign, SubAssign};
+
+use crate::CircularArray;
+
+/// Implement an elementwise compound assignment operator between arrays of equal
+/// shape, aligned to the offset of each array, and with a scalar, in raw order.
+macro_rules! impl_op_assign {
+    ($trait:ident, $fn:ident) => {
+        impl<const N: usize, A, B, T, U> $trait<&CircularArray<N, B, U>> for CircularArray<N, A, T>
+        where
+            A: AsRef<[T]> + AsMut<[T]>,
+            B: AsRef<[U]>,
+            T: $trait<U>,
+            U: Clone,
+        {
+            /// # Panics
+            ///
+            /// Panics if the shapes of the arrays do not match.
+            fn $fn(&mut self, rhs: &CircularArray<N, B, U>) {
+                self.zip_apply(rhs, |a, b| a.$fn(b.clone()));
+            }
+        }
+
+        impl<const N: usize, A, T> $trait<T> for CircularArray<N, A, T>
+        where
+            A: AsRef<[T]> + AsMut<[T]>,
+            T: $trait + Clone,
+        {
+            fn $fn(&mut self, rhs: T) {
+                self.array
+                    .as_mut()
+                    .iter_mut()
+                    .for_each(|el| el.$fn(rhs.clone()));
+            }
+        }
+    };
+}
+
+impl_op_assign!(AddAssign, add_assign);
+impl_op_assign!(SubAssign, sub_assign);
+impl_op_assign!(MulAssign, mul_assign);
+impl_op_assign!(DivAssign, div_assign);
+impl_op_assign!(RemAssign, rem_assign);
+
+#[cfg(test)]
+mod tests {
+    use crate::{CircularArrayVec, CircularIndex};
+
+    #[test]
+    fn op_assign_array() {
+        let shape = [4, 3, 2];
+        let mut m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..24);
+        let n = CircularArrayVec::from_iter_offset(shape, [3, 0, 1], 100..124);
+        let pairs = m.iter().cloned().zip(n.iter().cloned()).collect::<Vec<_>>();
+
+        m += &n;
+        assert!(m.iter().cloned().eq(pairs.iter().map(|(a, b)| a + b)));
+        m -= &n;
+        assert!(m.iter().cloned().eq(pairs.iter().map(|(a, _)| *a)));
+        m *= &n;
+        assert!(m.iter().cloned().eq(pairs.iter().map(|(a, b)| a * b)));
+        m /= &n;
+        assert!(m.iter().cloned().eq(pairs.iter().map(|(a, _)| *a)));
+        m %= &n;
+        assert!(m.iter().cloned().eq(pairs.iter().map(|(a, b)| a % b)));
+    }
+
+    #[test]
+    fn op_assign_scalar() {
+        let data = [2.0, 4.0, 8.0, 16.0, 32.0, 64.0];
+        let mut m = CircularArrayVec::from_iter_offset([3, 2], [1, 1], data.into_iter());
+        let expected = m.iter().cloned().collect::<Vec<f64>>();
+
+        m *= 0.5;
+        m += 1.0;
+        m -= 2.0;
+        m /= 0.25;
+        assert!(m
+            .iter()
+            .cloned()
+            .eq(expected.iter().map(|el| (el * 0.5 - 1.0) * 4.0)));
+        m %= 3.0;
+        assert!(m.iter().all(|el| *el < 3.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn op_assign_shape() {
+        let mut m = CircularArrayVec::from_iter([3, 2], 0..6);
+        m += &CircularArrayVec::from_iter([2, 3], 0..6);
+    }
+}
@@ -0,0 +1,105 @@
+use std::array;
+
+use crate::array_index::CircularIndex;
+use crate::{CircularArray, CircularArrayVec};
+
+/// The memory order of the elements of an `N` dimensional array, for exchanging
+/// elements with other libraries.
+///
+/// Orders follow the convention of Fortran and C for an index `[i0, i1, ..]`,
+/// where the first index of a column-major array varies fastest. All
+/// [`CircularArray`] buffers, and elements yielded by [`CircularIndex::iter`], are
+/// in [`Order::ColumnMajor`]. See [`CircularArray::into_ordered`] to store an
+/// array in an arbitrary order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Order {
+    /// Axis `0` varies fastest (Fortran order). This is the order of all
+    /// [`CircularArray`] buffers.
+    ColumnMajor,
+    /// Axis `N - 1` varies fastest (C order).
+    RowMajor,
+}
+
+/// Get the axes of an `N` dimensional array in reverse order.
+fn reversed<const N: usize>() -> [usize; N] {
+    array::from_fn(|i| N - 1 - i)
+}
+
+impl<const N: usize, A: AsRef<[T]>, T: Clone> CircularArray<N, A, T> {
+    /// Get the elements of the array aligned to the offset, laid out in the given
+    /// memory `order`.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, Order};
+    /// let array = CircularArray::new_offset([3, 2], [1, 0], vec![
+    ///     2, 0, 1,
+    ///     5, 3, 4,
+    /// ]);
+    ///
+    /// assert_eq!(array.to_order(Order::ColumnMajor), &[0, 1, 2, 3, 4, 5]);
+    /// assert_eq!(array.to_order(Order::RowMajor), &[0, 3, 1, 4, 2, 5]);
+    /// ```
+    pub fn to_order(&self, order: Order) -> Vec<T> {
+        match order {
+            Order::ColumnMajor => self.iter().cloned().collect(),
+            Order::RowMajor => self.permuted_view(reversed()).iter().cloned().collect(),
+        }
+    }
+}
+
+impl<const N: usize, T: Clone> CircularArrayVec<N, T> {
+    /// Create a new [`CircularArrayVec`] of the given `shape` from elements laid
+    /// out in the given memory `order`. The length of `array` **must** equal the
+    /// product of the shape.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArrayVec, CircularIndex, Order};
+    /// let array = CircularArrayVec::from_order([3, 2], Order::RowMajor, vec![
+    ///     0, 3,
+    ///     1, 4,
+    ///     2, 5,
+    /// ]);
+    ///
+    /// assert_eq!(array.data(), &[
+    ///     0, 1, 2,
+    ///     3, 4, 5,
+    /// ]);
+    /// ```
+    pub fn from_order(shape: [usize; N], order: Order, array: Vec<T>) -> Self {
+        match order {
+            Order::ColumnMajor => CircularArrayVec::new(shape, array),
+            Order::RowMajor => {
+                let shape = reversed().map(|axis| shape[axis]);
+                CircularArrayVec::new(shape, array).permute_axes(reversed())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CircularArrayVec, CircularIndex, Order};
+
+    #[test]
+    fn to_order() {
+        let shape = [4, 3, 2];
+        let m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..shape.iter().product());
+
+        let row_major = m.to_order(Order::RowMajor);
+        for (index, el) in m.indexed_iter() {
+            let [x, y, z] = index;
+            assert_eq!(row_major[z + y * 2 + x * 6], *el);
+        }
+
+        for order in [Order::ColumnMajor, Order::RowMajor] {
+            let array = CircularArrayVec::from_order(shape, order, m.to_order(order));
+            assert_eq!(array.offset(), &[0; 3]);
+            assert_eq!(
+                array.iter().collect::<Vec<_>>(),
+                m.iter().collect::<Vec<_>>()
+            );
+        }
+    }
+}
@@ -0,0 +1,270 @@
+use std::array;
+use std::borrow::Cow;
+
+use crate::array_index::CircularIndex;
+use crate::array_mut::CircularMut;
+use crate::{CircularArray, PermutedView};
+
+/// A [`CircularArray`] stored in an explicit memory order, with axes numbered
+/// independently of their order in memory.
+///
+/// Axis `order[j]` of the array is stored as axis `j` of the inner array, such
+/// that `order[0]` is the innermost (fastest varying) axis in memory, and
+/// `order[N - 1]` the outermost. Making the most frequently pushed axis outermost
+/// allows pushes to be reduced to contiguous copies, without changing the axis
+/// numbering of the caller. Elements passed to and yielded from the array are
+/// ordered with axis `0` the fastest varying, as for any [`CircularArray`].
+///
+/// The inner array is a [`CircularArray::permute_axes`] of the array, and
+/// [`OrderedArray::view`] a [`PermutedView`] restoring the axis numbering. The
+/// orders of [`Order`](crate::Order) are the permutations `[0, 1, ..]` and
+/// `[.., 1, 0]`.
+///
+/// See [`CircularArray::into_ordered`].
+pub struct OrderedArray<const N: usize, A, T> {
+    /// The array in memory order.
+    array: CircularArray<N, A, T>,
+    /// The axis of the array stored as each axis of the inner array.
+    order: [usize; N],
+    /// The length of elements for each axis of the array.
+    shape: [usize; N],
+}
+
+impl<const N: usize, A: AsRef<[T]>, T> OrderedArray<N, A, T> {
+    /// Create a new [`OrderedArray`] from an `array` already laid out in the
+    /// given memory `order`, such that axis `j` of `array` is axis `order[j]` of
+    /// the new array. Elements are not copied.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex, OrderedArray};
+    /// // A [2, 3] array, stored with axis 0 outermost.
+    /// let array = CircularArray::new([3, 2], vec![
+    ///     0, 2, 4,
+    ///     1, 3, 5,
+    /// ]);
+    /// let ordered = OrderedArray::from_memory_order([1, 0], array);
+    ///
+    /// assert_eq!(ordered.shape(), &[2, 3]);
+    /// assert_eq!(ordered.view().iter().cloned().collect::<Vec<_>>(), &[
+    ///     0, 1,
+    ///     2, 3,
+    ///     4, 5,
+    /// ]);
+    /// ```
+    pub fn from_memory_order(order: [usize; N], array: CircularArray<N, A, T>) -> Self {
+        assert_permutation!(order, N);
+
+        let mut shape = [0; N];
+        order
+            .iter()
+            .zip(array.shape)
+            .for_each(|(axis, len)| shape[*axis] = len);
+
+        OrderedArray {
+            array,
+            order,
+            shape,
+        }
+    }
+
+    /// Get the array shape.
+    pub fn shape(&self) -> &[usize; N] {
+        &self.shape
+    }
+
+    /// Get the axis of the array stored as each axis of the inner array, from the
+    /// innermost to the outermost.
+    pub fn order(&self) -> &[usize; N] {
+        &self.order
+    }
+
+    /// Get a reference to the inner array, in memory order.
+    pub fn array(&self) -> &CircularArray<N, A, T> {
+        &self.array
+    }
+
+    /// Drop the memory order, returning the inner array.
+    pub fn into_inner(self) -> CircularArray<N, A, T> {
+        self.array
+    }
+
+    /// Get a view of the array with the axes of the array, for indexing and
+    /// iteration by [`CircularIndex`].
+    pub fn view(&self) -> PermutedView<'_, N, T> {
+        let mut axes = [0; N];
+        self.order
+            .iter()
+            .enumerate()
+            .for_each(|(j, axis)| axes[*axis] = j);
+
+        self.array.permuted_view(axes)
+    }
+
+    /// Get a reference to the element at the given index, aligned to the offset.
+    pub fn get(&self, index: [usize; N]) -> &T {
+        self.array.get(self.memory_index(index))
+    }
+
+    /// Map an `index` of the array to an index of the inner array.
+    fn memory_index(&self, index: [usize; N]) -> [usize; N] {
+        array::from_fn(|j| index[self.order[j]])
+    }
+
+    /// Get the axis of the inner array storing the given `axis`.
+    fn memory_axis(&self, axis: usize) -> usize {
+        assert_shape_index!(axis, N);
+        self.order.iter().position(|a| *a == axis).unwrap()
+    }
+}
+
+impl<const N: usize, A: AsRef<[T]> + AsMut<[T]>, T: Clone> OrderedArray<N, A, T> {
+    /// Get a mutable reference to the element at the given index, aligned to the
+    /// offset.
+    pub fn get_mut(&mut self, index: [usize; N]) -> &mut T {
+        let index = self.memory_index(index);
+        self.array.get_mut(index)
+    }
+
+    /// Push elements to the front of the given `axis`, ordered with axis `0` the
+    /// fastest varying. See [`CircularMut::push_front`].
+    ///
+    /// Elements are pushed without reordering where the pushed slices are laid out
+    /// in the same order in memory, such as a single slice of the outermost axis.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArrayVec, CircularIndex};
+    /// // Axis 0 is pushed most frequently, and stored outermost.
+    /// let mut ordered = CircularArrayVec::from_iter([4, 2], 0..8).into_ordered([1, 0]);
+    /// ordered.push_front(0, &[8, 9]);
+    ///
+    /// assert_eq!(ordered.array().data(), &[
+    ///     8, 9,
+    ///     1, 5,
+    ///     2, 6,
+    ///     3, 7,
+    /// ]);
+    /// assert_eq!(ordered.view().iter().cloned().collect::<Vec<_>>(), &[
+    ///     1, 2, 3, 8,
+    ///     5, 6, 7, 9,
+    /// ]);
+    /// ```
+    pub fn push_front(&mut self, axis: usize, el: &[T]) {
+        let memory_axis = self.memory_axis(axis);
+        let el = self.to_memory_order(axis, el);
+
+        self.array.push_front(memory_axis, &el);
+    }
+
+    /// Push elements to the back of the given `axis`, ordered with axis `0` the
+    /// fastest varying. See [`CircularMut::push_back`].
+    pub fn push_back(&mut self, axis: usize, el: &[T]) {
+        let memory_axis = self.memory_axis(axis);
+        let el = self.to_memory_order(axis, el);
+
+        self.array.push_back(memory_axis, &el);
+    }
+
+    /// Reorder elements of slices of the given `axis` into memory order, borrowing
+    /// `el` where already in memory order.
+    fn to_memory_order<'a>(&self, axis: usize, el: &'a [T]) -> Cow<'a, [T]> {
+        let el_len = el.len();
+        let slice_len = self.array.slice_len(self.memory_axis(axis));
+        if slice_len == 0 {
+            return Cow::Borrowed(el);
+        }
+        assert_element_len!(axis, el_len, slice_len);
+
+        let mut shape = self.shape;
+        shape[axis] = el_len / slice_len;
+        if self
+            .order
+            .iter()
+            .filter(|axis| shape[**axis] > 1)
+            .is_sorted()
+        {
+            return Cow::Borrowed(el);
+        }
+
+        let array = CircularArray::new(shape, el);
+        Cow::Owned(array.permute_axes(self.order).into_vec())
+    }
+}
+
+impl<const N: usize, A: AsRef<[T]>, T: Clone> CircularArray<N, A, T> {
+    /// Convert the array into an [`OrderedArray`] stored in the given memory
+    /// `order`, retaining the axis numbering and offset of the array. Axis
+    /// `order[0]` is stored innermost, and axis `order[N - 1]` outermost.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let array = CircularArray::new_offset([3, 2], [1, 0], vec![
+    ///     2, 0, 1,
+    ///     5, 3, 4,
+    /// ]);
+    /// let ordered = array.into_ordered([1, 0]);
+    ///
+    /// assert_eq!(ordered.shape(), &[3, 2]);
+    /// assert_eq!(ordered.get([2, 1]), &5);
+    /// assert_eq!(ordered.array().offset(), &[0, 1]);
+    /// assert_eq!(ordered.array().iter().cloned().collect::<Vec<_>>(), &[
+    ///     0, 3,
+    ///     1, 4,
+    ///     2, 5,
+    /// ]);
+    /// ```
+    pub fn into_ordered(self, order: [usize; N]) -> OrderedArray<N, Vec<T>, T> {
+        assert_permutation!(order, N);
+
+        OrderedArray::from_memory_order(order, self.permute_axes(order))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CircularArrayVec, CircularIndex, CircularMut};
+
+    #[test]
+    fn ordered() {
+        let shape = [4, 3, 2];
+        let len = shape.iter().product::<usize>();
+
+        for order in [[0, 1, 2], [2, 1, 0], [1, 2, 0], [0, 2, 1]] {
+            let mut m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..len);
+            let mut o = m.clone().into_ordered(order);
+            assert_eq!(o.shape(), &shape);
+            assert!(o.view().iter().eq(m.iter()));
+
+            for axis in 0..3 {
+                for n in 0..=shape[axis] {
+                    let el = (0..n * m.slice_len(axis))
+                        .map(|i| len * (axis + 1) + i)
+                        .collect::<Vec<_>>();
+                    m.push_front(axis, &el);
+                    o.push_front(axis, &el);
+                    assert!(o.view().iter().eq(m.iter()));
+
+                    let el = &el[..el.len().min(m.slice_len(axis))];
+                    m.push_back(axis, el);
+                    o.push_back(axis, el);
+                    assert!(o.view().iter().eq(m.iter()));
+                }
+            }
+
+            for (index, el) in m.indexed_iter() {
+                assert_eq!(o.get(index), el);
+            }
+            *o.get_mut([3, 2, 1]) = usize::MAX;
+            assert_eq!(o.view().get([3, 2, 1]), &usize::MAX);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn ordered_permutation() {
+        let m = CircularArrayVec::from_iter([3, 3], 0..9);
+        let _ = m.into_ordered([1, 1]);
+    }
+}
@@ -0,0 +1,204 @@
+use std::mem;
+
+use crate::index::RawIndexAdaptor;
+use crate::index_iter::IndexIterator;
+use crate::span::BoundSpan;
+use crate::CircularArray;
+
+impl<const N: usize, A: AsRef<[T]> + AsMut<[T]>, T> CircularArray<N, A, T> {
+    /// Push elements to the front of the given `axis`, moving elements into the
+    /// array. Returns the evicted elements in the order of
+    /// [`CircularIndex::iter_range`](crate::CircularIndex::iter_range). Elements
+    /// need not implement `Clone`. See [`CircularMut::push_front`](crate::CircularMut::push_front).
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let mut array = CircularArray::new([3], vec![
+    ///     String::from("a"), String::from("b"), String::from("c"),
+    /// ]);
+    /// let evicted = array.push_front_owned(0, vec![String::from("d")]);
+    ///
+    /// assert_eq!(evicted, &["a"]);
+    /// assert_eq!(array.iter().collect::<Vec<_>>(), &["b", "c", "d"]);
+    /// ```
+    pub fn push_front_owned(&mut self, axis: usize, el: Vec<T>) -> Vec<T> {
+        self.push_front_owned_iter(axis, el)
+    }
+
+    /// Push elements to the back of the given `axis`, moving elements into the
+    /// array. Returns the evicted elements in the order of
+    /// [`CircularIndex::iter_range`](crate::CircularIndex::iter_range). Elements
+    /// need not implement `Clone`. See [`CircularMut::push_back`](crate::CircularMut::push_back).
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let mut array = CircularArray::new([3], vec![
+    ///     String::from("a"), String::from("b"), String::from("c"),
+    /// ]);
+    /// let evicted = array.push_back_owned(0, vec![String::from("d")]);
+    ///
+    /// assert_eq!(evicted, &["c"]);
+    /// assert_eq!(array.iter().collect::<Vec<_>>(), &["d", "a", "b"]);
+    /// ```
+    pub fn push_back_owned(&mut self, axis: usize, el: Vec<T>) -> Vec<T> {
+        self.push_back_owned_iter(axis, el)
+    }
+
+    /// Push an iterator of elements to the front of the given `axis`, moving
+    /// elements into the array. Returns the evicted elements in the order of
+    /// [`CircularIndex::iter_range`](crate::CircularIndex::iter_range). See
+    /// [`CircularArray::push_front_owned`].
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let mut array = CircularArray::new([2, 2], vec![
+    ///     Box::new(0), Box::new(1),
+    ///     Box::new(2), Box::new(3),
+    /// ]);
+    /// let evicted = array.push_front_owned_iter(1, (4..6).map(Box::new));
+    ///
+    /// assert_eq!(evicted, &[Box::new(0), Box::new(1)]);
+    /// assert_eq!(array.iter().map(|el| **el).collect::<Vec<_>>(), &[
+    ///     2, 3,
+    ///     4, 5,
+    /// ]);
+    /// ```
+    pub fn push_front_owned_iter<I>(&mut self, axis: usize, el: I) -> Vec<T>
+    where
+        I: IntoIterator<IntoIter: ExactSizeIterator, Item = T>,
+    {
+        let iter = el.into_iter();
+        let n = self.owned_slice_n(axis, iter.len());
+
+        if n == 0 {
+            return vec![];
+        }
+
+        let spans = self.spans_axis_bound(axis, BoundSpan::new(0, n, self.shape[axis]));
+        let evicted = self.push_owned(IndexIterator::new_bound_contiguous(spans), iter);
+        self.incr_offset(axis, n);
+
+        evicted
+    }
+
+    /// Push an iterator of elements to the back of the given `axis`, moving
+    /// elements into the array. Returns the evicted elements in the order of
+    /// [`CircularIndex::iter_range`](crate::CircularIndex::iter_range). See
+    /// [`CircularArray::push_back_owned`].
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let mut array = CircularArray::new([2, 2], vec![
+    ///     Box::new(0), Box::new(1),
+    ///     Box::new(2), Box::new(3),
+    /// ]);
+    /// let evicted = array.push_back_owned_iter(1, (4..6).map(Box::new));
+    ///
+    /// assert_eq!(evicted, &[Box::new(2), Box::new(3)]);
+    /// assert_eq!(array.iter().map(|el| **el).collect::<Vec<_>>(), &[
+    ///     4, 5,
+    ///     0, 1,
+    /// ]);
+    /// ```
+    pub fn push_back_owned_iter<I>(&mut self, axis: usize, el: I) -> Vec<T>
+    where
+        I: IntoIterator<IntoIter: ExactSizeIterator, Item = T>,
+    {
+        let iter = el.into_iter();
+        let n = self.owned_slice_n(axis, iter.len());
+
+        if n == 0 {
+            return vec![];
+        }
+
+        let span = BoundSpan::new(self.shape[axis] - n, n, self.shape[axis]);
+        let spans = self.spans_axis_bound(axis, span);
+        let evicted = self.push_owned(IndexIterator::new_bound_contiguous(spans), iter);
+        self.decr_offset(axis, n);
+
+        evicted
+    }
+
+    /// Get the number of slices of `axis` within `el_len` elements, asserting
+    /// `el_len` is a valid push.
+    fn owned_slice_n(&self, axis: usize, el_len: usize) -> usize {
+        assert_shape_index!(axis, N);
+        let slice_len = self.slice_len(axis);
+        let n = el_len / slice_len;
+
+        assert_element_len!(axis, el_len, slice_len);
+        assert_slice_len!(self, axis, n);
+
+        n
+    }
+
+    /// Move elements into the given spans, returning the replaced elements.
+    fn push_owned<'a>(
+        &'a mut self,
+        spans: impl RawIndexAdaptor<'a, N>,
+        mut el: impl ExactSizeIterator<Item = T>,
+    ) -> Vec<T> {
+        let CircularArray { array, strides, .. } = self;
+        let mut evicted = Vec::with_capacity(el.len());
+
+        for range in spans.into_flat_ranges(strides) {
+            for dst in &mut array.as_mut()[range] {
+                let src = el.next().expect("Misaligned owned elements");
+                evicted.push(mem::replace(dst, src));
+            }
+        }
+
+        evicted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CircularArrayVec, CircularIndex, CircularMut};
+
+    #[test]
+    fn push_owned() {
+        let shape = [4, 3, 2];
+        let len = shape.iter().product::<usize>();
+
+        for axis in 0..3 {
+            for n in 0..=shape[axis] {
+                let mut m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..len);
+                let mut expected = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..len);
+
+                for i in 0..4 {
+                    let el = (0..n * m.slice_len(axis))
+                        .map(|j| len * (i + 1) + j)
+                        .collect::<Vec<_>>();
+                    let axis_len = m.shape()[axis];
+
+                    let (evicted, range) = match i % 2 {
+                        0 => (m.push_front_owned(axis, el.clone()), 0..n),
+                        _ => (m.push_back_owned(axis, el.clone()), axis_len - n..axis_len),
+                    };
+                    match n {
+                        0 => assert!(evicted.is_empty()),
+                        _ => assert!(evicted.iter().eq(expected.iter_range(axis, range))),
+                    }
+
+                    match i % 2 {
+                        0 => expected.push_front(axis, &el),
+                        _ => expected.push_back(axis, &el),
+                    }
+                    assert!(m.iter().eq(expected.iter()));
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn push_owned_len() {
+        let mut m = CircularArrayVec::from_iter([3, 3], 0..9);
+        m.push_front_owned(0, vec![0, 1]);
+    }
+}
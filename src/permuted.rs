@@ -0,0 +1,253 @@
+use std::array;
+use std::ops::{Index, Range};
+
+use crate::array_index::CircularIndex;
+use crate::array_iter::iter_indices;
+use crate::{CircularArray, CircularArrayView};
+
+/// A view of a [`CircularArray`] with reordered axes.
+///
+/// Axis `i` of the view is axis `axes[i]` of the parent array. Indices are
+/// translated on access, and no elements are copied. See [`CircularArray::permute_axes`]
+/// for an owned equivalent.
+///
+/// See [`CircularArray::permuted_view`].
+pub struct PermutedView<'a, const N: usize, T> {
+    /// The parent array.
+    array: CircularArrayView<'a, N, T>,
+    /// The parent axis of each axis of the view.
+    axes: [usize; N],
+    /// The length of elements for each axis of the view.
+    shape: [usize; N],
+}
+
+impl<'a, const N: usize, T> PermutedView<'a, N, T> {
+    /// Get the view shape.
+    pub fn shape(&self) -> &[usize; N] {
+        &self.shape
+    }
+
+    /// Get the parent axis of each axis of the view.
+    pub fn axes(&self) -> &[usize; N] {
+        &self.axes
+    }
+
+    /// Get the number of elements in the view.
+    pub fn len(&self) -> usize {
+        self.shape.iter().product()
+    }
+
+    /// Returns `true` if the view contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Map a view `index` to an index of the parent array.
+    fn parent_index(&self, index: [usize; N]) -> [usize; N] {
+        let mut parent = [0; N];
+        index.iter().enumerate().for_each(|(i, idx)| {
+            assert_slice_index!(self, i, *idx);
+            parent[self.axes[i]] = *idx;
+        });
+
+        parent
+    }
+
+    /// Get the slice of the view, replacing the range of `axis` with the given
+    /// `range`.
+    fn slice_axis(&self, axis: usize, range: Range<usize>) -> [Range<usize>; N] {
+        assert_shape_index!(axis, N);
+
+        array::from_fn(|i| match i == axis {
+            true => range.clone(),
+            false => 0..self.shape[i],
+        })
+    }
+
+    /// Iterate over the indices of the given view `slice`.
+    fn iter_slice_indices(
+        &self,
+        slice: [Range<usize>; N],
+    ) -> impl ExactSizeIterator<Item = [usize; N]> {
+        slice.iter().enumerate().for_each(|(i, range)| {
+            assert!(
+                range.start <= range.end && range.end <= self.shape[i],
+                "range {:?} is out of bounds for axis {} of length {}",
+                range,
+                i,
+                self.shape[i]
+            );
+        });
+
+        let start = slice.each_ref().map(|range| range.start);
+        iter_indices(slice.map(|range| range.len())).map(move |mut index| {
+            index
+                .iter_mut()
+                .zip(start)
+                .for_each(|(idx, start)| *idx += start);
+            index
+        })
+    }
+}
+
+impl<'a, 'b: 'a, const N: usize, T: 'a> CircularIndex<'a, N, T> for PermutedView<'b, N, T> {
+    fn get(&'a self, index: [usize; N]) -> &'a T {
+        self.array.get(self.parent_index(index))
+    }
+
+    fn get_raw(&'a self, index: [usize; N]) -> &'a T {
+        self.array.get_raw(self.parent_index(index))
+    }
+
+    fn iter(&'a self) -> impl ExactSizeIterator<Item = &'a T> {
+        self.iter_slice(self.shape.map(|len| 0..len))
+    }
+
+    fn iter_raw(&'a self) -> impl ExactSizeIterator<Item = &'a T> {
+        self.iter_slice_raw(self.shape.map(|len| 0..len))
+    }
+
+    fn iter_index(&'a self, axis: usize, index: usize) -> impl ExactSizeIterator<Item = &'a T> {
+        self.iter_slice(self.slice_axis(axis, index..index + 1))
+    }
+
+    fn iter_index_contiguous(
+        &'a self,
+        axis: usize,
+        index: usize,
+    ) -> impl ExactSizeIterator<Item = &'a T> {
+        self.iter_index(axis, index)
+    }
+
+    fn iter_index_raw(&'a self, axis: usize, index: usize) -> impl ExactSizeIterator<Item = &'a T> {
+        self.iter_slice_raw(self.slice_axis(axis, index..index + 1))
+    }
+
+    fn iter_range(
+        &'a self,
+        axis: usize,
+        range: Range<usize>,
+    ) -> impl ExactSizeIterator<Item = &'a T> {
+        self.iter_slice(self.slice_axis(axis, range))
+    }
+
+    fn iter_range_contiguous(
+        &'a self,
+        axis: usize,
+        range: Range<usize>,
+    ) -> impl ExactSizeIterator<Item = &'a T> {
+        self.iter_range(axis, range)
+    }
+
+    fn iter_range_raw(
+        &'a self,
+        axis: usize,
+        range: Range<usize>,
+    ) -> impl ExactSizeIterator<Item = &'a T> {
+        self.iter_slice_raw(self.slice_axis(axis, range))
+    }
+
+    fn iter_slice(&'a self, slice: [Range<usize>; N]) -> impl ExactSizeIterator<Item = &'a T> {
+        self.iter_slice_indices(slice).map(|index| self.get(index))
+    }
+
+    fn iter_slice_contiguous(
+        &'a self,
+        slice: [Range<usize>; N],
+    ) -> impl ExactSizeIterator<Item = &'a T> {
+        self.iter_slice(slice)
+    }
+
+    fn iter_slice_raw(&'a self, slice: [Range<usize>; N]) -> impl ExactSizeIterator<Item = &'a T> {
+        self.iter_slice_indices(slice)
+            .map(|index| self.get_raw(index))
+    }
+}
+
+impl<const N: usize, T> Index<[usize; N]> for PermutedView<'_, N, T> {
+    type Output = T;
+
+    fn index(&self, index: [usize; N]) -> &Self::Output {
+        self.get(index)
+    }
+}
+
+impl<const N: usize, A: AsRef<[T]>, T> CircularArray<N, A, T> {
+    /// Get a view of the array with the axes reordered by `axes`, where axis `i`
+    /// of the view is axis `axes[i]` of this array. Elements are not copied. See
+    /// [`CircularArray::permute_axes`] for an owned equivalent.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let array = CircularArray::new_offset([3, 2], [1, 0], vec![
+    ///     2, 0, 1,
+    ///     5, 3, 4,
+    /// ]);
+    /// let view = array.permuted_view([1, 0]);
+    ///
+    /// assert_eq!(view.shape(), &[2, 3]);
+    /// assert_eq!(view.get([1, 0]), &3);
+    /// assert_eq!(view.iter().cloned().collect::<Vec<_>>(), &[
+    ///     0, 3,
+    ///     1, 4,
+    ///     2, 5,
+    /// ]);
+    /// ```
+    pub fn permuted_view(&self, axes: [usize; N]) -> PermutedView<'_, N, T> {
+        assert_permutation!(axes, N);
+
+        PermutedView {
+            array: self.view(),
+            axes,
+            shape: axes.map(|axis| self.shape[axis]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CircularArrayVec, CircularIndex};
+
+    #[test]
+    fn permuted_view() {
+        let shape = [4, 3, 2];
+        let m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..shape.iter().product());
+        let owned = m.permute_axes([2, 0, 1]);
+        let view = m.permuted_view([2, 0, 1]);
+
+        assert_eq!(view.shape(), owned.shape());
+        assert_eq!(view.len(), 24);
+        assert_eq!(view.iter().len(), 24);
+        assert_eq!(
+            view.iter().collect::<Vec<_>>(),
+            owned.iter().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            view.iter_raw().collect::<Vec<_>>(),
+            owned.iter_raw().collect::<Vec<_>>()
+        );
+        for axis in 0..3 {
+            assert_eq!(
+                view.iter_index(axis, 1).collect::<Vec<_>>(),
+                owned.iter_index(axis, 1).collect::<Vec<_>>()
+            );
+            assert_eq!(
+                view.iter_range(axis, 0..2).collect::<Vec<_>>(),
+                owned.iter_range(axis, 0..2).collect::<Vec<_>>()
+            );
+        }
+        assert_eq!(
+            view.iter_slice([1..2, 1..3, 0..2]).collect::<Vec<_>>(),
+            owned.iter_slice([1..2, 1..3, 0..2]).collect::<Vec<_>>()
+        );
+        assert_eq!(view[[1, 3, 2]], owned[[1, 3, 2]]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn permuted_view_repeated() {
+        let m = CircularArrayVec::from_iter([3, 3], 0..9);
+        m.permuted_view([1, 1]);
+    }
+}
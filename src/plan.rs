@@ -0,0 +1,188 @@
+use std::ops::Range;
+
+use crate::CircularArray;
+
+/// A prepared push of `n` slices onto an axis of a [`CircularArray`].
+///
+/// Buffer ranges written by a push depend upon the array offset. A `PushPlan`
+/// caches the ranges of each offset of the pushed axis as they are encountered,
+/// such that repeated pushes of the same axis and length do not recompute spans
+/// or allocate. The cache is invalidated where the shape, or the offset of any other
+/// axis, differs from that of the previous execution.
+///
+/// See [`CircularArray::push_planned`].
+#[derive(Debug, Clone)]
+pub struct PushPlan<const N: usize> {
+    /// The axis of the push.
+    axis: usize,
+    /// The number of slices pushed.
+    n: usize,
+    /// Whether slices are pushed to the back of the axis.
+    back: bool,
+    /// The shape and offset (excluding `axis`) for which the cache is valid.
+    key: Option<([usize; N], [usize; N])>,
+    /// The cached ranges for each offset of `axis`.
+    cache: Vec<Option<Vec<Range<usize>>>>,
+}
+
+impl<const N: usize> PushPlan<N> {
+    /// Create a `PushPlan` pushing `n` slices to the front of the given `axis`.
+    /// See [`CircularMut::push_front`](crate::CircularMut::push_front).
+    pub fn front(axis: usize, n: usize) -> Self {
+        Self::new(axis, n, false)
+    }
+
+    /// Create a `PushPlan` pushing `n` slices to the back of the given `axis`.
+    /// See [`CircularMut::push_back`](crate::CircularMut::push_back).
+    pub fn back(axis: usize, n: usize) -> Self {
+        Self::new(axis, n, true)
+    }
+
+    fn new(axis: usize, n: usize, back: bool) -> Self {
+        assert_shape_index!(axis, N);
+
+        PushPlan {
+            axis,
+            n,
+            back,
+            key: None,
+            cache: vec![],
+        }
+    }
+
+    /// Get the axis of the push.
+    pub fn axis(&self) -> usize {
+        self.axis
+    }
+
+    /// Get the number of slices pushed.
+    pub fn n(&self) -> usize {
+        self.n
+    }
+
+    /// Get the ranges for the current state of `array`, computing and caching
+    /// the ranges if necessary.
+    fn ranges<A: AsRef<[T]>, T>(&mut self, array: &CircularArray<N, A, T>) -> &[Range<usize>] {
+        let axis = self.axis;
+        let mut offset = array.offset;
+        offset[axis] = 0;
+
+        if self.key != Some((array.shape, offset)) {
+            self.key = Some((array.shape, offset));
+            self.cache.clear();
+            self.cache.resize(array.shape[axis], None);
+        }
+
+        let len = array.shape[axis];
+        let evicted = match self.back {
+            false => 0..self.n,
+            true => len - self.n..len,
+        };
+
+        self.cache[array.offset[axis]].get_or_insert_with(|| {
+            let mut ranges = vec![];
+            array.extend_push_ranges(axis, evicted, &mut ranges);
+
+            ranges
+        })
+    }
+}
+
+impl<const N: usize, A: AsRef<[T]> + AsMut<[T]>, T: Clone> CircularArray<N, A, T> {
+    /// Push elements onto the array as prepared by the given [`PushPlan`].
+    /// Elements must be exactly `n` slices of the planned axis. This is equivalent
+    /// to [`CircularMut::push_front`](crate::CircularMut::push_front) or
+    /// [`CircularMut::push_back`](crate::CircularMut::push_back).
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex, PushPlan};
+    /// let mut array = CircularArray::new([3, 3], vec![
+    ///     0, 1, 2,
+    ///     3, 4, 5,
+    ///     6, 7, 8,
+    /// ]);
+    /// let mut plan = PushPlan::front(1, 1);
+    ///
+    /// array.push_planned(&mut plan, &[9, 10, 11]);
+    /// array.push_planned(&mut plan, &[12, 13, 14]);
+    ///
+    /// assert_eq!(array.iter().cloned().collect::<Vec<_>>(), &[
+    ///      6,  7,  8,
+    ///      9, 10, 11,
+    ///     12, 13, 14,
+    /// ]);
+    /// ```
+    pub fn push_planned(&mut self, plan: &mut PushPlan<N>, mut el: &[T]) {
+        let axis = plan.axis;
+        let n = plan.n;
+        let el_len = el.len();
+        let slice_len = self.slice_len(axis);
+
+        assert_slice_len!(self, axis, n);
+        assert!(
+            el_len == n * slice_len,
+            "operation on axis {} expected {} elements (recieved {})",
+            axis,
+            n * slice_len,
+            el_len
+        );
+
+        if n != 0 {
+            for range in plan.ranges(self) {
+                let len = range.len();
+                self.array.as_mut()[range.clone()].clone_from_slice(&el[..len]);
+                (_, el) = el.split_at(len);
+            }
+
+            if n == self.shape[axis] {
+                self.offset = [0; N];
+            } else if plan.back {
+                self.decr_offset(axis, n);
+            } else {
+                self.incr_offset(axis, n);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CircularArrayVec, CircularMut, PushPlan};
+
+    #[test]
+    fn push_planned() {
+        let shape = [4, 3, 2];
+        let len = shape.iter().product::<usize>();
+
+        for axis in 0..3 {
+            for n in 0..=shape[axis] {
+                let mut m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..len);
+                let mut expected = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..len);
+                let mut front = PushPlan::front(axis, n);
+                let mut back = PushPlan::back(axis, n);
+
+                for i in 0..6 {
+                    let el = vec![i; n * m.slice_len(axis)];
+                    if i % 3 == 2 {
+                        m.push_planned(&mut back, &el);
+                        expected.push_back(axis, &el);
+                    } else {
+                        m.push_planned(&mut front, &el);
+                        expected.push_front(axis, &el);
+                    }
+
+                    assert_eq!(m.array, expected.array);
+                    assert_eq!(m.offset, expected.offset);
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn push_planned_len() {
+        let mut m = CircularArrayVec::from_iter([3, 3], 0..9);
+        m.push_planned(&mut PushPlan::front(0, 1), &[0, 1]);
+    }
+}
@@ -0,0 +1,201 @@
+use std::array;
+use std::ops::Range;
+
+use crate::index::RawIndexAdaptor;
+use crate::index_iter::IndexIterator;
+use crate::span::BoundSpan;
+use crate::CircularArray;
+
+/// The effect of pushing slices onto an axis of a [`CircularArray`], derived
+/// without mutating the array.
+///
+/// See [`CircularArray::dry_run_push_front`] and [`CircularArray::dry_run_push_back`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PushPreview<const N: usize> {
+    /// The axis of the push.
+    pub(crate) axis: usize,
+    /// The shape of the array.
+    pub(crate) shape: [usize; N],
+    /// The logical slices of the axis that will be evicted.
+    pub(crate) evicted: Range<usize>,
+    /// The buffer ranges that will be written, in order of insertion.
+    pub(crate) ranges: Vec<Range<usize>>,
+}
+
+impl<const N: usize> PushPreview<N> {
+    /// Get the axis of the push.
+    pub fn axis(&self) -> usize {
+        self.axis
+    }
+
+    /// Get the number of slices pushed.
+    pub fn n(&self) -> usize {
+        self.evicted.len()
+    }
+
+    /// Get the range of logical slice indices of the axis that will be evicted,
+    /// aligned to the current offset.
+    pub fn evicted(&self) -> Range<usize> {
+        self.evicted.clone()
+    }
+
+    /// Get the `N` dimensional logical slice of the evicted elements, aligned
+    /// to the current offset. This can be passed to [`CircularIndex::iter_slice`](crate::CircularIndex::iter_slice)
+    /// to retrieve evicted elements prior to pushing.
+    pub fn evicted_slice(&self) -> [Range<usize>; N] {
+        array::from_fn(|i| match i == self.axis {
+            true => self.evicted.clone(),
+            false => 0..self.shape[i],
+        })
+    }
+
+    /// Get the contiguous ranges of the inner buffer that will be written, in
+    /// the order pushed elements are inserted.
+    pub fn ranges(&self) -> &[Range<usize>] {
+        &self.ranges
+    }
+}
+
+impl<const N: usize, A: AsRef<[T]>, T> CircularArray<N, A, T> {
+    /// Get a [`PushPreview`] of pushing `n` slices to the front of the given
+    /// `axis`, without mutating the array. See [`CircularMut::push_front`](crate::CircularMut::push_front).
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let array = CircularArray::new_offset([3, 3], [0, 1], vec![
+    ///     6, 7, 8,
+    ///     0, 1, 2,
+    ///     3, 4, 5,
+    /// ]);
+    /// let preview = array.dry_run_push_front(1, 1);
+    ///
+    /// assert_eq!(preview.evicted(), 0..1);
+    /// assert_eq!(preview.ranges(), &[3..6]);
+    /// assert_eq!(array.iter_slice(preview.evicted_slice()).cloned().collect::<Vec<_>>(), &[
+    ///     0, 1, 2
+    /// ]);
+    /// ```
+    pub fn dry_run_push_front(&self, axis: usize, n: usize) -> PushPreview<N> {
+        assert_shape_index!(axis, N);
+        assert_slice_len!(self, axis, n);
+
+        let mut ranges = vec![];
+        self.extend_push_ranges(axis, 0..n, &mut ranges);
+
+        PushPreview {
+            axis,
+            shape: self.shape,
+            evicted: 0..n,
+            ranges,
+        }
+    }
+
+    /// Get a [`PushPreview`] of pushing `n` slices to the back of the given
+    /// `axis`, without mutating the array. See [`CircularMut::push_back`](crate::CircularMut::push_back).
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let array = CircularArray::new_offset([3, 3], [0, 1], vec![
+    ///     6, 7, 8,
+    ///     0, 1, 2,
+    ///     3, 4, 5,
+    /// ]);
+    /// let preview = array.dry_run_push_back(1, 1);
+    ///
+    /// assert_eq!(preview.evicted(), 2..3);
+    /// assert_eq!(preview.ranges(), &[0..3]);
+    /// assert_eq!(array.iter_slice(preview.evicted_slice()).cloned().collect::<Vec<_>>(), &[
+    ///     6, 7, 8
+    /// ]);
+    /// ```
+    pub fn dry_run_push_back(&self, axis: usize, n: usize) -> PushPreview<N> {
+        assert_shape_index!(axis, N);
+        assert_slice_len!(self, axis, n);
+
+        let len = self.shape[axis];
+        let mut ranges = vec![];
+        self.extend_push_ranges(axis, len - n..len, &mut ranges);
+
+        PushPreview {
+            axis,
+            shape: self.shape,
+            evicted: len - n..len,
+            ranges,
+        }
+    }
+
+    /// Extend `ranges` with the buffer ranges written when pushing onto the given
+    /// `axis`, replacing the `evicted` slices. Ranges are in order of insertion.
+    pub(crate) fn extend_push_ranges(
+        &self,
+        axis: usize,
+        evicted: Range<usize>,
+        ranges: &mut Vec<Range<usize>>,
+    ) {
+        let n = evicted.len();
+
+        if n == self.shape[axis] {
+            ranges.push(0..self.len());
+        } else if n != 0 {
+            let spans =
+                self.spans_axis_bound(axis, BoundSpan::new(evicted.start, n, self.shape[axis]));
+            ranges
+                .extend(IndexIterator::new_bound_contiguous(spans).into_flat_ranges(&self.strides));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CircularArrayVec, CircularIndex, CircularMut};
+
+    #[test]
+    fn dry_run_push() {
+        let shape = [4, 3, 2];
+        let len = shape.iter().product::<usize>();
+
+        for axis in 0..3 {
+            for n in 0..=shape[axis] {
+                let el = vec![usize::MAX; n * (len / shape[axis])];
+
+                let mut m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..len);
+                let preview = m.dry_run_push_front(axis, n);
+                let evicted = match n {
+                    0 => vec![],
+                    _ => m.iter_slice(preview.evicted_slice()).cloned().collect(),
+                };
+                m.push_front(axis, &el);
+                assert_eq!(
+                    preview.ranges().iter().map(|r| r.len()).sum::<usize>(),
+                    el.len()
+                );
+                assert!(preview
+                    .ranges()
+                    .iter()
+                    .flat_map(|r| r.clone())
+                    .all(|i| m.array[i] == usize::MAX));
+                assert!(evicted.iter().all(|i| !m.array.contains(i)));
+
+                let mut m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..len);
+                let preview = m.dry_run_push_back(axis, n);
+                let evicted = match n {
+                    0 => vec![],
+                    _ => m.iter_slice(preview.evicted_slice()).cloned().collect(),
+                };
+                m.push_back(axis, &el);
+                assert_eq!(
+                    preview.ranges().iter().map(|r| r.len()).sum::<usize>(),
+                    el.len()
+                );
+                assert!(preview
+                    .ranges()
+                    .iter()
+                    .flat_map(|r| r.clone())
+                    .all(|i| m.array[i] == usize::MAX));
+                assert!(evicted.iter().all(|i| !m.array.contains(i)));
+            }
+        }
+    }
+}
@@ -0,0 +1,226 @@
+use crate::array_iter::iter_indices;
+use crate::index::RawIndexAdaptor;
+use crate::index_iter::IndexIterator;
+use crate::span::BoundSpan;
+use crate::CircularArray;
+
+impl<const N: usize, A: AsRef<[T]> + AsMut<[T]>, T> CircularArray<N, A, T> {
+    /// Push `n` slices to the front of the given `axis`, generating each element
+    /// by `f`. The index passed to `f` is that of the element within the pushed
+    /// slices, of length `n` on `axis`, such that elements are equivalent to those
+    /// passed to [`CircularMut::push_front`](crate::CircularMut::push_front).
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let mut array = CircularArray::new([3, 2], vec![
+    ///     0, 1, 2,
+    ///     3, 4, 5,
+    /// ]);
+    /// array.push_front_fn(0, 2, |[x, y]| 10 * y + x);
+    ///
+    /// assert_eq!(array.iter().cloned().collect::<Vec<_>>(), &[
+    ///     2,  0,  1,
+    ///     5, 10, 11,
+    /// ]);
+    /// ```
+    pub fn push_front_fn<F: FnMut([usize; N]) -> T>(&mut self, axis: usize, n: usize, f: F) {
+        assert_shape_index!(axis, N);
+        assert_slice_len!(self, axis, n);
+
+        if n == self.shape[axis] {
+            self.offset = [0; N];
+            self.push_fn(IndexIterator::new_unbound(self.spans_raw()), self.shape, f);
+        } else if n != 0 {
+            let spans = self.spans_axis_bound(axis, BoundSpan::new(0, n, self.shape[axis]));
+            let mut shape = self.shape;
+            shape[axis] = n;
+
+            self.push_fn(IndexIterator::new_bound_contiguous(spans), shape, f);
+            self.incr_offset(axis, n);
+        }
+    }
+
+    /// Push `n` slices to the back of the given `axis`, generating each element
+    /// by `f`. See [`CircularArray::push_front_fn`].
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let mut array = CircularArray::new([3, 2], vec![
+    ///     0, 1, 2,
+    ///     3, 4, 5,
+    /// ]);
+    /// array.push_back_fn(1, 1, |[x, _]| 10 + x);
+    ///
+    /// assert_eq!(array.iter().cloned().collect::<Vec<_>>(), &[
+    ///     10, 11, 12,
+    ///      0,  1,  2,
+    /// ]);
+    /// ```
+    pub fn push_back_fn<F: FnMut([usize; N]) -> T>(&mut self, axis: usize, n: usize, f: F) {
+        assert_shape_index!(axis, N);
+        assert_slice_len!(self, axis, n);
+
+        if n == self.shape[axis] {
+            self.offset = [0; N];
+            self.push_fn(IndexIterator::new_unbound(self.spans_raw()), self.shape, f);
+        } else if n != 0 {
+            let span = BoundSpan::new(self.shape[axis] - n, n, self.shape[axis]);
+            let spans = self.spans_axis_bound(axis, span);
+            let mut shape = self.shape;
+            shape[axis] = n;
+
+            self.push_fn(IndexIterator::new_bound_contiguous(spans), shape, f);
+            self.decr_offset(axis, n);
+        }
+    }
+
+    /// Advance the array by `n` slices to the front of the given `axis`, filling
+    /// the new slices with `T::default()`.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let mut array = CircularArray::new([3, 2], vec![
+    ///     1, 2, 3,
+    ///     4, 5, 6,
+    /// ]);
+    /// array.advance_front(0, 1);
+    ///
+    /// assert_eq!(array.iter().cloned().collect::<Vec<_>>(), &[
+    ///     2, 3, 0,
+    ///     5, 6, 0,
+    /// ]);
+    /// ```
+    pub fn advance_front(&mut self, axis: usize, n: usize)
+    where
+        T: Default,
+    {
+        self.push_front_fn(axis, n, |_| T::default());
+    }
+
+    /// Advance the array by `n` slices to the back of the given `axis`, filling
+    /// the new slices with `T::default()`.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let mut array = CircularArray::new([3, 2], vec![
+    ///     1, 2, 3,
+    ///     4, 5, 6,
+    /// ]);
+    /// array.advance_back(1, 1);
+    ///
+    /// assert_eq!(array.iter().cloned().collect::<Vec<_>>(), &[
+    ///     0, 0, 0,
+    ///     1, 2, 3,
+    /// ]);
+    /// ```
+    pub fn advance_back(&mut self, axis: usize, n: usize)
+    where
+        T: Default,
+    {
+        self.push_back_fn(axis, n, |_| T::default());
+    }
+
+    /// Advance the array by `n` slices to the front of the given `axis`, filling
+    /// the new slices with clones of `value`. See [`CircularArray::advance_front`].
+    pub fn advance_front_fill(&mut self, axis: usize, n: usize, value: T)
+    where
+        T: Clone,
+    {
+        self.push_front_fn(axis, n, |_| value.clone());
+    }
+
+    /// Advance the array by `n` slices to the back of the given `axis`, filling
+    /// the new slices with clones of `value`. See [`CircularArray::advance_back`].
+    pub fn advance_back_fill(&mut self, axis: usize, n: usize, value: T)
+    where
+        T: Clone,
+    {
+        self.push_back_fn(axis, n, |_| value.clone());
+    }
+
+    /// Assign the elements of the given `spans` by `f`, for each index of `shape`.
+    fn push_fn<'a, F: FnMut([usize; N]) -> T>(
+        &'a mut self,
+        spans: impl RawIndexAdaptor<'a, N>,
+        shape: [usize; N],
+        mut f: F,
+    ) {
+        let CircularArray { array, strides, .. } = self;
+        let mut indices = iter_indices(shape);
+
+        for range in spans.into_flat_ranges(strides) {
+            for (el, index) in array.as_mut()[range].iter_mut().zip(&mut indices) {
+                *el = f(index);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array_iter::iter_indices;
+    use crate::{CircularArrayVec, CircularIndex, CircularMut};
+
+    #[test]
+    fn push_fn() {
+        let shape = [4, 3, 2];
+        let len = shape.iter().product::<usize>();
+        let f = |i: usize| move |[x, y, z]: [usize; 3]| 1000 * i + 100 * z + 10 * y + x;
+
+        for axis in 0..3 {
+            for n in 0..=shape[axis] {
+                let mut m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..len);
+                let mut expected = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..len);
+                let mut el_shape = shape;
+                el_shape[axis] = n;
+
+                for i in 0..4 {
+                    let el = iter_indices(el_shape).map(f(i)).collect::<Vec<_>>();
+                    match i % 2 {
+                        0 => {
+                            m.push_front_fn(axis, n, f(i));
+                            expected.push_front(axis, &el);
+                        }
+                        _ => {
+                            m.push_back_fn(axis, n, f(i));
+                            expected.push_back(axis, &el);
+                        }
+                    }
+
+                    assert_eq!(m.offset(), expected.offset());
+                    assert!(m.iter().eq(expected.iter()));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn advance() {
+        let mut m = CircularArrayVec::from_iter_offset([4, 3, 2], [1, 2, 1], 1..25);
+        let mut expected = m.clone();
+
+        m.advance_front(1, 2);
+        expected.push_front(1, &[0; 16]);
+        assert!(m.iter().eq(expected.iter()));
+
+        m.advance_back_fill(0, 1, -1);
+        expected.push_back(0, &[-1; 6]);
+        assert!(m.iter().eq(expected.iter()));
+
+        m.advance_front_fill(2, 2, 7);
+        m.advance_back(0, 0);
+        assert!(m.iter().all(|el| *el == 7));
+        assert_eq!(m.offset(), &[0; 3]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn push_fn_len() {
+        let mut m = CircularArrayVec::from_iter([3, 3], 0..9);
+        m.push_front_fn(0, 4, |_| 0);
+    }
+}
@@ -0,0 +1,100 @@
+use std::array;
+
+use crate::CircularArray;
+
+impl<const N: usize, A: AsRef<[T]>, T> CircularArray<N, A, T> {
+    /// Fold the elements of the given `axis` into `other`, an array of `M = N - 1`
+    /// dimensions of the shape of this array without `axis`. For each element of
+    /// this array, `f` is passed the element of `other` at the same index (less
+    /// `axis`), aligned to the offset of each array. Elements of `other` are not
+    /// reset prior to folding.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let array = CircularArray::new_offset([3, 2], [1, 0], vec![
+    ///     2, 0, 1,
+    ///     5, 3, 4,
+    /// ]);
+    /// let mut sums = CircularArray::new_offset([2], [1], vec![0, 0]);
+    /// let mut maxima = CircularArray::new([3], vec![0, 0, 0]);
+    ///
+    /// array.reduce_axis_into(0, &mut sums, |sum, el| *sum += el);
+    /// array.reduce_axis_into(1, &mut maxima, |max: &mut i32, el| *max = (*max).max(*el));
+    ///
+    /// assert_eq!(sums.iter().cloned().collect::<Vec<_>>(), &[3, 12]);
+    /// assert_eq!(maxima.iter().cloned().collect::<Vec<_>>(), &[3, 4, 5]);
+    /// ```
+    pub fn reduce_axis_into<const M: usize, B, U, F>(
+        &self,
+        axis: usize,
+        other: &mut CircularArray<M, B, U>,
+        mut f: F,
+    ) where
+        B: AsRef<[U]> + AsMut<[U]>,
+        F: FnMut(&mut U, &T),
+    {
+        const { assert!(M + 1 == N, "reduced dimensionality must be N - 1") };
+        assert_shape_index!(axis, N);
+
+        let shape: [usize; M] = array::from_fn(|i| self.shape[i + (i >= axis) as usize]);
+        assert!(
+            other.shape == shape,
+            "shape {:?} does not match reduced shape {:?}",
+            other.shape,
+            shape
+        );
+
+        let CircularArray {
+            array,
+            strides,
+            offset,
+            ..
+        } = other;
+        let array = array.as_mut();
+
+        for (index, el) in self.indexed_iter() {
+            let raw: [usize; M] =
+                array::from_fn(|i| (index[i + (i >= axis) as usize] + offset[i]) % shape[i]);
+
+            f(&mut array[strides.offset_index(raw)], el);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CircularArrayVec, CircularIndex};
+
+    #[test]
+    fn reduce_axis_into() {
+        let shape = [4, 3, 2];
+        let m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..shape.iter().product());
+
+        let mut sums = CircularArrayVec::new_offset([3, 2], [1, 1], vec![0; 6]);
+        m.reduce_axis_into(0, &mut sums, |sum, el| *sum += el);
+        for ([y, z], sum) in sums.indexed_iter() {
+            assert_eq!(
+                *sum,
+                m.iter_slice([0..4, y..y + 1, z..z + 1]).sum::<usize>()
+            );
+        }
+
+        let mut sums = CircularArrayVec::new_offset([4, 3], [2, 0], vec![0; 12]);
+        m.reduce_axis_into(2, &mut sums, |sum, el| *sum += el);
+        for ([x, y], sum) in sums.indexed_iter() {
+            assert_eq!(
+                *sum,
+                m.iter_slice([x..x + 1, y..y + 1, 0..2]).sum::<usize>()
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn reduce_axis_into_shape() {
+        let m = CircularArrayVec::from_iter([2, 3], 0..6);
+        let mut sums = CircularArrayVec::new([2], vec![0; 2]);
+        m.reduce_axis_into(0, &mut sums, |sum, el| *sum += el);
+    }
+}
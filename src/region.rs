@@ -0,0 +1,347 @@
+use std::array;
+use std::ops::{Index, Range};
+
+use crate::array_index::CircularIndex;
+use crate::axis_slice::IntoSlice;
+use crate::{CircularArray, CircularArrayView};
+
+/// A rectangular region of a [`CircularArray`], with logical coordinates local
+/// to the region.
+///
+/// Regions borrow the elements of the parent array. Index `[0; N]` of a region
+/// corresponds to the start of the region within the parent array (aligned to the
+/// parent offset). **Raw** operations ignore the parent offset, equivalent to
+/// [`CircularIndex::iter_slice_raw`].
+///
+/// See [`CircularArray::view_region`].
+pub struct CircularArrayRegion<'a, const N: usize, T> {
+    /// The parent array.
+    array: CircularArrayView<'a, N, T>,
+    /// The start of the region within the parent array.
+    origin: [usize; N],
+    /// The length of elements for each axis of the region.
+    shape: [usize; N],
+}
+
+impl<'a, const N: usize, T> CircularArrayRegion<'a, N, T> {
+    /// Get the region shape.
+    pub fn shape(&self) -> &[usize; N] {
+        &self.shape
+    }
+
+    /// Get the start of the region within the parent array.
+    pub fn origin(&self) -> &[usize; N] {
+        &self.origin
+    }
+
+    /// Get the number of elements in the region.
+    pub fn len(&self) -> usize {
+        self.shape.iter().product()
+    }
+
+    /// Returns `true` if the region contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the number of elements for a single slice of the region, for the given
+    /// `axis`.
+    pub fn slice_len(&self, axis: usize) -> usize {
+        self.shape
+            .iter()
+            .enumerate()
+            .fold(1, |acc, (i, sh)| if i == axis { acc } else { acc * sh })
+    }
+
+    /// Get a view of the rectangular region of this region given by `slice`,
+    /// with coordinates local to this region. See [`CircularArray::slice`].
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArrayVec, CircularIndex};
+    /// let array = CircularArrayVec::from_iter([4, 3], 0..12);
+    /// let region = array.slice([1..4, 1..3]).slice([1..3, 1..2]);
+    ///
+    /// assert_eq!(region.origin(), &[2, 2]);
+    /// assert_eq!(region.iter().cloned().collect::<Vec<_>>(), &[10, 11]);
+    /// ```
+    pub fn slice(&self, slice: impl IntoSlice<N>) -> CircularArrayRegion<'a, N, T> {
+        let slice = slice.into_slice(&self.shape);
+        slice.iter().enumerate().for_each(|(i, range)| {
+            assert!(
+                range.start <= range.end,
+                "range {:?} is out of bounds for axis {} of length {}",
+                range,
+                i,
+                self.shape[i]
+            );
+        });
+
+        let slice = self.parent_slice(slice);
+        CircularArrayRegion {
+            array: self.array.clone(),
+            origin: slice.each_ref().map(|range| range.start),
+            shape: slice.each_ref().map(|range| range.len()),
+        }
+    }
+
+    /// Map a region `index` to an index of the parent array.
+    fn parent_index(&self, mut index: [usize; N]) -> [usize; N] {
+        index.iter_mut().enumerate().for_each(|(i, idx)| {
+            assert_slice_index!(self, i, *idx);
+            *idx += self.origin[i];
+        });
+
+        index
+    }
+
+    /// Map a region `slice` to a slice of the parent array.
+    fn parent_slice(&self, slice: [Range<usize>; N]) -> [Range<usize>; N] {
+        array::from_fn(|i| {
+            let range = &slice[i];
+            assert!(
+                range.end <= self.shape[i],
+                "range {:?} is out of bounds for axis {} of length {}",
+                range,
+                i,
+                self.shape[i]
+            );
+
+            range.start + self.origin[i]..range.end + self.origin[i]
+        })
+    }
+
+    /// Get the slice of the parent array covered by the region, replacing the
+    /// range of `axis` with the given `range`.
+    fn parent_slice_axis(&self, axis: usize, range: Range<usize>) -> [Range<usize>; N] {
+        assert_shape_index!(axis, N);
+
+        self.parent_slice(array::from_fn(|i| match i == axis {
+            true => range.clone(),
+            false => 0..self.shape[i],
+        }))
+    }
+}
+
+impl<'a, 'b: 'a, const N: usize, T: 'a> CircularIndex<'a, N, T> for CircularArrayRegion<'b, N, T> {
+    fn get(&'a self, index: [usize; N]) -> &'a T {
+        self.array.get(self.parent_index(index))
+    }
+
+    fn get_raw(&'a self, index: [usize; N]) -> &'a T {
+        self.array.get_raw(self.parent_index(index))
+    }
+
+    fn iter(&'a self) -> impl ExactSizeIterator<Item = &'a T> {
+        self.array
+            .iter_slice(self.parent_slice(self.shape.map(|len| 0..len)))
+    }
+
+    fn iter_raw(&'a self) -> impl ExactSizeIterator<Item = &'a T> {
+        self.array
+            .iter_slice_raw(self.parent_slice(self.shape.map(|len| 0..len)))
+    }
+
+    fn iter_index(&'a self, axis: usize, index: usize) -> impl ExactSizeIterator<Item = &'a T> {
+        self.array
+            .iter_slice(self.parent_slice_axis(axis, index..index + 1))
+    }
+
+    fn iter_index_contiguous(
+        &'a self,
+        axis: usize,
+        index: usize,
+    ) -> impl ExactSizeIterator<Item = &'a T> {
+        self.array
+            .iter_slice_contiguous(self.parent_slice_axis(axis, index..index + 1))
+    }
+
+    fn iter_index_raw(&'a self, axis: usize, index: usize) -> impl ExactSizeIterator<Item = &'a T> {
+        self.array
+            .iter_slice_raw(self.parent_slice_axis(axis, index..index + 1))
+    }
+
+    fn iter_range(
+        &'a self,
+        axis: usize,
+        range: Range<usize>,
+    ) -> impl ExactSizeIterator<Item = &'a T> {
+        self.array.iter_slice(self.parent_slice_axis(axis, range))
+    }
+
+    fn iter_range_contiguous(
+        &'a self,
+        axis: usize,
+        range: Range<usize>,
+    ) -> impl ExactSizeIterator<Item = &'a T> {
+        self.array
+            .iter_slice_contiguous(self.parent_slice_axis(axis, range))
+    }
+
+    fn iter_range_raw(
+        &'a self,
+        axis: usize,
+        range: Range<usize>,
+    ) -> impl ExactSizeIterator<Item = &'a T> {
+        self.array
+            .iter_slice_raw(self.parent_slice_axis(axis, range))
+    }
+
+    fn iter_slice(&'a self, slice: [Range<usize>; N]) -> impl ExactSizeIterator<Item = &'a T> {
+        self.array.iter_slice(self.parent_slice(slice))
+    }
+
+    fn iter_slice_contiguous(
+        &'a self,
+        slice: [Range<usize>; N],
+    ) -> impl ExactSizeIterator<Item = &'a T> {
+        self.array.iter_slice_contiguous(self.parent_slice(slice))
+    }
+
+    fn iter_slice_raw(&'a self, slice: [Range<usize>; N]) -> impl ExactSizeIterator<Item = &'a T> {
+        self.array.iter_slice_raw(self.parent_slice(slice))
+    }
+}
+
+impl<const N: usize, T> Index<[usize; N]> for CircularArrayRegion<'_, N, T> {
+    type Output = T;
+
+    fn index(&self, index: [usize; N]) -> &Self::Output {
+        self.get(index)
+    }
+}
+
+impl<const N: usize, A: AsRef<[T]>, T> CircularArray<N, A, T> {
+    /// Get a view of the rectangular region of the array given by `slice`. The
+    /// region has logical coordinates local to the region, where index `[0; N]`
+    /// is the start of the `slice` (aligned to the offset).
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let array = CircularArray::new_offset([4, 3], [1, 1], vec![
+    ///     11,  8,  9, 10,
+    ///      3,  0,  1,  2,
+    ///      7,  4,  5,  6,
+    /// ]);
+    /// let region = array.view_region([1..3, 1..3]);
+    ///
+    /// assert_eq!(region.shape(), &[2, 2]);
+    /// assert_eq!(region.get([0, 0]), &5);
+    /// assert_eq!(region.iter().cloned().collect::<Vec<_>>(), &[
+    ///      5,  6,
+    ///      9, 10,
+    /// ]);
+    /// ```
+    pub fn view_region(&self, slice: [Range<usize>; N]) -> CircularArrayRegion<'_, N, T> {
+        slice.iter().enumerate().for_each(|(i, range)| {
+            assert!(
+                range.start <= range.end && range.end <= self.shape[i],
+                "range {:?} is out of bounds for axis {} of length {}",
+                range,
+                i,
+                self.shape[i]
+            );
+        });
+
+        CircularArrayRegion {
+            array: self.view(),
+            origin: slice.each_ref().map(|range| range.start),
+            shape: slice.each_ref().map(|range| range.len()),
+        }
+    }
+
+    /// Get a view of the rectangular region of the array given by `slice`, of any
+    /// [`IntoSlice`], such as `(.., 1..=2)`. Equivalent to
+    /// [`CircularArray::view_region`].
+    ///
+    /// `Index` may not return a borrowing view, such that this method stands in
+    /// for `&array[slice]`.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let array = CircularArray::new_offset([3, 2], [1, 0], vec![
+    ///     2, 0, 1,
+    ///     5, 3, 4,
+    /// ]);
+    ///
+    /// assert_eq!(array.slice((..2, 1..)).iter().cloned().collect::<Vec<_>>(), &[3, 4]);
+    /// ```
+    pub fn slice(&self, slice: impl IntoSlice<N>) -> CircularArrayRegion<'_, N, T> {
+        self.view_region(self.to_slice(slice))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CircularArrayVec, CircularIndex};
+
+    #[test]
+    fn view_region() {
+        let shape = [4, 3, 2];
+        let m = CircularArrayVec::from_iter_offset(shape, [3, 2, 1], 0..shape.iter().product());
+        let region = m.view_region([1..4, 0..2, 1..2]);
+
+        assert_eq!(region.shape(), &[3, 2, 1]);
+        assert_eq!(region.len(), 6);
+        #[rustfmt::skip]
+        assert_eq!(region.iter().cloned().collect::<Vec<_>>(), &[
+            8, 9, 10,
+            0, 1,  2,
+        ]);
+        assert_eq!(region.iter().len(), 6);
+        assert_eq!(
+            region.iter().cloned().collect::<Vec<_>>(),
+            m.iter_slice([1..4, 0..2, 1..2])
+                .cloned()
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(region.get([2, 1, 0]), &2);
+        assert_eq!(region[[0, 0, 0]], 8);
+        assert_eq!(
+            region.iter_index(1, 1).cloned().collect::<Vec<_>>(),
+            &[0, 1, 2]
+        );
+        assert_eq!(
+            region.iter_range(0, 1..3).cloned().collect::<Vec<_>>(),
+            &[9, 10, 1, 2]
+        );
+        assert_eq!(
+            region
+                .iter_slice([2..3, 0..2, 0..1])
+                .cloned()
+                .collect::<Vec<_>>(),
+            &[10, 2]
+        );
+        assert_eq!(
+            region.iter_raw().cloned().collect::<Vec<_>>(),
+            &[13, 14, 15, 17, 18, 19]
+        );
+    }
+
+    #[test]
+    fn slice() {
+        let shape = [4, 3, 2];
+        let m = CircularArrayVec::from_iter_offset(shape, [3, 2, 1], 0..shape.iter().product());
+        let region = m.slice([1..4, 0..3, 0..2]).slice([1..3, 1..3, 1..2]);
+
+        assert_eq!(region.origin(), &[2, 1, 1]);
+        assert_eq!(region.shape(), &[2, 2, 1]);
+        assert!(region.iter().eq(m.iter_slice([2..4, 1..3, 1..2])));
+    }
+
+    #[test]
+    #[should_panic]
+    fn slice_out_of_bounds() {
+        let m = CircularArrayVec::from_iter([3, 3], 0..9);
+        m.slice([1..3, 0..3]).slice([0..3, 0..3]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn view_region_out_of_bounds() {
+        let m = CircularArrayVec::from_iter([3, 3], 0..9);
+        m.view_region([1..4, 0..3]);
+    }
+}
@@ -0,0 +1,345 @@
+use std::array;
+use std::ops::Range;
+
+use crate::array_index::CircularIndex;
+use crate::array_iter::iter_indices;
+use crate::{CircularArray, CircularArrayVec};
+
+/// The slices of an axis retained when resizing. See [`CircularArray::resize_axis`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Retain {
+    /// Retain the newest slices (the last logical indices of the axis). Padding
+    /// is inserted before the retained slices.
+    Newest,
+    /// Retain the oldest slices (the first logical indices of the axis). Padding
+    /// is inserted after the retained slices.
+    Oldest,
+}
+
+impl<const N: usize, A: AsRef<[T]>, T: Clone> CircularArray<N, A, T> {
+    /// Create a new [`CircularArrayVec`] with the given `axis` resized to `len`,
+    /// retaining the slices given by `retain`. Where the axis grows, new slices
+    /// are filled with `fill`. The new array has an offset of `[0; N]`.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex, Retain};
+    /// let array = CircularArray::new_offset([3, 2], [1, 0], vec![
+    ///     2, 0, 1,
+    ///     5, 3, 4,
+    /// ]);
+    ///
+    /// let shrunk = array.resize_axis(0, 2, Retain::Newest, 0);
+    /// assert_eq!(shrunk.iter().cloned().collect::<Vec<_>>(), &[
+    ///     1, 2,
+    ///     4, 5,
+    /// ]);
+    ///
+    /// let grown = array.resize_axis(1, 3, Retain::Oldest, 9);
+    /// assert_eq!(grown.iter().cloned().collect::<Vec<_>>(), &[
+    ///     0, 1, 2,
+    ///     3, 4, 5,
+    ///     9, 9, 9,
+    /// ]);
+    /// ```
+    pub fn resize_axis(
+        &self,
+        axis: usize,
+        len: usize,
+        retain: Retain,
+        fill: T,
+    ) -> CircularArrayVec<N, T> {
+        assert_shape_index!(axis, N);
+
+        let axis_len = self.shape[axis];
+        let n = axis_len.min(len);
+
+        // The retained range of this array, and the range it occupies in the new array.
+        let (src, dst) = match retain {
+            Retain::Newest => (axis_len - n..axis_len, len - n..len),
+            Retain::Oldest => (0..n, 0..n),
+        };
+
+        self.place_axis(axis, src, dst.start, len, fill)
+    }
+
+    /// Create a new [`CircularArrayVec`] of the elements within the given `slice`,
+    /// aligned to the offset. The new array has an offset of `[0; N]`.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let array = CircularArray::new_offset([3, 3], [1, 1], vec![
+    ///     8, 6, 7,
+    ///     2, 0, 1,
+    ///     5, 3, 4,
+    /// ]);
+    /// let cropped = array.crop([1..3, 0..2]);
+    ///
+    /// assert_eq!(cropped.shape(), &[2, 2]);
+    /// assert_eq!(cropped.data(), &[
+    ///     1, 2,
+    ///     4, 5,
+    /// ]);
+    /// ```
+    pub fn crop(&self, slice: [Range<usize>; N]) -> CircularArrayVec<N, T> {
+        let region = self.view_region(slice);
+
+        match region.is_empty() {
+            true => CircularArrayVec::new(*region.shape(), vec![]),
+            false => CircularArrayVec::from_iter(*region.shape(), region.iter().cloned()),
+        }
+    }
+
+    /// Create a new [`CircularArrayVec`] with `before` and `after` slices of
+    /// `fill` inserted on either side of the given `axis`. The new array has an
+    /// offset of `[0; N]`.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let array = CircularArray::new_offset([2, 2], [1, 0], vec![
+    ///     1, 0,
+    ///     3, 2,
+    /// ]);
+    /// let padded = array.pad(0, 1, 2, 9);
+    ///
+    /// assert_eq!(padded.shape(), &[5, 2]);
+    /// assert_eq!(padded.data(), &[
+    ///     9, 0, 1, 9, 9,
+    ///     9, 2, 3, 9, 9,
+    /// ]);
+    /// ```
+    pub fn pad(&self, axis: usize, before: usize, after: usize, fill: T) -> CircularArrayVec<N, T> {
+        assert_shape_index!(axis, N);
+
+        let axis_len = self.shape[axis];
+        self.place_axis(axis, 0..axis_len, before, before + axis_len + after, fill)
+    }
+
+    /// Create a new [`CircularArrayVec`] of the elements of this array followed
+    /// by the elements of `other` along the given `axis`, aligned to the offset of
+    /// each array. The shape of each array **must** match for all other axes. The
+    /// new array has an offset of `[0; N]`.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let a = CircularArray::new_offset([2, 2], [1, 0], vec![
+    ///     1, 0,
+    ///     3, 2,
+    /// ]);
+    /// let b = CircularArray::new([1, 2], vec![
+    ///     4,
+    ///     5,
+    /// ]);
+    ///
+    /// assert_eq!(a.concat(0, &b).data(), &[
+    ///     0, 1, 4,
+    ///     2, 3, 5,
+    /// ]);
+    /// ```
+    pub fn concat<B: AsRef<[T]>>(
+        &self,
+        axis: usize,
+        other: &CircularArray<N, B, T>,
+    ) -> CircularArrayVec<N, T> {
+        assert_shape_index!(axis, N);
+        (0..N).filter(|i| *i != axis).for_each(|i| {
+            assert!(
+                self.shape[i] == other.shape[i],
+                "shape {:?} does not match shape {:?} for axis {}",
+                self.shape,
+                other.shape,
+                i
+            )
+        });
+
+        let len = self.shape[axis];
+        let mut shape = self.shape;
+        shape[axis] += other.shape[axis];
+
+        let mut iter = (!self.is_empty()).then(|| self.iter());
+        let mut other_iter = (!other.is_empty()).then(|| other.iter());
+
+        CircularArrayVec::from_iter(
+            shape,
+            iter_indices(shape).map(|index| {
+                match index[axis] < len {
+                    true => iter.as_mut().and_then(|iter| iter.next()),
+                    false => other_iter.as_mut().and_then(|iter| iter.next()),
+                }
+                .expect("Misaligned concatenated elements")
+                .clone()
+            }),
+        )
+    }
+
+    /// Create a new [`CircularArrayVec`] with the given `axis` of length `len`,
+    /// placing the `src` slices of this array at index `dst` of the new array.
+    /// Remaining slices are filled with `fill`.
+    fn place_axis(
+        &self,
+        axis: usize,
+        src: Range<usize>,
+        dst: usize,
+        len: usize,
+        fill: T,
+    ) -> CircularArrayVec<N, T> {
+        let dst = dst..dst + src.len();
+        debug_assert!(dst.end <= len);
+
+        let mut shape = self.shape;
+        shape[axis] = len;
+
+        let mut iter = match src.is_empty() || self.is_empty() {
+            true => None,
+            false => Some(self.iter_slice(array::from_fn(|i| match i == axis {
+                true => src.clone(),
+                false => 0..self.shape[i],
+            }))),
+        };
+
+        CircularArrayVec::from_iter(
+            shape,
+            iter_indices(shape).map(|index| match dst.contains(&index[axis]) {
+                true => iter
+                    .as_mut()
+                    .and_then(|iter| iter.next())
+                    .expect("Misaligned retained elements")
+                    .clone(),
+                false => fill.clone(),
+            }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::Range;
+
+    use crate::{CircularArrayVec, CircularIndex, Retain};
+
+    #[test]
+    fn resize_axis() {
+        let shape = [4, 3, 2];
+        let m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..shape.iter().product());
+
+        for axis in 0..3 {
+            let axis_len = m.shape()[axis];
+            for len in 0..axis_len + 3 {
+                let n = axis_len.min(len);
+
+                let r = m.resize_axis(axis, len, Retain::Newest, usize::MAX);
+                assert_eq!(r.shape()[axis], len);
+                assert_eq!(r.offset(), &[0; 3]);
+                for i in 0..len {
+                    let expected = match i + n < len {
+                        true => vec![usize::MAX; m.slice_len(axis)],
+                        false => m.iter_index(axis, i + axis_len - len).cloned().collect(),
+                    };
+                    assert_eq!(r.iter_index(axis, i).cloned().collect::<Vec<_>>(), expected);
+                }
+
+                let r = m.resize_axis(axis, len, Retain::Oldest, usize::MAX);
+                assert_eq!(r.shape()[axis], len);
+                for i in 0..len {
+                    let expected = match i < n {
+                        true => m.iter_index(axis, i).cloned().collect(),
+                        false => vec![usize::MAX; m.slice_len(axis)],
+                    };
+                    assert_eq!(r.iter_index(axis, i).cloned().collect::<Vec<_>>(), expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn crop() {
+        let shape = [4, 3, 2];
+        let m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..shape.iter().product());
+        let c = m.crop([1..3, 0..3, 1..2]);
+
+        assert_eq!(c.shape(), &[2, 3, 1]);
+        assert_eq!(c.offset(), &[0; 3]);
+        assert_eq!(
+            c.data(),
+            &m.iter_slice([1..3, 0..3, 1..2])
+                .cloned()
+                .collect::<Vec<_>>()
+        );
+        assert!(m.crop([1..1, 0..3, 0..2]).is_empty());
+    }
+
+    #[test]
+    fn pad() {
+        let shape = [4, 3, 2];
+        let m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..shape.iter().product());
+
+        for axis in 0..3 {
+            let p = m.pad(axis, 2, 1, usize::MAX);
+            let axis_len = m.shape()[axis];
+
+            assert_eq!(p.shape()[axis], axis_len + 3);
+            assert_eq!(
+                p.crop(array_slice(p.shape(), axis, 2..2 + axis_len)).data(),
+                m.crop(m.shape().map(|len| 0..len)).data()
+            );
+            assert!(p.iter_range(axis, 0..2).all(|el| *el == usize::MAX));
+            assert!(p.iter_index(axis, axis_len + 2).all(|el| *el == usize::MAX));
+        }
+    }
+
+    #[test]
+    fn concat() {
+        let shape = [4, 3, 2];
+        let m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..shape.iter().product());
+
+        for axis in 0..3 {
+            let axis_len = m.shape()[axis];
+            let p = m.pad(axis, 0, 2, usize::MAX);
+            let c = m.concat(axis, &m.crop(array_slice(m.shape(), axis, 0..2)));
+
+            assert_eq!(c.shape()[axis], axis_len + 2);
+            assert_eq!(
+                c.crop(array_slice(c.shape(), axis, 0..axis_len)).data(),
+                p.crop(array_slice(p.shape(), axis, 0..axis_len)).data()
+            );
+            assert_eq!(
+                c.iter_range(axis, axis_len..axis_len + 2)
+                    .collect::<Vec<_>>(),
+                m.iter_range(axis, 0..2).collect::<Vec<_>>()
+            );
+        }
+    }
+
+    #[test]
+    fn empty() {
+        let e = CircularArrayVec::<3, usize>::from_iter([4, 0, 2], 0..0);
+
+        assert_eq!(e.resize_axis(0, 6, Retain::Newest, 1).shape(), &[6, 0, 2]);
+        assert_eq!(e.resize_axis(1, 2, Retain::Oldest, 1).data(), &[1; 16]);
+        assert_eq!(e.pad(2, 1, 1, 1).shape(), &[4, 0, 4]);
+        assert_eq!(e.pad(1, 1, 0, 1).data(), &[1; 8]);
+        assert_eq!(e.concat(0, &e).shape(), &[8, 0, 2]);
+
+        let m = CircularArrayVec::from_iter([4, 2, 2], 0..16);
+        assert_eq!(e.concat(1, &m).data(), m.data());
+        assert_eq!(m.concat(1, &e).data(), m.data());
+    }
+
+    #[test]
+    #[should_panic]
+    fn concat_shape() {
+        let a = CircularArrayVec::from_iter([2, 3], 0..6);
+        let b = CircularArrayVec::from_iter([2, 2], 0..4);
+        a.concat(0, &b);
+    }
+
+    fn array_slice(shape: &[usize; 3], axis: usize, range: Range<usize>) -> [Range<usize>; 3] {
+        std::array::from_fn(|i| match i == axis {
+            true => range.clone(),
+            false => 0..shape[i],
+        })
+    }
+}
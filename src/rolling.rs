@@ -0,0 +1,468 @@
+use std::array;
+use std::collections::VecDeque;
+use std::ops::{Add, Range, Sub};
+
+use crate::array_index::CircularIndex;
+use crate::array_iter::iter_indices;
+use crate::array_mut::CircularMut;
+use crate::strides::Strides;
+use crate::{CircularArray, CircularArrayVec};
+
+/// A [`CircularArray`] maintaining the sum of each lane of an axis as slices are
+/// pushed onto the axis.
+///
+/// Sums are stored as a [`CircularArrayVec`] of `M = N - 1` dimensions, the shape
+/// of the array without the rolling axis. Each push updates sums incrementally,
+/// subtracting evicted elements and adding inserted elements. For floating point
+/// elements, error accumulates over many pushes. See [`RollingSum::recompute`].
+///
+/// See [`CircularArray::rolling_sum`].
+pub struct RollingSum<const N: usize, const M: usize, A, T> {
+    /// The array.
+    array: CircularArray<N, A, T>,
+    /// The rolling axis.
+    axis: usize,
+    /// The sum of each lane of the rolling axis, aligned to the array offset.
+    sums: CircularArrayVec<M, T>,
+}
+
+impl<const N: usize, A: AsRef<[T]>, T> CircularArray<N, A, T>
+where
+    T: Clone + Default + Add<Output = T> + Sub<Output = T>,
+{
+    /// Convert the array into a [`RollingSum`] over the lanes of the given `axis`.
+    /// `M` **must** equal `N - 1`.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex, RollingSum};
+    /// let array = CircularArray::new([3, 2], vec![
+    ///     0, 1, 2,
+    ///     3, 4, 5,
+    /// ]);
+    /// let mut rolling: RollingSum<2, 1, _, _> = array.rolling_sum(0);
+    /// assert_eq!(rolling.sums().data(), &[3, 12]);
+    ///
+    /// rolling.push_front(&[6, 7]);
+    /// assert_eq!(rolling.sums().data(), &[9, 16]);
+    /// assert_eq!(rolling.means().collect::<Vec<_>>(), &[3.0, 16.0 / 3.0]);
+    /// ```
+    pub fn rolling_sum<const M: usize>(self, axis: usize) -> RollingSum<N, M, A, T> {
+        const { assert!(M + 1 == N, "rolling sum dimensionality must be N - 1") };
+        assert_shape_index!(axis, N);
+
+        let shape = lane_index(self.shape, axis);
+        let len = shape.iter().product();
+        let mut rolling = RollingSum {
+            array: self,
+            axis,
+            sums: CircularArrayVec::new(shape, vec![T::default(); len]),
+        };
+        rolling.recompute();
+
+        rolling
+    }
+}
+
+impl<const N: usize, const M: usize, A, T> RollingSum<N, M, A, T>
+where
+    A: AsRef<[T]>,
+    T: Clone + Default + Add<Output = T> + Sub<Output = T>,
+{
+    /// Get a reference to the array.
+    pub fn array(&self) -> &CircularArray<N, A, T> {
+        &self.array
+    }
+
+    /// Get the rolling axis.
+    pub fn axis(&self) -> usize {
+        self.axis
+    }
+
+    /// Get the sum of each lane of the rolling axis, aligned to the array offset.
+    pub fn sums(&self) -> &CircularArrayVec<M, T> {
+        &self.sums
+    }
+
+    /// Iterate over the mean of each lane of the rolling axis, aligned to the
+    /// array offset.
+    pub fn means(&self) -> impl ExactSizeIterator<Item = f64> + '_
+    where
+        T: Into<f64>,
+    {
+        let len = self.array.shape[self.axis] as f64;
+        self.sums.iter().map(move |sum| sum.clone().into() / len)
+    }
+
+    /// Recompute the sums from the elements of the array, discarding accumulated
+    /// error.
+    pub fn recompute(&mut self) {
+        self.sums.fill(T::default());
+        self.array
+            .reduce_axis_into(self.axis, &mut self.sums, |sum, el| {
+                *sum = sum.clone() + el.clone()
+            });
+    }
+
+    /// Drop the sums, returning the array.
+    pub fn into_inner(self) -> CircularArray<N, A, T> {
+        self.array
+    }
+}
+
+impl<const N: usize, const M: usize, A, T> RollingSum<N, M, A, T>
+where
+    A: AsRef<[T]> + AsMut<[T]>,
+    T: Clone + Default + Add<Output = T> + Sub<Output = T>,
+{
+    /// Push elements to the front of the rolling axis, updating the sums. See
+    /// [`CircularMut::push_front`].
+    pub fn push_front(&mut self, el: &[T]) {
+        let n = slice_n(&self.array, self.axis, el);
+        self.update(0..n, el);
+        self.array.push_front(self.axis, el);
+    }
+
+    /// Push elements to the back of the rolling axis, updating the sums. See
+    /// [`CircularMut::push_back`].
+    pub fn push_back(&mut self, el: &[T]) {
+        let len = self.array.shape[self.axis];
+        let n = slice_n(&self.array, self.axis, el);
+        self.update(len - n..len, el);
+        self.array.push_back(self.axis, el);
+    }
+
+    /// Update the sums, replacing the elements of the `evicted` slices of the
+    /// rolling axis with `el`.
+    fn update(&mut self, evicted: Range<usize>, el: &[T]) {
+        let axis = self.axis;
+        let n = evicted.len();
+        if n == 0 {
+            return;
+        }
+
+        let mut shape = self.array.shape;
+        shape[axis] = n;
+        let strides = Strides::new(&self.sums.shape);
+        let sums = self.sums.array.as_mut_slice();
+
+        let evicted = self.array.iter_range(axis, evicted);
+        for ((index, el), evicted) in iter_indices(shape).zip(el).zip(evicted) {
+            let sum = &mut sums[strides.offset_index(lane_index(index, axis))];
+
+            *sum = sum.clone() - evicted.clone() + el.clone();
+        }
+    }
+}
+
+/// A [`CircularArray`] maintaining the minimum and maximum of each lane of an axis
+/// as slices are pushed onto the axis.
+///
+/// Each lane holds a monotonic deque of candidate minimums and maximums, such that
+/// pushes to the front of the axis update in amortized `O(1)` per element. Pushes
+/// to the back of the axis reverse the direction of the window, and rebuild the
+/// deques in `O(len)`. Where elements are not comparable (`NaN`), the minimum and
+/// maximum are unspecified.
+///
+/// See [`CircularArray::rolling_min_max`].
+pub struct RollingMinMax<const N: usize, const M: usize, A, T> {
+    /// The array.
+    array: CircularArray<N, A, T>,
+    /// The rolling axis.
+    axis: usize,
+    /// The shape of the lanes of the rolling axis.
+    lanes: [usize; M],
+    /// The strides of the lanes of the rolling axis.
+    strides: Strides<M>,
+    /// The sequence number of the first logical slice of the rolling axis.
+    head: usize,
+    /// The candidate minimums of each lane, paired with their sequence number.
+    min: Vec<VecDeque<(usize, T)>>,
+    /// The candidate maximums of each lane, paired with their sequence number.
+    max: Vec<VecDeque<(usize, T)>>,
+}
+
+impl<const N: usize, A: AsRef<[T]>, T: Clone + PartialOrd> CircularArray<N, A, T> {
+    /// Convert the array into a [`RollingMinMax`] over the lanes of the given
+    /// `axis`. `M` **must** equal `N - 1`.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex, RollingMinMax};
+    /// let array = CircularArray::new([3, 2], vec![
+    ///     4, 1, 2,
+    ///     3, 5, 0,
+    /// ]);
+    /// let mut rolling: RollingMinMax<2, 1, _, _> = array.rolling_min_max(0);
+    /// assert_eq!((rolling.min([0]), rolling.max([0])), (&1, &4));
+    ///
+    /// rolling.push_front(&[3, 9]);
+    /// assert_eq!((rolling.min([0]), rolling.max([0])), (&1, &3));
+    /// assert_eq!((rolling.min([1]), rolling.max([1])), (&0, &9));
+    /// ```
+    pub fn rolling_min_max<const M: usize>(self, axis: usize) -> RollingMinMax<N, M, A, T> {
+        const { assert!(M + 1 == N, "rolling min/max dimensionality must be N - 1") };
+        assert_shape_index!(axis, N);
+
+        let lanes = lane_index(self.shape, axis);
+        let len = lanes.iter().product();
+        let mut rolling = RollingMinMax {
+            array: self,
+            axis,
+            lanes,
+            strides: Strides::new(&lanes),
+            head: 0,
+            min: vec![VecDeque::new(); len],
+            max: vec![VecDeque::new(); len],
+        };
+        rolling.rebuild();
+
+        rolling
+    }
+}
+
+impl<const N: usize, const M: usize, A: AsRef<[T]>, T: Clone + PartialOrd>
+    RollingMinMax<N, M, A, T>
+{
+    /// Get a reference to the array.
+    pub fn array(&self) -> &CircularArray<N, A, T> {
+        &self.array
+    }
+
+    /// Get the rolling axis.
+    pub fn axis(&self) -> usize {
+        self.axis
+    }
+
+    /// Get the minimum of the given lane of the rolling axis, aligned to the array
+    /// offset. The rolling axis **must** not be empty.
+    pub fn min(&self, lane: [usize; M]) -> &T {
+        Self::front(&self.min[self.lane(lane)])
+    }
+
+    /// Get the maximum of the given lane of the rolling axis, aligned to the array
+    /// offset. The rolling axis **must** not be empty.
+    pub fn max(&self, lane: [usize; M]) -> &T {
+        Self::front(&self.max[self.lane(lane)])
+    }
+
+    /// Drop the deques, returning the array.
+    pub fn into_inner(self) -> CircularArray<N, A, T> {
+        self.array
+    }
+
+    /// Rebuild the deques from the elements of the array.
+    fn rebuild(&mut self) {
+        self.head = 0;
+        self.min.iter_mut().for_each(VecDeque::clear);
+        self.max.iter_mut().for_each(VecDeque::clear);
+
+        for (index, el) in self.array.indexed_iter() {
+            let lane = self.strides.offset_index(lane_index(index, self.axis));
+            insert(
+                &mut self.min[lane],
+                &mut self.max[lane],
+                index[self.axis],
+                el,
+            );
+        }
+    }
+
+    /// Get the flat index of the given lane.
+    fn lane(&self, lane: [usize; M]) -> usize {
+        assert!(
+            lane.iter().zip(self.lanes).all(|(i, len)| *i < len),
+            "lane {:?} is out of bounds for lanes of shape {:?}",
+            lane,
+            self.lanes
+        );
+
+        self.strides.offset_index(lane)
+    }
+
+    /// Get the value at the front of the given deque.
+    fn front(deque: &VecDeque<(usize, T)>) -> &T {
+        &deque.front().expect("Rolling axis is empty").1
+    }
+}
+
+impl<const N: usize, const M: usize, A, T> RollingMinMax<N, M, A, T>
+where
+    A: AsRef<[T]> + AsMut<[T]>,
+    T: Clone + PartialOrd,
+{
+    /// Push elements to the front of the rolling axis, updating the minimums and
+    /// maximums. See [`CircularMut::push_front`].
+    pub fn push_front(&mut self, el: &[T]) {
+        let axis = self.axis;
+        let len = self.array.shape[axis];
+        let n = slice_n(&self.array, axis, el);
+
+        let mut shape = self.array.shape;
+        shape[axis] = n;
+        self.head += n;
+
+        for (index, el) in iter_indices(shape).zip(el) {
+            let lane = self.strides.offset_index(lane_index(index, axis));
+            let seq = self.head + len - n + index[axis];
+            insert(&mut self.min[lane], &mut self.max[lane], seq, el);
+        }
+
+        // Evict candidates preceding the first logical slice.
+        let head = self.head;
+        for deque in self.min.iter_mut().chain(self.max.iter_mut()) {
+            while deque.front().is_some_and(|(seq, _)| *seq < head) {
+                deque.pop_front();
+            }
+        }
+
+        self.array.push_front(axis, el);
+    }
+
+    /// Push elements to the back of the rolling axis, rebuilding the minimums and
+    /// maximums. See [`CircularMut::push_back`].
+    pub fn push_back(&mut self, el: &[T]) {
+        self.array.push_back(self.axis, el);
+        self.rebuild();
+    }
+}
+
+/// Insert `el` with sequence number `seq` into the candidate `min` and `max` deques
+/// of a lane, removing candidates it supersedes.
+fn insert<T: Clone + PartialOrd>(
+    min: &mut VecDeque<(usize, T)>,
+    max: &mut VecDeque<(usize, T)>,
+    seq: usize,
+    el: &T,
+) {
+    while min.back().is_some_and(|(_, back)| back > el) {
+        min.pop_back();
+    }
+    min.push_back((seq, el.clone()));
+
+    while max.back().is_some_and(|(_, back)| back < el) {
+        max.pop_back();
+    }
+    max.push_back((seq, el.clone()));
+}
+
+/// Get the number of slices of `axis` within `el`, asserting `el` is a valid push
+/// onto `array`.
+fn slice_n<const N: usize, A: AsRef<[T]>, T>(
+    array: &CircularArray<N, A, T>,
+    axis: usize,
+    el: &[T],
+) -> usize {
+    let el_len = el.len();
+    let slice_len = array.slice_len(axis);
+    let n = el_len / slice_len;
+
+    assert_element_len!(axis, el_len, slice_len);
+    assert_slice_len!(array, axis, n);
+
+    n
+}
+
+/// Get the index of the lane of `axis` containing the given `index`, removing
+/// `axis` from `index`.
+fn lane_index<const N: usize, const M: usize>(index: [usize; N], axis: usize) -> [usize; M] {
+    array::from_fn(|i| index[i + (i >= axis) as usize])
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CircularArrayVec, CircularMut, RollingMinMax, RollingSum};
+
+    #[test]
+    fn push() {
+        let shape = [4, 3, 2];
+        let len = shape.iter().product::<usize>();
+
+        for axis in 0..3 {
+            let m =
+                CircularArrayVec::from_iter_offset(shape, [1, 2, 1], (0..len).map(|i| i as i64));
+            let mut rolling: RollingSum<3, 2, _, _> = m.rolling_sum(axis);
+
+            for i in 0..8 {
+                let n = i % (shape[axis] + 1);
+                let el = (0..n * rolling.array().slice_len(axis))
+                    .map(|j| (i * 100 + j) as i64)
+                    .collect::<Vec<_>>();
+
+                match i % 2 {
+                    0 => rolling.push_front(&el),
+                    _ => rolling.push_back(&el),
+                }
+
+                let sums = rolling.sums().data().clone();
+                rolling.recompute();
+                assert_eq!(&sums, rolling.sums().data());
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn push_len() {
+        let m = CircularArrayVec::from_iter([3, 3], 0..9);
+        let mut rolling: RollingSum<2, 1, _, _> = m.rolling_sum(0);
+        rolling.push_front(&[0, 1]);
+    }
+
+    #[test]
+    fn into_inner() {
+        let m = CircularArrayVec::from_iter([3, 3], 0..9);
+        let mut rolling: RollingSum<2, 1, _, _> = m.rolling_sum(1);
+        rolling.push_front(&[9, 10, 11]);
+
+        let mut expected = CircularArrayVec::from_iter([3, 3], 0..9);
+        expected.push_front(1, &[9, 10, 11]);
+        assert_eq!(rolling.into_inner().data(), expected.data());
+    }
+
+    #[test]
+    fn min_max() {
+        let shape = [4, 3, 2];
+        let len = shape.iter().product::<usize>();
+
+        for axis in 0..3 {
+            let m =
+                CircularArrayVec::from_iter_offset(shape, [1, 2, 1], (0..len).map(|i| i * 7 % 11));
+            let mut rolling: RollingMinMax<3, 2, _, _> = m.rolling_min_max(axis);
+
+            for i in 0..12 {
+                let n = i % (shape[axis] + 1);
+                let el = (0..n * rolling.array().slice_len(axis))
+                    .map(|j| (i * 13 + j * 5) % 17)
+                    .collect::<Vec<_>>();
+
+                match i % 5 {
+                    4 => rolling.push_back(&el),
+                    _ => rolling.push_front(&el),
+                }
+
+                let array = rolling.array();
+                for index in array.indexed_iter().map(|(index, _)| index) {
+                    let lane = [0, 1].map(|i| index[i + (i >= axis) as usize]);
+                    let window = array
+                        .indexed_iter()
+                        .filter(|(other, _)| (0..3).all(|i| i == axis || other[i] == index[i]));
+                    let (min, max) = window.fold((usize::MAX, 0), |(min, max), (_, el)| {
+                        (min.min(*el), max.max(*el))
+                    });
+
+                    assert_eq!(rolling.min(lane), &min);
+                    assert_eq!(rolling.max(lane), &max);
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn min_max_lane() {
+        let m = CircularArrayVec::from_iter([3, 3], 0..9);
+        let rolling: RollingMinMax<2, 1, _, _> = m.rolling_min_max(0);
+        rolling.min([3]);
+    }
+}
@@ -0,0 +1,227 @@
+use crate::array_index::CircularIndex;
+use crate::array_iter::iter_indices;
+use crate::array_mut::CircularMut;
+use crate::{CircularArray, PushPreview};
+
+impl<const N: usize, A: AsRef<[T]>, T: Clone> CircularArray<N, A, T> {
+    /// Write the elements of this array into `dst` with the axes reordered by
+    /// `axes`, without allocating. Equivalent to [`CircularArray::permute_axes`],
+    /// where `dst` **must** have the permuted shape. The offset of `dst` is
+    /// replaced with the permuted offset.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let array = CircularArray::new_offset([3, 2], [1, 0], vec![
+    ///     2, 0, 1,
+    ///     5, 3, 4,
+    /// ]);
+    /// let mut dst = CircularArray::new([2, 3], [0; 6]);
+    /// array.permute_axes_into([1, 0], &mut dst);
+    ///
+    /// assert_eq!(dst.iter().cloned().collect::<Vec<_>>(), &[
+    ///     0, 3,
+    ///     1, 4,
+    ///     2, 5,
+    /// ]);
+    /// ```
+    pub fn permute_axes_into<B: AsRef<[T]> + AsMut<[T]>>(
+        &self,
+        axes: [usize; N],
+        dst: &mut CircularArray<N, B, T>,
+    ) {
+        assert_permutation!(axes, N);
+
+        let shape = axes.map(|axis| self.shape[axis]);
+        assert!(
+            dst.shape == shape,
+            "shape {:?} does not match permuted shape {:?}",
+            dst.shape,
+            shape
+        );
+
+        let strides = axes.map(|axis| self.strides[axis]);
+        let array = self.array.as_ref();
+
+        dst.offset = axes.map(|axis| self.offset[axis]);
+        iter_indices(shape)
+            .zip(dst.array.as_mut())
+            .for_each(|(index, el)| {
+                let i = index
+                    .iter()
+                    .zip(strides)
+                    .map(|(idx, stride)| idx * stride)
+                    .sum::<usize>();
+                el.clone_from(&array[i]);
+            });
+    }
+
+    /// Write a [`PushPreview`] of pushing `n` slices to the front of the given
+    /// `axis` into `preview`, reusing its allocation. See
+    /// [`CircularArray::dry_run_push_front`].
+    pub fn dry_run_push_front_into(&self, axis: usize, n: usize, preview: &mut PushPreview<N>) {
+        assert_shape_index!(axis, N);
+        assert_slice_len!(self, axis, n);
+
+        preview.axis = axis;
+        preview.shape = self.shape;
+        preview.evicted = 0..n;
+        preview.ranges.clear();
+        self.extend_push_ranges(axis, 0..n, &mut preview.ranges);
+    }
+
+    /// Write a [`PushPreview`] of pushing `n` slices to the back of the given
+    /// `axis` into `preview`, reusing its allocation. See
+    /// [`CircularArray::dry_run_push_back`].
+    pub fn dry_run_push_back_into(&self, axis: usize, n: usize, preview: &mut PushPreview<N>) {
+        assert_shape_index!(axis, N);
+        assert_slice_len!(self, axis, n);
+
+        let len = self.shape[axis];
+        preview.axis = axis;
+        preview.shape = self.shape;
+        preview.evicted = len - n..len;
+        preview.ranges.clear();
+        self.extend_push_ranges(axis, len - n..len, &mut preview.ranges);
+    }
+}
+
+impl<const N: usize, A: AsRef<[T]> + AsMut<[T]>, T: Clone> CircularArray<N, A, T> {
+    /// Push elements to the front of the given `axis`, writing the evicted elements
+    /// into `evicted` in the order of [`CircularIndex::iter_range`]. `evicted` is
+    /// cleared prior to writing, such that no allocation occurs where its capacity
+    /// is sufficient. See [`CircularMut::push_front`].
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let mut array = CircularArray::new([3, 3], vec![
+    ///     0, 1, 2,
+    ///     3, 4, 5,
+    ///     6, 7, 8,
+    /// ]);
+    /// let mut evicted = Vec::with_capacity(3);
+    /// array.push_front_evicted(1, &[9, 10, 11], &mut evicted);
+    ///
+    /// assert_eq!(evicted, &[0, 1, 2]);
+    /// ```
+    pub fn push_front_evicted(&mut self, axis: usize, el: &[T], evicted: &mut Vec<T>) {
+        assert_shape_index!(axis, N);
+        let n = el.len() / self.slice_len(axis);
+        assert_slice_len!(self, axis, n);
+
+        self.extend_evicted(axis, 0..n, evicted);
+        self.push_front(axis, el);
+    }
+
+    /// Push elements to the back of the given `axis`, writing the evicted elements
+    /// into `evicted` in the order of [`CircularIndex::iter_range`]. `evicted` is
+    /// cleared prior to writing, such that no allocation occurs where its capacity
+    /// is sufficient. See [`CircularMut::push_back`].
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let mut array = CircularArray::new([3, 3], vec![
+    ///     0, 1, 2,
+    ///     3, 4, 5,
+    ///     6, 7, 8,
+    /// ]);
+    /// let mut evicted = Vec::with_capacity(3);
+    /// array.push_back_evicted(0, &[9, 10, 11], &mut evicted);
+    ///
+    /// assert_eq!(evicted, &[2, 5, 8]);
+    /// ```
+    pub fn push_back_evicted(&mut self, axis: usize, el: &[T], evicted: &mut Vec<T>) {
+        assert_shape_index!(axis, N);
+        let len = self.shape[axis];
+        let n = el.len() / self.slice_len(axis);
+        assert_slice_len!(self, axis, n);
+
+        self.extend_evicted(axis, len - n..len, evicted);
+        self.push_back(axis, el);
+    }
+
+    /// Clear `evicted`, then extend it with the elements of the given `range` of
+    /// `axis`.
+    fn extend_evicted(&self, axis: usize, range: std::ops::Range<usize>, evicted: &mut Vec<T>) {
+        evicted.clear();
+        if !range.is_empty() {
+            evicted.extend(self.iter_range(axis, range).cloned());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::Range;
+
+    use crate::{CircularArrayVec, CircularIndex, CircularMut};
+
+    #[test]
+    fn permute_axes_into() {
+        let shape = [4, 3, 2];
+        let m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..shape.iter().product());
+        let mut dst = CircularArrayVec::new([2, 4, 3], vec![0; 24]);
+        let ptr = dst.data().as_ptr();
+
+        m.permute_axes_into([2, 0, 1], &mut dst);
+        let expected = m.permute_axes([2, 0, 1]);
+
+        assert_eq!(dst.data().as_ptr(), ptr);
+        assert_eq!(dst.offset(), expected.offset());
+        assert_eq!(dst.data(), expected.data());
+    }
+
+    #[test]
+    fn dry_run_into() {
+        let shape = [4, 3, 2];
+        let m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..shape.iter().product());
+        let mut preview = m.dry_run_push_front(0, 4);
+
+        for axis in 0..3 {
+            for n in 0..=m.shape()[axis] {
+                m.dry_run_push_front_into(axis, n, &mut preview);
+                assert_eq!(preview, m.dry_run_push_front(axis, n));
+                m.dry_run_push_back_into(axis, n, &mut preview);
+                assert_eq!(preview, m.dry_run_push_back(axis, n));
+            }
+        }
+    }
+
+    #[test]
+    fn push_evicted() {
+        let shape = [4, 3, 2];
+        let len = shape.iter().product::<usize>();
+        let mut evicted = Vec::with_capacity(len);
+        let ptr = evicted.as_ptr();
+
+        for axis in 0..3 {
+            for n in 0..=shape[axis] {
+                let mut m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..len);
+                let el = vec![len; n * m.slice_len(axis)];
+
+                let expected = evicted_elements(&m, m.dry_run_push_front(axis, n).evicted_slice());
+                m.push_front_evicted(axis, &el, &mut evicted);
+                assert_eq!(evicted, expected);
+
+                let expected = evicted_elements(&m, m.dry_run_push_back(axis, n).evicted_slice());
+                m.push_back_evicted(axis, &el, &mut evicted);
+                assert_eq!(evicted, expected);
+                assert_eq!(evicted.as_ptr(), ptr);
+
+                let mut pushed = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..len);
+                pushed.push_front(axis, &el);
+                pushed.push_back(axis, &el);
+                assert_eq!(m.data(), pushed.data());
+            }
+        }
+    }
+
+    fn evicted_elements(m: &CircularArrayVec<3, usize>, slice: [Range<usize>; 3]) -> Vec<usize> {
+        match slice.iter().any(|range| range.is_empty()) {
+            true => vec![],
+            false => m.iter_slice(slice).cloned().collect(),
+        }
+    }
+}
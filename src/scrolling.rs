@@ -0,0 +1,310 @@
+use std::array;
+use std::ops::Range;
+
+use crate::array_index::CircularIndex;
+use crate::index_iter::IndexIterator;
+use crate::CircularArray;
+
+/// A source of the elements of a world larger than a [`ScrollingWindow`], such
+/// as chunks loaded from disk.
+///
+/// Regions are passed as `N` dimensional index ranges of the world, where only
+/// the range of axis `0` may be of a length greater than `1`, as per
+/// [`CircularMut::translate_front`](crate::CircularMut::translate_front).
+pub trait ChunkSource<const N: usize, T> {
+    /// Get the contiguous elements of the given `region`.
+    fn load(&self, region: [Range<usize>; N]) -> &[T];
+
+    /// Store the elements of the given `region`, evicted from the window. Regions
+    /// passed to `store` are blocks of any shape, and elements are ordered with
+    /// axis `0` the fastest varying. The default implementation discards them.
+    fn store(
+        &mut self,
+        region: [Range<usize>; N],
+        elements: &mut dyn ExactSizeIterator<Item = &T>,
+    ) {
+        let _ = (region, elements);
+    }
+}
+
+/// A [`CircularArray`] scrolling over the world of a [`ChunkSource`], tracking
+/// the `origin` of the window and a `focus` to be kept within it. Elements
+/// leaving the window are stored to the source, and elements entering the window
+/// are loaded from it. See [`CircularArray::into_scrolling`].
+pub struct ScrollingWindow<const N: usize, A, T, S> {
+    /// The window of the world.
+    array: CircularArray<N, A, T>,
+    /// The source of the world.
+    source: S,
+    /// The world position of the first element of the window.
+    origin: [usize; N],
+    /// The world position kept within the window.
+    focus: [usize; N],
+    /// The minimum distance of the focus from the bounds of the window.
+    margin: [usize; N],
+}
+
+impl<const N: usize, A: AsRef<[T]> + AsMut<[T]>, T: Clone> CircularArray<N, A, T> {
+    /// Convert the array into a [`ScrollingWindow`] of the world of `source`,
+    /// positioned at `origin`. The window is loaded from `source`, and focused on
+    /// its center.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::ops::Range;
+    /// # use n_circular_array::{ChunkSource, CircularArray, CircularIndex};
+    /// // A one dimensional world of 16 elements.
+    /// struct World(Vec<u32>);
+    ///
+    /// impl ChunkSource<1, u32> for World {
+    ///     fn load(&self, region: [Range<usize>; 1]) -> &[u32] {
+    ///         &self.0[region[0].clone()]
+    ///     }
+    ///
+    ///     fn store(&mut self, region: [Range<usize>; 1], elements: &mut dyn ExactSizeIterator<Item = &u32>) {
+    ///         self.0[region[0].clone()].iter_mut().zip(elements).for_each(|(a, b)| *a = *b);
+    ///     }
+    /// }
+    ///
+    /// let array = CircularArray::new([4], vec![0; 4]);
+    /// let mut window = array.into_scrolling([2], World((0..16).collect()));
+    /// assert_eq!(window.array().iter().cloned().collect::<Vec<_>>(), &[2, 3, 4, 5]);
+    ///
+    /// // Edit the window, then move the focus beyond it.
+    /// window.array_mut().data_mut().fill(0);
+    /// window.move_to([10]);
+    ///
+    /// assert_eq!(window.origin(), &[7]);
+    /// assert_eq!(window.array().iter().cloned().collect::<Vec<_>>(), &[7, 8, 9, 10]);
+    /// assert_eq!(&window.source().0[..8], &[0, 1, 0, 0, 0, 0, 6, 7]);
+    /// ```
+    pub fn into_scrolling<S: ChunkSource<N, T>>(
+        mut self,
+        origin: [usize; N],
+        source: S,
+    ) -> ScrollingWindow<N, A, T, S> {
+        if !self.is_empty() {
+            self.offset = [0; N];
+            let src = IndexIterator::new_unbound(self.spans_raw());
+            let dst = IndexIterator::new_unbound(self.spans_raw());
+            self.translate_spans(src, dst, origin, |region| source.load(region));
+        }
+
+        ScrollingWindow {
+            focus: array::from_fn(|i| origin[i] + self.shape[i] / 2),
+            array: self,
+            source,
+            origin,
+            margin: [0; N],
+        }
+    }
+}
+
+impl<const N: usize, A: AsRef<[T]> + AsMut<[T]>, T: Clone, S: ChunkSource<N, T>>
+    ScrollingWindow<N, A, T, S>
+{
+    /// Set the minimum distance of the focus from the bounds of the window before
+    /// scrolling, clamped to half the length of each axis. Defaults to `[0; N]`.
+    pub fn with_margin(mut self, margin: [usize; N]) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Get a reference to the window.
+    pub fn array(&self) -> &CircularArray<N, A, T> {
+        &self.array
+    }
+
+    /// Get a mutable reference to the window. Edits are stored to the source when
+    /// evicted, or on [`ScrollingWindow::flush`].
+    pub fn array_mut(&mut self) -> &mut CircularArray<N, A, T> {
+        &mut self.array
+    }
+
+    /// Get a reference to the source.
+    pub fn source(&self) -> &S {
+        &self.source
+    }
+
+    /// Get the world position of the first element of the window.
+    pub fn origin(&self) -> &[usize; N] {
+        &self.origin
+    }
+
+    /// Get the world position kept within the window.
+    pub fn focus(&self) -> &[usize; N] {
+        &self.focus
+    }
+
+    /// Get a reference to the element at the given world position, if within the
+    /// window.
+    pub fn get(&self, pos: [usize; N]) -> Option<&T> {
+        (0..N)
+            .all(|i| (self.origin[i]..self.origin[i] + self.array.shape[i]).contains(&pos[i]))
+            .then(|| self.array.get(array::from_fn(|i| pos[i] - self.origin[i])))
+    }
+
+    /// Move the focus to the given world position, scrolling the window by the
+    /// minimum distance keeping the focus within the margin of each axis.
+    pub fn move_to(&mut self, pos: [usize; N]) {
+        self.focus = pos;
+
+        let origin = array::from_fn(|i| {
+            let len = self.array.shape[i];
+            let margin = self.margin[i].min(len.saturating_sub(1) / 2);
+
+            if pos[i] < self.origin[i] + margin {
+                pos[i].saturating_sub(margin)
+            } else if pos[i] + margin >= self.origin[i] + len {
+                (pos[i] + margin + 1).saturating_sub(len)
+            } else {
+                self.origin[i]
+            }
+        });
+        self.scroll(origin);
+    }
+
+    /// Scroll the window such that the focus is at its center.
+    pub fn recenter(&mut self) {
+        let origin = array::from_fn(|i| self.focus[i].saturating_sub(self.array.shape[i] / 2));
+        self.scroll(origin);
+    }
+
+    /// Store all elements of the window to the source, without scrolling.
+    pub fn flush(&mut self) {
+        if !self.array.is_empty() {
+            let region = array::from_fn(|i| self.origin[i]..self.origin[i] + self.array.shape[i]);
+            self.source.store(region, &mut self.array.iter());
+        }
+    }
+
+    /// Drop the window without flushing, returning the array, its origin and the
+    /// source.
+    pub fn into_parts(self) -> (CircularArray<N, A, T>, [usize; N], S) {
+        (self.array, self.origin, self.source)
+    }
+
+    /// Scroll the window to the given `origin`, storing evicted elements and
+    /// loading ingested elements.
+    fn scroll(&mut self, origin: [usize; N]) {
+        if origin == self.origin || self.array.is_empty() {
+            self.origin = origin;
+            return;
+        }
+
+        let delta = array::from_fn(|i| origin[i] as isize - self.origin[i] as isize);
+        let ScrollingWindow { array, source, .. } = self;
+
+        array.evict(delta, self.origin, |region, elements| {
+            source.store(region, elements)
+        });
+        array.translate(delta, self.origin, |region| source.load(region));
+        self.origin = origin;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::Range;
+
+    use crate::strides::Strides;
+    use crate::{ChunkSource, CircularArrayVec, CircularIndex};
+
+    /// A world of `[9, 8, 7]` elements, counting the elements stored.
+    struct World {
+        elements: Vec<i32>,
+        strides: Strides<3>,
+        stored: usize,
+    }
+
+    impl ChunkSource<3, i32> for World {
+        fn load(&self, region: [Range<usize>; 3]) -> &[i32] {
+            &self.elements[self.strides.flatten_range(region)]
+        }
+
+        fn store(
+            &mut self,
+            region: [Range<usize>; 3],
+            elements: &mut dyn ExactSizeIterator<Item = &i32>,
+        ) {
+            for z in region[2].clone() {
+                for y in region[1].clone() {
+                    for x in region[0].clone() {
+                        let i = self.strides.offset_index([x, y, z]);
+                        self.elements[i] = *elements.next().unwrap();
+                        self.stored += 1;
+                    }
+                }
+            }
+            assert!(elements.next().is_none());
+        }
+    }
+
+    /// Iterate over the world indices of the window of `shape` at `origin`.
+    fn iter_window(origin: [usize; 3], shape: [usize; 3]) -> impl Iterator<Item = [usize; 3]> {
+        (origin[2]..origin[2] + shape[2]).flat_map(move |z| {
+            (origin[1]..origin[1] + shape[1])
+                .flat_map(move |y| (origin[0]..origin[0] + shape[0]).map(move |x| [x, y, z]))
+        })
+    }
+
+    #[test]
+    fn scrolling() {
+        let strides = Strides::new(&[9, 8, 7]);
+        let world = World {
+            elements: (0..9 * 8 * 7).collect(),
+            strides,
+            stored: 0,
+        };
+        let mut expected = world.elements.clone();
+
+        let shape = [4, 3, 2];
+        let m = CircularArrayVec::from_iter(shape, (0..24).map(|_| 0));
+        let mut w = m.into_scrolling([2, 3, 2], world).with_margin([1, 1, 0]);
+        assert_eq!(w.focus(), &[4, 4, 3]);
+
+        let origins = [[3, 3, 2], [4, 4, 3], [0, 0, 0], [5, 5, 4], [2, 5, 1]];
+        for (pos, origin) in [[5, 4, 3], [6, 5, 4], [0, 0, 0], [7, 6, 5], [3, 6, 1]]
+            .into_iter()
+            .zip(origins)
+        {
+            // Edit the window, stored on eviction.
+            w.array_mut()
+                .data_mut()
+                .iter_mut()
+                .for_each(|el| *el += 1000);
+            for index in iter_window(*w.origin(), shape) {
+                expected[strides.offset_index(index)] += 1000;
+            }
+
+            w.move_to(pos);
+            assert_eq!(w.origin(), &origin);
+            assert!(w.get(pos).is_some());
+            assert!(
+                w.array()
+                    .iter()
+                    .eq(iter_window(origin, shape)
+                        .map(|index| &expected[strides.offset_index(index)]))
+            );
+        }
+
+        w.recenter();
+        assert_eq!(w.origin(), &[1, 5, 0]);
+        assert_eq!(w.get([0, 0, 0]), None);
+        assert_eq!(
+            w.get([2, 6, 1]),
+            Some(&expected[strides.offset_index([2, 6, 1])])
+        );
+
+        w.array_mut().data_mut().fill(-1);
+        for index in iter_window([1, 5, 0], shape) {
+            expected[strides.offset_index(index)] = -1;
+        }
+        w.flush();
+
+        let (_, origin, world) = w.into_parts();
+        assert_eq!(origin, [1, 5, 0]);
+        assert!(world.stored > 0);
+        assert_eq!(world.elements, expected);
+    }
+}
@@ -0,0 +1,209 @@
+use crate::array_index::CircularIndex;
+use crate::CircularArray;
+
+impl<const N: usize, A: AsRef<[T]>, T> CircularArray<N, A, T> {
+    /// Get the index of the greatest element, aligned to the offset. Where several
+    /// elements are equally greatest, the first in the order of
+    /// [`CircularIndex::iter`](crate::CircularIndex::iter) is returned. Elements not
+    /// comparable to themselves (such as `NaN`) are ignored. Returns `None` if no
+    /// element is comparable.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::CircularArray;
+    /// let array = CircularArray::new_offset([3, 2], [1, 0], vec![
+    ///     2.0, 9.0, f32::NAN,
+    ///     5.0, 3.0, 9.0,
+    /// ]);
+    ///
+    /// assert_eq!(array.argmax(), Some([0, 0]));
+    /// assert_eq!(array.argmin(), Some([2, 0]));
+    /// ```
+    pub fn argmax(&self) -> Option<[usize; N]>
+    where
+        T: PartialOrd,
+    {
+        self.arg_by(|el, best| el > best)
+    }
+
+    /// Get the index of the least element, aligned to the offset. See
+    /// [`CircularArray::argmax`].
+    pub fn argmin(&self) -> Option<[usize; N]>
+    where
+        T: PartialOrd,
+    {
+        self.arg_by(|el, best| el < best)
+    }
+
+    /// Get the index of the first element satisfying `predicate`, aligned to the
+    /// offset. Elements are tested in the order of
+    /// [`CircularIndex::iter`](crate::CircularIndex::iter).
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::CircularArray;
+    /// let array = CircularArray::new_offset([3, 2], [1, 0], vec![
+    ///     2, 0, 1,
+    ///     5, 3, 4,
+    /// ]);
+    ///
+    /// assert_eq!(array.position(|&el| el > 2), Some([0, 1]));
+    /// assert_eq!(array.position(|&el| el > 5), None);
+    /// ```
+    pub fn position<F: FnMut(&T) -> bool>(&self, mut predicate: F) -> Option<[usize; N]> {
+        self.indexed_iter()
+            .find(|(_, el)| predicate(el))
+            .map(|(index, _)| index)
+    }
+
+    /// Get the index of the first element of the lane of `axis` through `lane` for
+    /// which `pred` returns `false`, aligned to the offset. The lane **must** be
+    /// partitioned by `pred`, such that every element returning `true` precedes every
+    /// element returning `false` in the order of the offset. `lane[axis]` is ignored.
+    /// The lane is searched in `O(log n)` time.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::CircularArray;
+    /// let array = CircularArray::new_offset([4, 2], [2, 0], vec![
+    ///     50, 60, 30, 40,
+    ///      5,  6,  3,  4,
+    /// ]);
+    ///
+    /// assert_eq!(array.partition_point_axis(0, [0, 0], |&el| el < 45), 2);
+    /// assert_eq!(array.partition_point_axis(0, [0, 1], |&el| el < 45), 4);
+    /// ```
+    pub fn partition_point_axis<F: FnMut(&T) -> bool>(
+        &self,
+        axis: usize,
+        mut lane: [usize; N],
+        mut pred: F,
+    ) -> usize {
+        assert_shape_index!(axis, N);
+        for i in (0..N).filter(|i| *i != axis) {
+            assert_slice_index!(self, i, lane[i]);
+        }
+
+        let (mut start, mut end) = (0, self.shape[axis]);
+        while start < end {
+            let mid = start + (end - start) / 2;
+            lane[axis] = mid;
+            match pred(self.get(lane)) {
+                true => start = mid + 1,
+                false => end = mid,
+            }
+        }
+
+        start
+    }
+
+    /// Binary search the lane of `axis` through `lane` for `x`, aligned to the
+    /// offset. The lane **must** be sorted in the order of the offset. `lane[axis]`
+    /// is ignored. Returns `Ok` with the index of the first element equal to `x`,
+    /// or `Err` with the index at which `x` could be inserted to retain the order.
+    /// See [`CircularArray::partition_point_axis`].
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::CircularArray;
+    /// // Timestamps of a rolling window.
+    /// let array = CircularArray::new_offset([5], [3], vec![40, 50, 60, 10, 20]);
+    ///
+    /// assert_eq!(array.binary_search_axis(0, [0], &50), Ok(3));
+    /// assert_eq!(array.binary_search_axis(0, [0], &15), Err(1));
+    /// assert_eq!(array.binary_search_axis(0, [0], &70), Err(5));
+    /// ```
+    pub fn binary_search_axis(&self, axis: usize, lane: [usize; N], x: &T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        let i = self.partition_point_axis(axis, lane, |el| el < x);
+        let mut index = lane;
+        index[axis] = i;
+
+        match i < self.shape[axis] && self.get(index) == x {
+            true => Ok(i),
+            false => Err(i),
+        }
+    }
+
+    /// Get the index of the element replacing all preceding elements by `replace`,
+    /// ignoring elements not comparable to themselves.
+    fn arg_by<F: Fn(&T, &T) -> bool>(&self, replace: F) -> Option<[usize; N]>
+    where
+        T: PartialOrd,
+    {
+        self.indexed_iter()
+            .filter(|(_, el)| el.partial_cmp(el).is_some())
+            .reduce(|best, next| match replace(next.1, best.1) {
+                true => next,
+                false => best,
+            })
+            .map(|(index, _)| index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CircularArrayVec, CircularIndex, CircularMut};
+
+    #[test]
+    fn argmax() {
+        let m = CircularArrayVec::from_iter_offset([4, 3, 2], [1, 2, 1], (0..24).map(|i| i % 7));
+
+        let max = m.argmax().unwrap();
+        assert_eq!(*m.get(max), 6);
+        assert!(m
+            .indexed_iter()
+            .take_while(|(i, _)| *i != max)
+            .all(|(_, el)| *el < 6));
+
+        let min = m.argmin().unwrap();
+        assert_eq!(*m.get(min), 0);
+        assert_eq!(m.position(|&el| el == 0), Some(min));
+
+        let m = CircularArrayVec::from_iter([2, 2], [f32::NAN; 4].into_iter());
+        assert_eq!(m.argmax(), None);
+        assert_eq!(m.argmin(), None);
+    }
+
+    #[test]
+    fn position() {
+        let m = CircularArrayVec::from_iter_offset([4, 3, 2], [1, 2, 1], 0..24);
+
+        for (index, el) in m.indexed_iter() {
+            assert_eq!(m.position(|other| other == el), Some(index));
+        }
+        assert_eq!(m.position(|el| *el == 24), None);
+    }
+
+    #[test]
+    fn binary_search_axis() {
+        let shape = [4, 3, 2];
+        let mut m = CircularArrayVec::from_iter(shape, (0..24).map(|i| i * 2));
+        m.push_front(1, &(0..8).map(|i| 100 + i * 2).collect::<Vec<_>>());
+
+        for (index, el) in m.indexed_iter() {
+            for axis in 0..3 {
+                let mut lane = index;
+                lane[axis] = usize::MAX;
+                assert_eq!(m.binary_search_axis(axis, lane, el), Ok(index[axis]));
+                assert_eq!(
+                    m.binary_search_axis(axis, lane, &(el + 1)),
+                    Err(index[axis] + 1)
+                );
+                assert_eq!(
+                    m.partition_point_axis(axis, lane, |other| other <= el),
+                    index[axis] + 1
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn binary_search_axis_lane() {
+        let m = CircularArrayVec::from_iter([3, 3], 0..9);
+        let _ = m.binary_search_axis(0, [0, 3], &4);
+    }
+}
@@ -0,0 +1,78 @@
+use crate::array_iter::iter_indices;
+use crate::CircularArray;
+
+impl<const N: usize, A: AsRef<[T]>, T> CircularArray<N, A, T> {
+    /// Iterate over the elements at the Cartesian product of the given `indices`
+    /// of each axis, aligned to the offset. Indices are visited in the order
+    /// given, and may repeat. Axis `0` varies fastest.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an index is out of bounds.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::CircularArray;
+    /// let array = CircularArray::new_offset([4, 3], [1, 0], vec![
+    ///      3,  0,  1,  2,
+    ///      7,  4,  5,  6,
+    ///     11,  8,  9, 10,
+    /// ]);
+    ///
+    /// assert_eq!(array.iter_select([&[3, 0], &[2, 0]]).cloned().collect::<Vec<_>>(), &[
+    ///     11, 8,
+    ///      3, 0,
+    /// ]);
+    /// ```
+    pub fn iter_select<'a>(
+        &'a self,
+        indices: [&'a [usize]; N],
+    ) -> impl ExactSizeIterator<Item = &'a T> + 'a {
+        for (i, axis_indices) in indices.iter().enumerate() {
+            for index in axis_indices.iter() {
+                assert_slice_index!(self, i, *index);
+            }
+        }
+
+        iter_indices(indices.map(<[usize]>::len)).map(move |index| {
+            let raw: [usize; N] =
+                std::array::from_fn(|i| (indices[i][index[i]] + self.offset[i]) % self.shape[i]);
+            &self.array.as_ref()[self.strides.offset_index(raw)]
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CircularArrayVec, CircularIndex};
+
+    #[test]
+    fn iter_select() {
+        let shape = [4, 3, 2];
+        let m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..shape.iter().product());
+        let indices: [&[usize]; 3] = [&[3, 0, 3], &[1], &[1, 0]];
+
+        let selected = m.iter_select(indices);
+        assert_eq!(selected.len(), 6);
+        assert!(selected.eq([
+            m.get([3, 1, 1]),
+            m.get([0, 1, 1]),
+            m.get([3, 1, 1]),
+            m.get([3, 1, 0]),
+            m.get([0, 1, 0]),
+            m.get([3, 1, 0]),
+        ]));
+
+        assert!(m
+            .iter_select([&[0, 1, 2, 3], &[0, 1, 2], &[0, 1]])
+            .eq(m.iter()));
+        assert_eq!(m.iter_select([&[], &[0], &[0]]).len(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn iter_select_out_of_bounds() {
+        let m = CircularArrayVec::from_iter([3, 3], 0..9);
+        let _ = m.iter_select([&[0], &[3]]);
+    }
+}
@@ -0,0 +1,350 @@
+use std::array;
+use std::ops::Range;
+
+use crate::array_mut::CircularMut;
+use crate::CircularArray;
+
+/// A set of `K` equally shaped [`CircularArray`]s sharing a single offset, such as
+/// the fields of a simulation.
+///
+/// Arrays are pushed and translated jointly, such that the elements of each
+/// array at any index remain in phase. Each operation is validated against every
+/// array before any array is mutated. Arrays may be read by reference, and their
+/// elements mutated in place, but never pushed or translated individually.
+pub struct CircularArraySet<const N: usize, const K: usize, A, T> {
+    /// The arrays of the set.
+    arrays: [CircularArray<N, A, T>; K],
+}
+
+impl<const N: usize, const K: usize, A, T> CircularArraySet<N, K, A, T>
+where
+    A: AsRef<[T]> + AsMut<[T]>,
+    T: Clone,
+{
+    /// Create a new `CircularArraySet` of the given `arrays`. The shape and offset
+    /// of each array **must** match.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularArraySet, CircularIndex};
+    /// let position = CircularArray::new([3], vec![0.0, 1.0, 2.0]);
+    /// let velocity = CircularArray::new([3], vec![0.5, 0.5, 0.5]);
+    /// let mut fields = CircularArraySet::new([position, velocity]);
+    ///
+    /// fields.push_front(0, [&[3.0], &[-0.5]]);
+    ///
+    /// assert_eq!(fields.offset(), &[1]);
+    /// assert_eq!(fields.array(0).iter().cloned().collect::<Vec<_>>(), &[1.0, 2.0, 3.0]);
+    /// assert_eq!(fields.array(1).iter().cloned().collect::<Vec<_>>(), &[0.5, 0.5, -0.5]);
+    /// ```
+    pub fn new(arrays: [CircularArray<N, A, T>; K]) -> Self {
+        const { assert!(K > 0, "set must contain at least one array") };
+
+        for array in &arrays[1..] {
+            assert!(
+                array.shape == arrays[0].shape,
+                "shape {:?} does not match shape {:?}",
+                array.shape,
+                arrays[0].shape
+            );
+            assert!(
+                array.offset == arrays[0].offset,
+                "offset {:?} does not match offset {:?}",
+                array.offset,
+                arrays[0].offset
+            );
+        }
+
+        CircularArraySet { arrays }
+    }
+
+    /// Get the shape shared by each array.
+    pub fn shape(&self) -> &[usize; N] {
+        &self.arrays[0].shape
+    }
+
+    /// Get the offset shared by each array.
+    pub fn offset(&self) -> &[usize; N] {
+        &self.arrays[0].offset
+    }
+
+    /// Get a reference to the array `k`.
+    pub fn array(&self, k: usize) -> &CircularArray<N, A, T> {
+        &self.arrays[k]
+    }
+
+    /// Get a reference to each array.
+    pub fn arrays(&self) -> &[CircularArray<N, A, T>; K] {
+        &self.arrays
+    }
+
+    /// Get a mutable reference to the element at the given index of the array `k`,
+    /// aligned to the offset. See [`CircularMut::get_mut`].
+    pub fn get_mut(&mut self, k: usize, index: [usize; N]) -> &mut T {
+        self.arrays[k].get_mut(index)
+    }
+
+    /// Iterate mutably over all elements of the array `k` together with their
+    /// index, aligned to the offset. See [`CircularArray::indexed_iter_mut`].
+    ///
+    /// Elements are borrowed rather than the array, such that the offset shared by
+    /// the set may not be changed.
+    pub fn indexed_iter_mut(
+        &mut self,
+        k: usize,
+    ) -> impl ExactSizeIterator<Item = ([usize; N], &mut T)> {
+        self.arrays[k].indexed_iter_mut()
+    }
+
+    /// Push the elements `el[k]` to the front of the given `axis` of each array
+    /// `k`. See [`CircularMut::push_front`].
+    pub fn push_front(&mut self, axis: usize, el: [&[T]; K]) {
+        self.assert_push(axis, &el);
+        self.arrays
+            .iter_mut()
+            .zip(el)
+            .for_each(|(array, el)| array.push_front(axis, el));
+    }
+
+    /// Push the elements `el[k]` to the back of the given `axis` of each array
+    /// `k`. See [`CircularMut::push_back`].
+    pub fn push_back(&mut self, axis: usize, el: [&[T]; K]) {
+        self.assert_push(axis, &el);
+        self.arrays
+            .iter_mut()
+            .zip(el)
+            .for_each(|(array, el)| array.push_back(axis, el));
+    }
+
+    /// Translate each array by `n` on the given `axis`, inserting elements to the
+    /// front of each array. `el_fn` is passed the array `k` and the index range of
+    /// the source of array `k`. See [`CircularMut::translate_front`].
+    ///
+    /// The sources of every array are requested and validated before any array is
+    /// translated, such that a panic leaves each array unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularArraySet, CircularIndex};
+    /// let sources = [(0..8).collect::<Vec<_>>(), (10..18).collect::<Vec<_>>()];
+    /// let arrays = [
+    ///     CircularArray::new([3], vec![0, 1, 2]),
+    ///     CircularArray::new([3], vec![10, 11, 12]),
+    /// ];
+    /// let mut fields = CircularArraySet::new(arrays);
+    ///
+    /// fields.translate_front(0, 2, [0], |k, [x]| &sources[k][x]);
+    ///
+    /// assert_eq!(fields.array(0).iter().cloned().collect::<Vec<_>>(), &[2, 3, 4]);
+    /// assert_eq!(fields.array(1).iter().cloned().collect::<Vec<_>>(), &[12, 13, 14]);
+    /// ```
+    pub fn translate_front<'b, F>(&mut self, axis: usize, n: usize, origin: [usize; N], el_fn: F)
+    where
+        T: 'b,
+        F: FnMut(usize, [Range<usize>; N]) -> &'b [T],
+    {
+        assert_shape_index!(axis, N);
+        let sources = self.translate_sources(axis, n, origin, true, el_fn);
+
+        for (array, sources) in self.arrays.iter_mut().zip(sources) {
+            let mut sources = sources.into_iter();
+            array.translate_front(axis, n, origin, |_| sources.next().unwrap());
+        }
+    }
+
+    /// Translate each array by `-n` on the given `axis`, inserting elements to the
+    /// back of each array. `el_fn` is passed the array `k` and the index range of
+    /// the source of array `k`. See [`CircularMut::translate_back`] and
+    /// [`CircularArraySet::translate_front`].
+    pub fn translate_back<'b, F>(&mut self, axis: usize, n: usize, origin: [usize; N], el_fn: F)
+    where
+        T: 'b,
+        F: FnMut(usize, [Range<usize>; N]) -> &'b [T],
+    {
+        assert_shape_index!(axis, N);
+        let sources = self.translate_sources(axis, n, origin, false, el_fn);
+
+        for (array, sources) in self.arrays.iter_mut().zip(sources) {
+            let mut sources = sources.into_iter();
+            array.translate_back(axis, n, origin, |_| sources.next().unwrap());
+        }
+    }
+
+    /// Drop the set, returning the arrays.
+    pub fn into_arrays(self) -> [CircularArray<N, A, T>; K] {
+        self.arrays
+    }
+
+    /// Get the source elements of each array `k` for a translation by `n` (or `-n`
+    /// where not `front`), in the order requested by the translation. Sources are
+    /// validated before any array is translated.
+    fn translate_sources<'b, F>(
+        &self,
+        axis: usize,
+        n: usize,
+        origin: [usize; N],
+        front: bool,
+        mut el_fn: F,
+    ) -> [Vec<&'b [T]>; K]
+    where
+        F: FnMut(usize, [Range<usize>; N]) -> &'b [T],
+    {
+        // Record the ranges requested by translating an array of zero sized
+        // elements of the same shape and offset.
+        let units = vec![(); self.arrays[0].len()];
+        let mut ranges = vec![];
+        let record = |range: [Range<usize>; N]| {
+            let len = range.iter().map(|range| range.len()).product::<usize>();
+            ranges.push(range);
+            &units[..len]
+        };
+        let mut array = CircularArray::new_offset(*self.shape(), *self.offset(), units.clone());
+        match front {
+            true => array.translate_front(axis, n, origin, record),
+            false => array.translate_back(axis, n, origin, record),
+        }
+
+        array::from_fn(|k| {
+            ranges
+                .iter()
+                .map(|range| {
+                    let el = el_fn(k, range.clone());
+                    let len = range.iter().map(|range| range.len()).product::<usize>();
+                    assert!(
+                        el.len() == len,
+                        "translation of array {} expected {} elements for range {:?} (recieved {})",
+                        k,
+                        len,
+                        range,
+                        el.len()
+                    );
+                    el
+                })
+                .collect()
+        })
+    }
+
+    /// Assert the elements `el` are a valid push to `axis` of each array.
+    fn assert_push(&self, axis: usize, el: &[&[T]; K]) {
+        assert_shape_index!(axis, N);
+        let array = &self.arrays[0];
+        let slice_len = array.slice_len(axis);
+
+        let lens: [usize; K] = array::from_fn(|k| el[k].len());
+        for el_len in lens {
+            assert_element_len!(axis, el_len, slice_len);
+            assert!(
+                el_len == lens[0],
+                "operation on axis {} expected {} elements for each array (recieved {})",
+                axis,
+                lens[0],
+                el_len
+            );
+        }
+
+        let n = lens[0] / slice_len.max(1);
+        assert_slice_len!(array, axis, n);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CircularArraySet, CircularArrayVec, CircularIndex, CircularMut};
+
+    #[test]
+    fn push() {
+        let shape = [4, 3, 2];
+        let arrays =
+            [0, 100, 200].map(|i| CircularArrayVec::from_iter_offset(shape, [1, 2, 1], i..i + 24));
+        let mut expected = arrays.clone();
+        let mut set = CircularArraySet::new(arrays);
+
+        for axis in 0..3 {
+            let len = set.array(0).slice_len(axis);
+            let el = [0, 1, 2].map(|k| (0..len).map(|i| 1000 * (k + 1) + i).collect::<Vec<_>>());
+
+            set.push_front(axis, el.each_ref().map(|el| &el[..]));
+            expected
+                .iter_mut()
+                .zip(&el)
+                .for_each(|(array, el)| array.push_front(axis, el));
+            set.push_back(axis, el.each_ref().map(|el| &el[..]));
+            expected
+                .iter_mut()
+                .zip(&el)
+                .for_each(|(array, el)| array.push_back(axis, el));
+
+            for (array, expected) in set.arrays().iter().zip(&expected) {
+                assert_eq!(array.offset(), set.offset());
+                assert!(array.iter().eq(expected.iter()));
+            }
+        }
+
+        *set.get_mut(1, [3, 2, 1]) = 0;
+        assert_eq!(set.array(1).get([3, 2, 1]), &0);
+        for k in 0..3 {
+            set.indexed_iter_mut(k).for_each(|(_, el)| *el = 0);
+        }
+        assert!(set
+            .into_arrays()
+            .iter()
+            .all(|array| array.iter().all(|el| *el == 0)));
+    }
+
+    #[test]
+    fn translate() {
+        let source = (0..64).collect::<Vec<_>>();
+        let arrays = [0, 1]
+            .map(|k| CircularArrayVec::from_iter([2, 2], [0, 1, 8, 9].map(|i| i + k).into_iter()));
+        let mut set = CircularArraySet::new(arrays);
+
+        let el_fn = |k: usize, [x, y]: [std::ops::Range<usize>; 2]| {
+            let start = x.start + y.start * 8 + k;
+            &source[start..start + x.len()]
+        };
+        set.translate_front(0, 2, [0, 0], el_fn);
+        set.translate_front(1, 1, [2, 0], el_fn);
+        set.translate_back(0, 1, [2, 1], el_fn);
+        assert_eq!(set.offset(), &[1, 1]);
+
+        let [a, b] = set.into_arrays();
+        assert_eq!(a.iter().cloned().collect::<Vec<_>>(), &[9, 10, 17, 18]);
+        assert_eq!(b.iter().cloned().collect::<Vec<_>>(), &[10, 11, 18, 19]);
+    }
+
+    #[test]
+    fn translate_invalid() {
+        let source = (0..64).collect::<Vec<_>>();
+        let arrays = [0, 1].map(|_| CircularArrayVec::from_iter([2, 2], [0, 1, 8, 9].into_iter()));
+        let mut set = CircularArraySet::new(arrays.clone());
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            set.translate_front(0, 1, [0, 0], |k, [x, y]| {
+                let start = x.start + y.start * 8;
+                &source[start..start + x.len() - k]
+            });
+        }));
+
+        assert!(result.is_err());
+        for (array, expected) in set.arrays().iter().zip(&arrays) {
+            assert_eq!(array.offset(), expected.offset());
+            assert_eq!(array.data(), expected.data());
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn push_len() {
+        let arrays = [0, 1].map(|_| CircularArrayVec::from_iter([3, 3], 0..9));
+        let mut set = CircularArraySet::new(arrays);
+        set.push_front(0, [&[0, 1, 2], &[0, 1, 2, 3, 4, 5]]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn new_offset() {
+        let a = CircularArrayVec::from_iter([3, 3], 0..9);
+        let b = CircularArrayVec::from_iter_offset([3, 3], [1, 0], 0..9);
+        CircularArraySet::new([a, b]);
+    }
+}
@@ -0,0 +1,199 @@
+use std::io::{self, Read, Write};
+
+use crate::{CircularArray, CircularArrayVec};
+
+/// The magic bytes and format version of a binary snapshot.
+const MAGIC: &[u8; 8] = b"NCARRAY\x01";
+
+/// An element of a fixed size, encoded as little-endian bytes within a binary
+/// snapshot. Implemented for primitive integer and float types. See
+/// [`CircularArray::write_to`].
+pub trait SnapshotElement: Sized {
+    /// The size in bytes of an encoded element.
+    const SIZE: usize;
+
+    /// Encode the element into `buf` of length [`SnapshotElement::SIZE`].
+    fn encode(&self, buf: &mut [u8]);
+
+    /// Decode an element from `buf` of length [`SnapshotElement::SIZE`].
+    fn decode(buf: &[u8]) -> Self;
+}
+
+/// Implement [`SnapshotElement`] for primitive types with `to_le_bytes`.
+macro_rules! impl_snapshot_element {
+    ($($t:ty),+) => {
+        $(
+            impl SnapshotElement for $t {
+                const SIZE: usize = std::mem::size_of::<$t>();
+
+                fn encode(&self, buf: &mut [u8]) {
+                    buf.copy_from_slice(&self.to_le_bytes());
+                }
+
+                fn decode(buf: &[u8]) -> Self {
+                    <$t>::from_le_bytes(buf.try_into().expect("Invalid element size"))
+                }
+            }
+        )+
+    };
+}
+
+impl_snapshot_element!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
+
+impl<const N: usize, A: AsRef<[T]>, T: SnapshotElement> CircularArray<N, A, T> {
+    /// Write a binary snapshot of the array to `writer`. The snapshot holds a
+    /// versioned header (the dimensionality, element size, shape and offset),
+    /// followed by the elements in raw order. All values are little-endian.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularArrayVec, CircularIndex};
+    /// let array = CircularArray::new_offset([3, 2], [1, 0], vec![2u16, 0, 1, 5, 3, 4]);
+    /// let mut bytes = vec![];
+    /// array.write_to(&mut bytes).unwrap();
+    ///
+    /// let restored = CircularArrayVec::<2, u16>::read_from(&mut &bytes[..]).unwrap();
+    /// assert_eq!(restored.offset(), &[1, 0]);
+    /// assert_eq!(restored, array);
+    /// ```
+    pub fn write_to<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        writer.write_all(MAGIC)?;
+        writer.write_all(&(N as u32).to_le_bytes())?;
+        writer.write_all(&(T::SIZE as u32).to_le_bytes())?;
+        for value in self.shape.iter().chain(self.offset.iter()) {
+            writer.write_all(&(*value as u64).to_le_bytes())?;
+        }
+
+        let mut buf = vec![0; T::SIZE];
+        for el in self.array.as_ref() {
+            el.encode(&mut buf);
+            writer.write_all(&buf)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<const N: usize, T: SnapshotElement> CircularArrayVec<N, T> {
+    /// Read a binary snapshot written by [`CircularArray::write_to`] from `reader`,
+    /// restoring the shape, offset and elements.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`io::ErrorKind::InvalidData`] where the header
+    /// does not match the format version, dimensionality `N` or element size, or
+    /// the shape or offset is invalid. Errors of `reader` are propagated.
+    pub fn read_from<R: Read>(mut reader: R) -> io::Result<Self> {
+        let mut magic = [0; 8];
+        reader.read_exact(&mut magic)?;
+        if &magic != MAGIC {
+            return Err(invalid("unsupported snapshot header"));
+        }
+        if read_u32(&mut reader)? != N as u32 {
+            return Err(invalid("snapshot dimensionality does not match"));
+        }
+        if read_u32(&mut reader)? != T::SIZE as u32 {
+            return Err(invalid("snapshot element size does not match"));
+        }
+
+        let mut header = [[0; N]; 2];
+        for value in header.iter_mut().flatten() {
+            let mut buf = [0; 8];
+            reader.read_exact(&mut buf)?;
+            *value = usize::try_from(u64::from_le_bytes(buf))
+                .map_err(|_| invalid("snapshot shape overflows usize"))?;
+        }
+        let [shape, offset] = header;
+
+        let len = shape
+            .iter()
+            .try_fold(1usize, |acc, len| acc.checked_mul(*len))
+            .ok_or_else(|| invalid("snapshot shape overflows usize"))?;
+        if offset.iter().zip(shape).any(|(i, len)| *i >= len.max(1)) {
+            return Err(invalid("snapshot offset is out of bounds"));
+        }
+
+        let mut buf = vec![0; T::SIZE];
+        let mut array = vec![];
+        for _ in 0..len {
+            reader.read_exact(&mut buf)?;
+            array.push(T::decode(&buf));
+        }
+
+        Ok(CircularArray::new_offset(shape, offset, array))
+    }
+}
+
+/// Read a little-endian `u32` from `reader`.
+fn read_u32<R: Read>(reader: &mut R) -> io::Result<u32> {
+    let mut buf = [0; 4];
+    reader.read_exact(&mut buf)?;
+
+    Ok(u32::from_le_bytes(buf))
+}
+
+/// Create an [`io::ErrorKind::InvalidData`] error.
+fn invalid(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use crate::CircularArrayVec;
+
+    #[test]
+    fn snapshot() {
+        let shape = [4, 3, 2];
+        let m =
+            CircularArrayVec::from_iter_offset(shape, [1, 2, 1], (0..24).map(|i| i as f64 * 0.5));
+        let mut bytes = vec![];
+        m.write_to(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), 8 + 4 + 4 + 6 * 8 + 24 * 8);
+
+        let p = CircularArrayVec::<3, f64>::read_from(&bytes[..]).unwrap();
+        assert_eq!(p.offset(), m.offset());
+        assert_eq!(p.data(), m.data());
+
+        let e = CircularArrayVec::<2, u8>::from_iter([0, 2], 0..0);
+        let mut bytes = vec![];
+        e.write_to(&mut bytes).unwrap();
+        assert_eq!(
+            CircularArrayVec::<2, u8>::read_from(&bytes[..])
+                .unwrap()
+                .shape(),
+            &[0, 2]
+        );
+    }
+
+    fn kind<T>(result: io::Result<T>) -> Option<io::ErrorKind> {
+        result.err().map(|e| e.kind())
+    }
+
+    #[test]
+    fn snapshot_errors() {
+        let m = CircularArrayVec::from_iter([3, 2], 0..6u32);
+        let mut bytes = vec![];
+        m.write_to(&mut bytes).unwrap();
+
+        assert_eq!(
+            kind(CircularArrayVec::<3, u32>::read_from(&bytes[..])),
+            Some(io::ErrorKind::InvalidData)
+        );
+        assert_eq!(
+            kind(CircularArrayVec::<2, u16>::read_from(&bytes[..])),
+            Some(io::ErrorKind::InvalidData)
+        );
+        assert_eq!(
+            kind(CircularArrayVec::<2, u32>::read_from(&bytes[1..])),
+            Some(io::ErrorKind::InvalidData)
+        );
+        assert_eq!(
+            kind(CircularArrayVec::<2, u32>::read_from(
+                &bytes[..bytes.len() - 1]
+            )),
+            Some(io::ErrorKind::UnexpectedEof)
+        );
+    }
+}
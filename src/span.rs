@@ -1,10 +1,9 @@
 use std::ops::Range;
-use std::ops::{Add, Rem, Sub};
 
 /// A span of inclusive elements within an axis. In contrast to [`BoundSpan`], all
 /// elements are guaranteed to be within axis bounds.
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
-pub(crate) struct UnboundSpan {
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnboundSpan {
     /// The first element of the span.
     pub(crate) start: usize,
     /// The last element of the span.
@@ -26,10 +25,16 @@ impl UnboundSpan {
     }
 
     /// Get the number of elements within the span.
-    pub(crate) fn len(&self) -> usize {
+    pub fn len(&self) -> usize {
         self.end - self.start + 1
     }
 
+    /// Returns `false`. An `UnboundSpan` always contains at least one element.
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
     /// Get the index of the element `i` from `start`. Returns `None` if the index
     /// exceeds the `end` of the span.
     pub(crate) fn get_index(&self, i: usize) -> Option<usize> {
@@ -43,25 +48,69 @@ impl UnboundSpan {
     }
 }
 
-impl From<usize> for UnboundSpan {
-    fn from(value: usize) -> Self {
-        UnboundSpan {
-            start: value,
-            end: value,
+#[cfg_attr(not(feature = "spans"), allow(dead_code))]
+impl UnboundSpan {
+    /// Create a new `UnboundSpan` of the elements of `range`. Returns `None` if
+    /// the range is empty.
+    pub fn from_range(range: Range<usize>) -> Option<Self> {
+        match range.is_empty() {
+            true => None,
+            false => Some(UnboundSpan::new(range.start, range.end - 1)),
         }
     }
-}
 
-impl From<(usize, usize)> for UnboundSpan {
-    fn from((start, end): (usize, usize)) -> Self {
-        UnboundSpan { start, end }
+    /// Get the first element of the span.
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Get the last (inclusive) element of the span.
+    pub fn end(&self) -> usize {
+        self.end
+    }
+
+    /// Returns `true` if the span contains the element `i`.
+    pub fn contains(&self, i: usize) -> bool {
+        (self.start..=self.end).contains(&i)
+    }
+
+    /// Get the span as a `Range<usize>`.
+    pub fn to_range(self) -> Range<usize> {
+        self.into_range(0)
+    }
+
+    /// Get the elements within both spans, or `None` if the spans are disjoint.
+    pub fn intersection(&self, other: &UnboundSpan) -> Option<UnboundSpan> {
+        let start = self.start.max(other.start);
+        let end = self.end.min(other.end);
+
+        (start <= end).then(|| UnboundSpan::new(start, end))
+    }
+
+    /// Get the elements within either span, or `None` if the spans are neither
+    /// overlapping nor adjacent.
+    pub fn union(&self, other: &UnboundSpan) -> Option<UnboundSpan> {
+        let start = self.start.max(other.start);
+        let end = self.end.min(other.end);
+
+        (start <= end + 1)
+            .then(|| UnboundSpan::new(self.start.min(other.start), self.end.max(other.end)))
+    }
+
+    /// Shift the span by `n` elements, or return `None` if the span would precede
+    /// element `0`.
+    pub fn shift(&self, n: isize) -> Option<UnboundSpan> {
+        let start = self.start.checked_add_signed(n)?;
+        let end = self.end.checked_add_signed(n)?;
+
+        Some(UnboundSpan::new(start, end))
     }
 }
 
 /// A span of inclusive elements within an axis. In contrast to [`UnboundSpan`],
 /// elements may wrap across axis bounds.
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
-pub(crate) struct BoundSpan {
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BoundSpan {
     /// The start index of the span.
     start: usize,
     /// The length of the span.
@@ -81,23 +130,34 @@ impl BoundSpan {
         Self { start, bound, len }
     }
 
+    /// Get the span shifted by `n` elements, wrapping over the `bound`.
+    pub(crate) fn wrapping_add(self, n: usize) -> Self {
+        BoundSpan::new((self.start + n) % self.bound, self.len, self.bound)
+    }
+
     /// Get the length of elements within the span.
-    pub(crate) fn len(&self) -> usize {
+    pub fn len(&self) -> usize {
         self.len
     }
 
+    /// Returns `true` if the span contains no elements.
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
     /// Get the upper bound of the span.
-    pub(crate) fn bound(&self) -> usize {
+    pub fn bound(&self) -> usize {
         self.bound
     }
 
     /// Returns `true` if the span is exhaustive of the axis.
-    pub(crate) fn exhaustive(&self) -> bool {
+    pub fn exhaustive(&self) -> bool {
         self.start == 0 && self.len == self.bound
     }
 
     /// Returns `true` if the span wraps across the `bound`.
-    pub(crate) fn is_wrapping(&self) -> bool {
+    pub fn is_wrapping(&self) -> bool {
         self.start + self.len > self.bound
     }
 
@@ -145,38 +205,142 @@ impl BoundSpan {
     }
 }
 
-impl Add<usize> for BoundSpan {
-    type Output = BoundSpan;
+#[cfg_attr(not(feature = "spans"), allow(dead_code))]
+impl BoundSpan {
+    /// Create a new `BoundSpan` of `len` elements from `start`, wrapping over
+    /// `bound`. Returns `None` if `start` is not less than `bound`, or `len` is
+    /// `0` or exceeds `bound`.
+    pub fn try_new(start: usize, len: usize, bound: usize) -> Option<Self> {
+        (start < bound && len > 0 && len <= bound).then(|| BoundSpan::new(start, len, bound))
+    }
 
-    fn add(self, rhs: usize) -> Self::Output {
-        BoundSpan {
-            start: self.start + rhs,
-            len: self.len,
-            bound: self.bound,
-        }
+    /// Get the start index of the span.
+    pub fn start(&self) -> usize {
+        self.start
     }
-}
 
-impl Sub<usize> for BoundSpan {
-    type Output = BoundSpan;
+    /// Returns `true` if the span contains the element `i`.
+    pub fn contains(&self, i: usize) -> bool {
+        i < self.bound && (self.bound + i - self.start) % self.bound < self.len
+    }
 
-    fn sub(self, rhs: usize) -> Self::Output {
-        BoundSpan {
-            start: self.start - rhs,
-            len: self.len,
-            bound: self.bound,
-        }
+    /// Get the span as up to two `Range<usize>`, the range following `start`,
+    /// followed by the range wrapping the `bound`, if any.
+    pub fn to_ranges(self) -> impl Iterator<Item = Range<usize>> {
+        (0..2).map_while(move |i| self.get_span(i).map(|span| span.to_range()))
+    }
+
+    /// Shift the span by `n` elements, wrapping over the `bound`.
+    pub fn shift(&self, n: isize) -> BoundSpan {
+        let start = (self.start as isize + n).rem_euclid(self.bound as isize) as usize;
+
+        BoundSpan::new(start, self.len, self.bound)
+    }
+
+    /// Get the elements within both spans, ordered from element `0`. A pair of
+    /// wrapping spans may intersect at both ends of the axis, yielding two spans.
+    /// Spans **must** share a `bound`.
+    pub fn intersection(&self, other: &BoundSpan) -> Vec<UnboundSpan> {
+        self.assert_bound(other);
+
+        let mut spans = self
+            .to_ranges()
+            .flat_map(|a| other.to_ranges().map(move |b| (a.clone(), b)))
+            .filter_map(|(a, b)| {
+                let a = UnboundSpan::from_range(a)?;
+                a.intersection(&UnboundSpan::from_range(b)?)
+            })
+            .collect::<Vec<_>>();
+        spans.sort_by_key(|span| span.start);
+
+        spans
+    }
+
+    /// Get the elements within either span, or `None` if the spans are neither
+    /// overlapping nor adjacent. Spans **must** share a `bound`.
+    pub fn union(&self, other: &BoundSpan) -> Option<BoundSpan> {
+        self.assert_bound(other);
+
+        let union = |a: &BoundSpan, b: &BoundSpan| {
+            let rel = (a.bound + b.start - a.start) % a.bound;
+            (rel <= a.len).then(|| {
+                let len = a.len.max(rel + b.len).min(a.bound);
+                BoundSpan::new(a.start, len, a.bound)
+            })
+        };
+
+        union(self, other).or_else(|| union(other, self))
+    }
+
+    /// Assert `other` shares the `bound` of this span.
+    fn assert_bound(&self, other: &BoundSpan) {
+        assert!(
+            self.bound == other.bound,
+            "span bound {} does not match span bound {}",
+            self.bound,
+            other.bound
+        );
     }
 }
 
-impl Rem<usize> for BoundSpan {
-    type Output = BoundSpan;
+#[cfg(test)]
+mod tests {
+    use crate::span::{BoundSpan, UnboundSpan};
 
-    fn rem(self, rhs: usize) -> Self::Output {
-        BoundSpan {
-            start: self.start % rhs,
-            len: self.len,
-            bound: self.bound,
-        }
+    #[test]
+    fn unbound_span() {
+        let a = UnboundSpan::from_range(2..6).unwrap();
+        let b = UnboundSpan::from_range(4..9).unwrap();
+        let c = UnboundSpan::from_range(6..7).unwrap();
+
+        assert!(UnboundSpan::from_range(3..3).is_none());
+        assert_eq!(a.to_range(), 2..6);
+        assert_eq!(a.intersection(&b).map(|span| span.to_range()), Some(4..6));
+        assert_eq!(a.intersection(&c), None);
+        assert_eq!(a.union(&b).map(|span| span.to_range()), Some(2..9));
+        assert_eq!(a.union(&c).map(|span| span.to_range()), Some(2..7));
+        assert_eq!(a.union(&UnboundSpan::new(7, 7)), None);
+        assert_eq!(a.shift(-2).map(|span| span.to_range()), Some(0..4));
+        assert_eq!(a.shift(-3), None);
+    }
+
+    #[test]
+    fn bound_span() {
+        let a = BoundSpan::try_new(4, 4, 6).unwrap();
+        let b = BoundSpan::try_new(1, 4, 6).unwrap();
+
+        assert!(BoundSpan::try_new(6, 1, 6).is_none());
+        assert!(BoundSpan::try_new(0, 7, 6).is_none());
+        assert!(BoundSpan::try_new(0, 0, 6).is_none());
+
+        assert_eq!(a.to_ranges().collect::<Vec<_>>(), &[4..6, 0..2]);
+        assert!(b.to_ranges().eq(Some(1..5)));
+        assert_eq!(
+            (0..6).filter(|i| a.contains(*i)).collect::<Vec<_>>(),
+            &[0, 1, 4, 5]
+        );
+        assert!(a.shift(3).to_ranges().eq(Some(1..5)));
+        assert_eq!(a.shift(-5), BoundSpan::try_new(5, 4, 6).unwrap());
+
+        let ranges = |spans: Vec<UnboundSpan>| {
+            spans
+                .into_iter()
+                .map(|span| span.to_range())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(ranges(a.intersection(&b)), &[1..2, 4..5]);
+        assert_eq!(ranges(a.intersection(&a.shift(3))), &[1..2, 4..5]);
+
+        assert_eq!(a.union(&b), BoundSpan::try_new(4, 6, 6));
+        assert_eq!(
+            BoundSpan::try_new(4, 2, 6).unwrap().union(&b),
+            BoundSpan::try_new(1, 5, 6)
+        );
+        assert_eq!(
+            BoundSpan::try_new(3, 1, 6)
+                .unwrap()
+                .union(&BoundSpan::try_new(5, 2, 6).unwrap()),
+            None
+        );
     }
 }
@@ -75,7 +75,7 @@ impl SpanIterator for UnboundSpanIterator {
                     None
                 }
             }
-            false => self.span.get_index(self.i).map(|i| i.into()),
+            false => self.span.get_index(self.i).map(|i| UnboundSpan::new(i, i)),
         }
     }
 }
@@ -165,12 +165,18 @@ impl SpanIterator for BoundSpanIterator {
                 }
             }
             // Iterate over sequential indices.
-            (true, false) => self.bound_span.get_index_ordered(self.i).map(|i| i.into()),
+            (true, false) => self
+                .bound_span
+                .get_index_ordered(self.i)
+                .map(|i| UnboundSpan::new(i, i)),
 
             // Iterate over non-sequential spans.
             (false, true) => self.bound_span.get_span(self.i),
             // Iterate over non-sequential indices.
-            (false, false) => self.bound_span.get_index(self.i).map(|i| i.into()),
+            (false, false) => self
+                .bound_span
+                .get_index(self.i)
+                .map(|i| UnboundSpan::new(i, i)),
         }
     }
 }
@@ -223,7 +229,7 @@ mod tests {
 
             #[rustfmt::skip]
             assert_eq!(iter.collect::<Vec<_>>(), [
-                (1, 1).into(), (2, 2).into(), (3, 3).into()
+                UnboundSpan::new(1, 1), UnboundSpan::new(2, 2), UnboundSpan::new(3, 3)
             ]);
         }
 
@@ -231,14 +237,14 @@ mod tests {
         fn iter_span() {
             let iter = UnboundSpanIterator::new(UnboundSpan::new(1, 3), true);
 
-            assert_eq!(iter.collect::<Vec<_>>(), [(1, 3).into()]);
+            assert_eq!(iter.collect::<Vec<_>>(), [UnboundSpan::new(1, 3)]);
         }
     }
 
     #[cfg(test)]
     mod bound {
 
-        use crate::span::BoundSpan;
+        use crate::span::{BoundSpan, UnboundSpan};
         use crate::span_iter::BoundSpanIterator;
 
         #[test]
@@ -256,7 +262,7 @@ mod tests {
 
             #[rustfmt::skip]
             assert_eq!(iter.collect::<Vec<_>>(), [
-                (4, 4).into(), (5, 5).into(), (0, 0).into(), (1, 1).into(), (2, 2).into()
+                UnboundSpan::new(4, 4), UnboundSpan::new(5, 5), UnboundSpan::new(0, 0), UnboundSpan::new(1, 1), UnboundSpan::new(2, 2)
             ]);
         }
 
@@ -266,7 +272,7 @@ mod tests {
 
             #[rustfmt::skip]
             assert_eq!(iter.collect::<Vec<_>>(), [
-                (0, 0).into(), (1, 1).into(), (2, 2).into(), (4, 4).into(), (5, 5).into()
+                UnboundSpan::new(0, 0), UnboundSpan::new(1, 1), UnboundSpan::new(2, 2), UnboundSpan::new(4, 4), UnboundSpan::new(5, 5)
             ]);
         }
 
@@ -274,14 +280,20 @@ mod tests {
         fn iter_span() {
             let iter = BoundSpanIterator::new(BoundSpan::new(4, 5, 6), false, true);
 
-            assert_eq!(iter.collect::<Vec<_>>(), [(4, 5).into(), (0, 2).into()]);
+            assert_eq!(
+                iter.collect::<Vec<_>>(),
+                [UnboundSpan::new(4, 5), UnboundSpan::new(0, 2)]
+            );
         }
 
         #[test]
         fn iter_seq_span() {
             let iter = BoundSpanIterator::new(BoundSpan::new(4, 5, 6), true, true);
 
-            assert_eq!(iter.collect::<Vec<_>>(), [(0, 2).into(), (4, 5).into()]);
+            assert_eq!(
+                iter.collect::<Vec<_>>(),
+                [UnboundSpan::new(0, 2), UnboundSpan::new(4, 5)]
+            );
         }
     }
 }
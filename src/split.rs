@@ -0,0 +1,228 @@
+use std::array;
+use std::ops::Range;
+
+use crate::array_iter::{split_ranges_mut, CircularArrayIterator, CircularArrayIteratorMut};
+use crate::index::RawIndexAdaptor;
+use crate::index_iter::IndexIterator;
+use crate::span::BoundSpan;
+use crate::CircularArray;
+
+/// A mutable rectangular region of a [`CircularArray`], with logical coordinates
+/// local to the region.
+///
+/// Regions mutably borrow the contiguous runs of the parent buffer covered by the
+/// region, such that regions split from the same array are disjoint, and may be
+/// mutated independently (for example, on separate threads).
+///
+/// See [`CircularArray::split_at_axis_mut`].
+pub struct CircularArrayRegionMut<'a, const N: usize, T> {
+    /// The runs of the parent buffer, in the order of the region.
+    runs: Vec<&'a mut [T]>,
+    /// The position of the first element of each run within the region.
+    starts: Vec<usize>,
+    /// The start of the region within the parent array.
+    origin: [usize; N],
+    /// The length of elements for each axis of the region.
+    shape: [usize; N],
+}
+
+impl<'a, const N: usize, T> CircularArrayRegionMut<'a, N, T> {
+    /// Get the region shape.
+    pub fn shape(&self) -> &[usize; N] {
+        &self.shape
+    }
+
+    /// Get the start of the region within the parent array.
+    pub fn origin(&self) -> &[usize; N] {
+        &self.origin
+    }
+
+    /// Get the number of elements in the region.
+    pub fn len(&self) -> usize {
+        self.shape.iter().product()
+    }
+
+    /// Returns `true` if the region contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get a reference to the element at the given index of the region.
+    pub fn get(&self, index: [usize; N]) -> &T {
+        let (run, i) = self.run_index(index);
+        &self.runs[run][i]
+    }
+
+    /// Get a mutable reference to the element at the given index of the region.
+    pub fn get_mut(&mut self, index: [usize; N]) -> &mut T {
+        let (run, i) = self.run_index(index);
+        &mut self.runs[run][i]
+    }
+
+    /// Iterate over all elements of the region, with axis `0` the fastest varying.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &T> {
+        let iter = self.runs.iter().flat_map(|run| run.iter());
+
+        CircularArrayIterator::new(iter, self.len())
+    }
+
+    /// Iterate mutably over all elements of the region, with axis `0` the fastest
+    /// varying.
+    pub fn iter_mut(&mut self) -> impl ExactSizeIterator<Item = &mut T> + use<'_, 'a, N, T> {
+        let len = self.len();
+        let iter = self.runs.iter_mut().flat_map(|run| run.iter_mut());
+
+        CircularArrayIteratorMut::new(iter, len)
+    }
+
+    /// Get the run and position within the run of the given region `index`.
+    fn run_index(&self, index: [usize; N]) -> (usize, usize) {
+        let mut flat = 0;
+        for i in (0..N).rev() {
+            assert_slice_index!(self, i, index[i]);
+            flat = flat * self.shape[i] + index[i];
+        }
+        let run = self.starts.partition_point(|start| *start <= flat) - 1;
+
+        (run, flat - self.starts[run])
+    }
+}
+
+impl<const N: usize, A: AsRef<[T]> + AsMut<[T]>, T> CircularArray<N, A, T> {
+    /// Split the array at index `mid` of the given `axis` into two disjoint mutable
+    /// regions, of indices `0..mid` and `mid..len` of `axis` (aligned to the offset).
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let mut array = CircularArray::new_offset([3, 2], [1, 0], vec![
+    ///     2, 0, 1,
+    ///     5, 3, 4,
+    /// ]);
+    /// let (mut front, mut back) = array.split_at_axis_mut(0, 2);
+    ///
+    /// std::thread::scope(|s| {
+    ///     s.spawn(|| front.iter_mut().for_each(|el| *el *= 10));
+    ///     s.spawn(|| *back.get_mut([0, 1]) = -1);
+    /// });
+    ///
+    /// assert_eq!(array.iter().cloned().collect::<Vec<_>>(), &[
+    ///      0, 10,  2,
+    ///     30, 40, -1,
+    /// ]);
+    /// ```
+    pub fn split_at_axis_mut(
+        &mut self,
+        axis: usize,
+        mid: usize,
+    ) -> (
+        CircularArrayRegionMut<'_, N, T>,
+        CircularArrayRegionMut<'_, N, T>,
+    ) {
+        assert_shape_index!(axis, N);
+        assert_slice_len!(self, axis, mid);
+
+        let len = self.shape[axis];
+        let ranges = |range: Range<usize>| match self.is_empty() || range.is_empty() {
+            true => vec![],
+            false => {
+                let span = BoundSpan::new(range.start, range.len(), len);
+                IndexIterator::new_bound_contiguous(self.spans_axis_bound(axis, span))
+                    .into_flat_ranges(&self.strides)
+                    .collect::<Vec<_>>()
+            }
+        };
+        let (ranges_front, ranges_back) = (ranges(0..mid), ranges(mid..len));
+        let n = ranges_front.len();
+
+        let ranges = ranges_front.into_iter().chain(ranges_back);
+        let mut front = split_ranges_mut(self.array.as_mut(), ranges);
+        let back = front.split_off(n);
+
+        let mut front_shape = self.shape;
+        front_shape[axis] = mid;
+        let mut back_shape = self.shape;
+        back_shape[axis] = len - mid;
+
+        (
+            region_mut(front, [0; N], front_shape),
+            region_mut(
+                back,
+                array::from_fn(|i| if i == axis { mid } else { 0 }),
+                back_shape,
+            ),
+        )
+    }
+}
+
+/// Create a [`CircularArrayRegionMut`] of the given `runs`.
+fn region_mut<T, const N: usize>(
+    runs: Vec<&mut [T]>,
+    origin: [usize; N],
+    shape: [usize; N],
+) -> CircularArrayRegionMut<'_, N, T> {
+    let starts = runs
+        .iter()
+        .scan(0, |start, run| {
+            let i = *start;
+            *start += run.len();
+            Some(i)
+        })
+        .collect();
+
+    CircularArrayRegionMut {
+        runs,
+        starts,
+        origin,
+        shape,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CircularArrayVec, CircularIndex};
+
+    #[test]
+    fn split_at_axis_mut() {
+        let shape = [4, 3, 2];
+
+        for axis in 0..3 {
+            for mid in 0..=shape[axis] {
+                let mut m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..24);
+                let expected = m.clone();
+                let (front_el, back_el): (Vec<_>, Vec<_>) = expected
+                    .indexed_iter()
+                    .partition(|(index, _)| index[axis] < mid);
+
+                let (mut front, mut back) = m.split_at_axis_mut(axis, mid);
+                assert_eq!(front.shape()[axis], mid);
+                assert_eq!(back.origin()[axis], mid);
+                assert_eq!(front.iter().len(), front_el.len());
+                assert_eq!(back.iter().len(), back_el.len());
+                assert!(front.iter().eq(front_el.iter().map(|(_, el)| *el)));
+                assert!(back.iter().eq(back_el.iter().map(|(_, el)| *el)));
+
+                for (index, el) in &back_el {
+                    let mut local = *index;
+                    local[axis] -= mid;
+                    assert_eq!(back.get(local), *el);
+                }
+
+                front.iter_mut().for_each(|el| *el += 100);
+                back.iter_mut().for_each(|el| *el += 1000);
+
+                for (index, el) in m.indexed_iter() {
+                    let n = if index[axis] < mid { 100 } else { 1000 };
+                    assert_eq!(*el, expected.get(index) + n);
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn split_at_axis_mut_mid() {
+        let mut m = CircularArrayVec::from_iter([3, 3], 0..9);
+        let _ = m.split_at_axis_mut(1, 4);
+    }
+}
@@ -0,0 +1,106 @@
+use std::ops::{Add, Mul};
+
+use crate::array_index::CircularIndex;
+use crate::array_iter::iter_indices;
+use crate::strides::Strides;
+use crate::{Boundary, CircularArray, CircularArrayVec};
+
+impl<const N: usize, A: AsRef<[T]>, T> CircularArray<N, A, T> {
+    /// Create a new [`CircularArrayVec`] of the convolution of this array with the
+    /// given `kernel`, aligned to the offset. The origin of the kernel is
+    /// `kernel.shape()[i] / 2` for each axis. Indices beyond the bounds of the
+    /// array are resolved by `boundary`. The new array has an offset of `[0; N]`.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{Boundary, CircularArray, CircularArrayVec, CircularIndex};
+    /// let array = CircularArray::new_offset([4], [1], vec![4.0, 1.0, 2.0, 3.0]);
+    /// let kernel = CircularArrayVec::new([3], vec![0.0, 0.5, 0.5]);
+    ///
+    /// // Each element is the mean of itself and the preceding element.
+    /// assert_eq!(array.convolve(&kernel, Boundary::Wrap).data(), &[2.5, 1.5, 2.5, 3.5]);
+    /// assert_eq!(array.convolve(&kernel, Boundary::Clamp).data(), &[1.0, 1.5, 2.5, 3.5]);
+    /// assert_eq!(array.convolve(&kernel, Boundary::Constant(0.0)).data(), &[0.5, 1.5, 2.5, 3.5]);
+    /// ```
+    pub fn convolve<B: AsRef<[K]>, K>(
+        &self,
+        kernel: &CircularArray<N, B, K>,
+        boundary: Boundary<T>,
+    ) -> CircularArrayVec<N, T>
+    where
+        T: Clone + Default + Add<Output = T> + Mul<K, Output = T>,
+        K: Clone,
+    {
+        let shape = self.shape;
+        let strides = Strides::new(&shape);
+        let origin = kernel.shape.map(|len| len / 2);
+
+        // Elements and kernel weights aligned to the offset, such that the inner
+        // loop indexes contiguous memory.
+        let elements = self.iter().cloned().collect::<Vec<_>>();
+        let weights = kernel
+            .indexed_iter()
+            .map(|(index, weight)| (index, weight.clone()))
+            .collect::<Vec<_>>();
+
+        CircularArrayVec::from_iter(
+            shape,
+            iter_indices(shape).map(|index| {
+                weights.iter().fold(T::default(), |acc, (k, weight)| {
+                    let mut src = [0; N];
+                    for i in 0..N {
+                        let idx = index[i] as isize + origin[i] as isize - k[i] as isize;
+
+                        src[i] = match (&boundary, boundary.resolve(idx, shape[i])) {
+                            (_, Some(idx)) => idx,
+                            (Boundary::Constant(value), None) => {
+                                return acc + value.clone() * weight.clone();
+                            }
+                            (_, None) => unreachable!(),
+                        };
+                    }
+
+                    acc + elements[strides.offset_index(src)].clone() * weight.clone()
+                })
+            }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Boundary, CircularArrayVec, CircularIndex};
+
+    #[test]
+    fn convolve() {
+        let shape = [4, 3, 2];
+        let m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..shape.iter().product());
+        let m = m.map(|el| *el as i64);
+
+        // An identity kernel.
+        let mut kernel = CircularArrayVec::new([3, 3, 1], vec![0i64; 9]);
+        kernel.data_mut()[4] = 1;
+        for boundary in [Boundary::Wrap, Boundary::Clamp, Boundary::Constant(0)] {
+            assert_eq!(
+                m.convolve(&kernel, boundary).data(),
+                &m.iter().cloned().collect::<Vec<_>>()
+            );
+        }
+
+        // A shift of one along axis 0.
+        let kernel = CircularArrayVec::new([3, 1, 1], vec![0i64, 0, 1]);
+        let expected = |boundary: Boundary<i64>| {
+            m.indexed_iter()
+                .map(|([x, y, z], _)| match (x, boundary) {
+                    (0, Boundary::Wrap) => *m.get([3, y, z]),
+                    (0, Boundary::Clamp) => *m.get([0, y, z]),
+                    (0, Boundary::Constant(value)) => value,
+                    _ => *m.get([x - 1, y, z]),
+                })
+                .collect::<Vec<_>>()
+        };
+        for boundary in [Boundary::Wrap, Boundary::Clamp, Boundary::Constant(-1)] {
+            assert_eq!(m.convolve(&kernel, boundary).data(), &expected(boundary));
+        }
+    }
+}
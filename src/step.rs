@@ -0,0 +1,94 @@
+use std::ops::Range;
+
+use crate::array_iter::iter_indices;
+use crate::CircularArray;
+
+impl<const N: usize, A: AsRef<[T]>, T> CircularArray<N, A, T> {
+    /// Iterate over every `step[i]`th element of each axis `i` within the given
+    /// `slice`, aligned to the offset. Each axis is sampled from the start of its
+    /// range. A `step` of `[1; N]` is equivalent to
+    /// [`CircularIndex::iter_slice`](crate::CircularIndex::iter_slice).
+    ///
+    /// # Panics
+    ///
+    /// Panics if a range is out of bounds, or a step is `0`.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::CircularArray;
+    /// let array = CircularArray::new_offset([4, 3], [1, 0], vec![
+    ///      3,  0,  1,  2,
+    ///      7,  4,  5,  6,
+    ///     11,  8,  9, 10,
+    /// ]);
+    ///
+    /// assert_eq!(array.iter_slice_step([0..4, 0..3], [2, 2]).cloned().collect::<Vec<_>>(), &[
+    ///     0, 2,
+    ///     8, 10,
+    /// ]);
+    /// ```
+    pub fn iter_slice_step(
+        &self,
+        slice: [Range<usize>; N],
+        step: [usize; N],
+    ) -> impl ExactSizeIterator<Item = &T> {
+        slice.iter().enumerate().for_each(|(i, range)| {
+            assert!(
+                range.start <= range.end && range.end <= self.shape[i],
+                "range {:?} is out of bounds for axis {} of length {}",
+                range,
+                i,
+                self.shape[i]
+            );
+            assert!(step[i] > 0, "step of axis {} must be greater than 0", i);
+        });
+
+        let counts: [usize; N] = std::array::from_fn(|i| slice[i].len().div_ceil(step[i]));
+
+        iter_indices(counts).map(move |index| {
+            let raw = std::array::from_fn(|i| {
+                (slice[i].start + index[i] * step[i] + self.offset[i]) % self.shape[i]
+            });
+            &self.array.as_ref()[self.strides.offset_index(raw)]
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CircularArrayVec, CircularIndex};
+
+    #[test]
+    fn iter_slice_step() {
+        let shape = [5, 4, 3];
+        let m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..shape.iter().product());
+        let slice = [1..5, 0..3, 1..3];
+
+        assert!(m
+            .iter_slice_step(slice.clone(), [1; 3])
+            .eq(m.iter_slice(slice.clone())));
+
+        let expected = m
+            .indexed_iter()
+            .filter(|(index, _)| {
+                (1..5).contains(&index[0])
+                    && (index[0] - 1) % 3 == 0
+                    && index[1] < 3
+                    && index[1] % 2 == 0
+                    && index[2] >= 1
+            })
+            .map(|(_, el)| el);
+        let stepped = m.iter_slice_step(slice, [3, 2, 1]);
+        assert_eq!(stepped.len(), 2 * 2 * 2);
+        assert!(stepped.eq(expected));
+
+        assert_eq!(m.iter_slice_step([0..0, 0..4, 0..3], [2; 3]).len(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn iter_slice_step_zero() {
+        let m = CircularArrayVec::from_iter([3, 3], 0..9);
+        let _ = m.iter_slice_step([0..3, 0..3], [1, 0]);
+    }
+}
@@ -1,5 +1,8 @@
+use std::array;
 use std::ops::{Deref, DerefMut, Range};
 
+use crate::array_iter::iter_indices;
+
 /// The strides of an `N` dimension array.
 #[derive(Debug, Clone, Copy)]
 pub struct Strides<const N: usize>([usize; N]);
@@ -15,6 +18,46 @@ impl<const N: usize> Strides<N> {
         Strides(array)
     }
 
+    /// Flatten an `N` dimensional index into an index of the contiguous buffer.
+    #[allow(dead_code)]
+    pub fn flatten(&self, index: [usize; N]) -> usize {
+        self.offset_index(index)
+    }
+
+    /// Unflatten an index of the contiguous buffer into an `N` dimensional index,
+    /// the inverse of [`Strides::flatten`]. Strides **must** be non-zero, such
+    /// that the shape has no axis of length `0`.
+    #[allow(dead_code)]
+    pub fn unflatten(&self, mut flat: usize) -> [usize; N] {
+        let mut index = [0; N];
+        for i in (0..N).rev() {
+            index[i] = flat / self[i];
+            flat %= self[i];
+        }
+
+        index
+    }
+
+    /// Iterate over the flattened contiguous ranges of an arbitrary `N` dimensional
+    /// index range, yielding a range for each lane of axis `0` within
+    /// `index_range`, in buffer order. Yields no ranges where `index_range` is
+    /// empty. See also [`Strides::flatten_range`].
+    #[allow(dead_code)]
+    pub fn flatten_ranges(
+        &self,
+        index_range: [Range<usize>; N],
+    ) -> impl Iterator<Item = Range<usize>> + '_ {
+        let lens: [usize; N] = array::from_fn(|i| match i {
+            0 => (!index_range[0].is_empty()) as usize,
+            _ => index_range[i].len(),
+        });
+
+        iter_indices(lens).map(move |index| {
+            let start = self.offset_index(array::from_fn(|i| index_range[i].start + index[i]));
+            start..start + index_range[0].len()
+        })
+    }
+
     /// Multiply an `N` dimensional index by the strides.
     pub(crate) fn offset_index(&self, index: [usize; N]) -> usize {
         index
@@ -37,7 +80,7 @@ impl<const N: usize> Strides<N> {
         );
 
         let cont_range = std::mem::take(&mut index_range[0]);
-        let offset = self.offset_index(index_range.map(|range| range.start as usize));
+        let offset = self.offset_index(index_range.map(|range| range.start));
 
         cont_range.start as usize + offset..cont_range.end as usize + offset
     }
@@ -56,3 +99,43 @@ impl<const N: usize> DerefMut for Strides<N> {
         &mut self.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unflatten() {
+        let strides = Strides::new(&[4, 3, 2]);
+
+        for flat in 0..24 {
+            let index = strides.unflatten(flat);
+            assert!(index.iter().zip([4, 3, 2]).all(|(i, len)| *i < len));
+            assert_eq!(strides.flatten(index), flat);
+        }
+    }
+
+    #[test]
+    fn flatten_ranges() {
+        let strides = Strides::new(&[4, 3, 2]);
+
+        let ranges = strides
+            .flatten_ranges([1..3, 0..2, 1..2])
+            .collect::<Vec<_>>();
+        assert_eq!(ranges, [13..15, 17..19]);
+
+        let ranges = strides
+            .flatten_ranges([0..4, 1..3, 0..2])
+            .collect::<Vec<_>>();
+        assert_eq!(ranges, [4..8, 8..12, 16..20, 20..24]);
+        assert_eq!(
+            strides
+                .flatten_ranges([0..4, 2..3, 1..2])
+                .collect::<Vec<_>>(),
+            [strides.flatten_range([0..4, 2..3, 1..2])]
+        );
+
+        assert_eq!(strides.flatten_ranges([0..0, 0..3, 0..2]).count(), 0);
+        assert_eq!(strides.flatten_ranges([0..4, 1..1, 0..2]).count(), 0);
+    }
+}
@@ -0,0 +1,119 @@
+use std::fmt;
+
+use crate::array_index::CircularIndex;
+use crate::array_mut::CircularMut;
+use crate::CircularArray;
+
+/// The number of `char`s buffered per push when writing to a `char` array.
+const CHAR_CHUNK_LEN: usize = 32;
+
+impl<A: AsRef<[u8]> + AsMut<[u8]>> fmt::Write for CircularArray<1, A, u8> {
+    /// Push the UTF-8 bytes of `s` to the front of the array, evicting the oldest
+    /// bytes. Where `s` exceeds the length of the array, only the trailing bytes
+    /// are retained.
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let bytes = s.as_bytes();
+        let n = bytes.len().min(self.shape[0]);
+        self.push_front(0, &bytes[bytes.len() - n..]);
+
+        Ok(())
+    }
+}
+
+impl<A: AsRef<[char]> + AsMut<[char]>> fmt::Write for CircularArray<1, A, char> {
+    /// Push the `char`s of `s` to the front of the array, evicting the oldest
+    /// `char`s. Where `s` exceeds the length of the array, only the trailing
+    /// `char`s are retained.
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let count = s.chars().count();
+        let mut buffer = ['\0'; CHAR_CHUNK_LEN];
+        let mut len = 0;
+
+        for c in s.chars().skip(count.saturating_sub(self.shape[0])) {
+            buffer[len] = c;
+            len += 1;
+
+            if len == CHAR_CHUNK_LEN {
+                self.push_front(0, &buffer);
+                len = 0;
+            }
+        }
+        self.push_front(0, &buffer[..len]);
+
+        Ok(())
+    }
+}
+
+impl<A: AsRef<[u8]>> CircularArray<1, A, u8> {
+    /// Get the bytes of the array, aligned to the offset.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::fmt::Write;
+    /// # use n_circular_array::CircularArray;
+    /// let mut array = CircularArray::new([12], vec![b' '; 12]);
+    /// write!(array, "step {}; ", 1).unwrap();
+    /// write!(array, "step {}; ", 2).unwrap();
+    ///
+    /// assert_eq!(array.contents(), b" 1; step 2; ");
+    /// ```
+    pub fn contents(&self) -> Vec<u8> {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<A: AsRef<[char]>> CircularArray<1, A, char> {
+    /// Get the `char`s of the array as a `String`, aligned to the offset.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::fmt::Write;
+    /// # use n_circular_array::CircularArray;
+    /// let mut array = CircularArray::new([8], vec![' '; 8]);
+    /// write!(array, "λ = {}", 0.5).unwrap();
+    /// write!(array, ", μ = {}", 2).unwrap();
+    ///
+    /// assert_eq!(array.contents(), "5, μ = 2");
+    /// ```
+    pub fn contents(&self) -> String {
+        self.iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Write;
+
+    use crate::CircularArrayVec;
+
+    #[test]
+    fn write_bytes() {
+        let mut m = CircularArrayVec::new([16], vec![b'-'; 16]);
+        let mut expected = vec![b'-'; 16];
+
+        for i in 0..40 {
+            let line = "x".repeat(i % 20) + &i.to_string();
+            write!(m, "{}", line).unwrap();
+            expected.extend(line.bytes());
+
+            assert_eq!(m.contents(), &expected[expected.len() - 16..]);
+        }
+    }
+
+    #[test]
+    fn write_chars() {
+        let mut m = CircularArrayVec::new([40], vec!['-'; 40]);
+        let mut expected = vec!['-'; 40];
+
+        for i in 0..60 {
+            let line = "λ".repeat(i) + &i.to_string();
+            write!(m, "{}", line).unwrap();
+            expected.extend(line.chars());
+
+            assert_eq!(
+                m.contents(),
+                expected[expected.len() - 40..].iter().collect::<String>()
+            );
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use std::array;
+
+use crate::array_iter::iter_indices;
+use crate::{CircularArray, CircularArrayRegion, CircularArrayVec};
+
+impl<const N: usize, A: AsRef<[T]>, T> CircularArray<N, A, T> {
+    /// Iterate over views of the non-overlapping blocks of the given `tile` shape,
+    /// aligned to the offset. Tiles are ordered with axis `0` the fastest varying.
+    /// Where the length of an axis is not a multiple of the tile, the last tile of
+    /// the axis is truncated. Owned copies may be collected from each
+    /// [`CircularArrayRegion`], and the array restored by [`CircularArray::from_tiles`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of any axis of `tile` is `0`.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let array = CircularArray::new_offset([3, 2], [1, 0], vec![
+    ///     2, 0, 1,
+    ///     5, 3, 4,
+    /// ]);
+    /// let tiles = array
+    ///     .tiles([2, 2])
+    ///     .map(|tile| tile.iter().cloned().collect::<Vec<_>>())
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(tiles, [vec![0, 1, 3, 4], vec![2, 5]]);
+    /// ```
+    pub fn tiles(
+        &self,
+        tile: [usize; N],
+    ) -> impl ExactSizeIterator<Item = CircularArrayRegion<'_, N, T>> {
+        let grid = tile_grid(self.shape, tile);
+
+        iter_indices(grid).map(move |index| {
+            self.view_region(array::from_fn(|i| {
+                let start = index[i] * tile[i];
+                start..(start + tile[i]).min(self.shape[i])
+            }))
+        })
+    }
+}
+
+impl<const N: usize, T> CircularArray<N, Vec<T>, T> {
+    /// Create a new [`CircularArrayVec`] of the given `shape` from the elements of
+    /// `tiles`, the inverse of [`CircularArray::tiles`]. Tiles are ordered with axis
+    /// `0` the fastest varying, and the elements of each tile are ordered likewise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of any axis of `tile` is `0`, or the number of tiles
+    /// or elements of any tile does not match the `shape`.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularArrayVec, CircularIndex};
+    /// let array = CircularArrayVec::from_tiles([3, 2], [2, 2], [vec![0, 1, 3, 4], vec![2, 5]]);
+    ///
+    /// assert_eq!(array.iter().cloned().collect::<Vec<_>>(), &[
+    ///     0, 1, 2,
+    ///     3, 4, 5,
+    /// ]);
+    /// ```
+    pub fn from_tiles<I: IntoIterator<Item = T>>(
+        shape: [usize; N],
+        tile: [usize; N],
+        tiles: impl IntoIterator<Item = I>,
+    ) -> CircularArrayVec<N, T> {
+        let grid = tile_grid(shape, tile);
+        let mut tiles = tiles
+            .into_iter()
+            .map(|tile| tile.into_iter().collect::<Vec<_>>().into_iter())
+            .collect::<Vec<_>>();
+
+        assert!(
+            tiles.len() == grid.iter().product(),
+            "expected {} tiles for shape {:?} (recieved {})",
+            grid.iter().product::<usize>(),
+            shape,
+            tiles.len()
+        );
+        iter_indices(grid)
+            .zip(&tiles)
+            .enumerate()
+            .for_each(|(i, (index, elements))| {
+                let len = (0..N)
+                    .map(|j| (shape[j] - index[j] * tile[j]).min(tile[j]))
+                    .product::<usize>();
+                assert!(
+                    elements.len() == len,
+                    "tile {} of length {} does not match tile length {}",
+                    i,
+                    elements.len(),
+                    len
+                );
+            });
+
+        // Elements of each tile are visited in order when iterating the array.
+        let mut strides = [1; N];
+        for i in 1..N {
+            strides[i] = strides[i - 1] * grid[i - 1];
+        }
+        let array = iter_indices(shape)
+            .map(|index| {
+                let i = (0..N)
+                    .map(|j| index[j] / tile[j] * strides[j])
+                    .sum::<usize>();
+                tiles[i].next().expect("Misaligned tile elements")
+            })
+            .collect();
+
+        CircularArray::new(shape, array)
+    }
+}
+
+/// Get the number of tiles of each axis of an array of the given `shape`.
+fn tile_grid<const N: usize>(shape: [usize; N], tile: [usize; N]) -> [usize; N] {
+    assert!(
+        tile.iter().all(|len| *len != 0),
+        "tile shape {:?} must be greater than 0 for each axis",
+        tile
+    );
+
+    array::from_fn(|i| shape[i].div_ceil(tile[i]))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CircularArrayVec, CircularIndex};
+
+    #[test]
+    fn tiles() {
+        let shape = [5, 3, 4];
+        let m = CircularArrayVec::from_iter_offset(shape, [1, 2, 3], 0..60);
+
+        for tile in [[1, 1, 1], [2, 2, 2], [5, 3, 4], [3, 2, 3], [8, 8, 8]] {
+            let tiles = m
+                .tiles(tile)
+                .map(|tile| tile.iter().cloned().collect::<Vec<_>>())
+                .collect::<Vec<_>>();
+            assert_eq!(tiles.iter().map(Vec::len).sum::<usize>(), 60);
+
+            let p = CircularArrayVec::from_tiles(shape, tile, tiles);
+            assert!(p.iter().eq(m.iter()));
+        }
+
+        let tiles = m.tiles([2, 2, 4]).collect::<Vec<_>>();
+        assert_eq!(tiles.len(), 6);
+        assert_eq!(tiles[5].shape(), &[1, 1, 4]);
+        assert_eq!(tiles[5].get([0, 0, 1]), m.get([4, 2, 1]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_tiles_len() {
+        let _ = CircularArrayVec::from_tiles([3, 2], [2, 2], [vec![0, 1, 3, 4], vec![2]]);
+    }
+}
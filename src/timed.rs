@@ -0,0 +1,200 @@
+use std::collections::VecDeque;
+use std::ops::Range;
+
+use crate::array_mut::CircularMut;
+use crate::CircularArray;
+
+/// A [`CircularArray`] associating a strictly increasing timestamp `S` with each
+/// slice pushed to the front of a chosen axis, for querying slices by time rather
+/// than by index.
+///
+/// Timestamps are retained for the slices still within the array, from oldest to
+/// newest. Queries return indices of the axis aligned to the offset, for use with
+/// [`CircularIndex::iter_index`](crate::CircularIndex::iter_index) and
+/// [`CircularIndex::iter_range`](crate::CircularIndex::iter_range). Slices present
+/// before the first push have no timestamp, and are never matched by a query.
+pub struct TimedAxis<const N: usize, A, T, S> {
+    /// The array.
+    array: CircularArray<N, A, T>,
+    /// The timed axis.
+    axis: usize,
+    /// The timestamp of the newest slices of the axis, from oldest to newest.
+    times: VecDeque<S>,
+}
+
+impl<const N: usize, A, T, S> TimedAxis<N, A, T, S>
+where
+    A: AsRef<[T]> + AsMut<[T]>,
+    T: Clone,
+    S: PartialOrd + Copy,
+{
+    /// Create a new `TimedAxis` timing slices of the given `axis` of `array`.
+    /// Existing slices have no timestamp.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex, TimedAxis};
+    /// let array = CircularArray::new([2, 3], vec![0; 6]);
+    /// let mut timed = TimedAxis::new(array, 1);
+    ///
+    /// timed.push(10.0, &[1, 1]);
+    /// timed.push(20.0, &[2, 2]);
+    /// timed.push(35.0, &[3, 3]);
+    ///
+    /// assert_eq!(timed.latest(), Some(35.0));
+    /// assert_eq!(timed.slice_at(25.0), Some(1));
+    /// assert_eq!(timed.slice_at(5.0), None);
+    /// assert_eq!(timed.range_between(15.0, 40.0), 1..3);
+    ///
+    /// let i = timed.slice_at(25.0).unwrap();
+    /// assert_eq!(timed.array().iter_index(1, i).cloned().collect::<Vec<_>>(), &[2, 2]);
+    /// ```
+    pub fn new(array: CircularArray<N, A, T>, axis: usize) -> Self {
+        assert_shape_index!(axis, N);
+
+        TimedAxis {
+            array,
+            axis,
+            times: VecDeque::new(),
+        }
+    }
+
+    /// Get the timed axis.
+    pub fn axis(&self) -> usize {
+        self.axis
+    }
+
+    /// Get a reference to the array.
+    pub fn array(&self) -> &CircularArray<N, A, T> {
+        &self.array
+    }
+
+    /// Drop the timestamps, returning the array.
+    pub fn into_array(self) -> CircularArray<N, A, T> {
+        self.array
+    }
+
+    /// Push a single slice `el` to the front of the timed axis with timestamp `t`,
+    /// evicting the oldest slice. `t` **must** be greater than the latest timestamp.
+    pub fn push(&mut self, t: S, el: &[T]) {
+        let axis = self.axis;
+        let el_len = el.len();
+        let slice_len = self.array.slice_len(axis);
+        assert!(
+            el_len == slice_len,
+            "operation on axis {} expected a single slice of {} elements (recieved {})",
+            axis,
+            slice_len,
+            el_len
+        );
+        if let Some(latest) = self.times.back() {
+            assert!(
+                t > *latest,
+                "timestamp must be greater than the latest timestamp"
+            );
+        }
+
+        self.array.push_front(axis, el);
+        self.times.push_back(t);
+        if self.times.len() > self.array.shape[axis] {
+            self.times.pop_front();
+        }
+    }
+
+    /// Get the timestamp of the newest slice, or `None` if no slice is timed.
+    pub fn latest(&self) -> Option<S> {
+        self.times.back().copied()
+    }
+
+    /// Get the timestamp of the slice at `index` of the timed axis, aligned to the
+    /// offset, or `None` if the slice is not timed.
+    pub fn timestamp(&self, index: usize) -> Option<S> {
+        let (array, axis) = (&self.array, self.axis);
+        assert_slice_index!(array, axis, index);
+
+        index.checked_sub(self.untimed()).map(|i| self.times[i])
+    }
+
+    /// Iterate over the timestamps of the timed slices, from oldest to newest.
+    pub fn timestamps(&self) -> impl ExactSizeIterator<Item = &S> {
+        self.times.iter()
+    }
+
+    /// Get the index of the slice current at time `t`, being the newest slice with
+    /// a timestamp of at most `t`, or `None` if `t` precedes every timestamp.
+    pub fn slice_at(&self, t: S) -> Option<usize> {
+        match self.times.partition_point(|s| *s <= t) {
+            0 => None,
+            i => Some(self.untimed() + i - 1),
+        }
+    }
+
+    /// Get the range of indices of the slices with a timestamp within `t0..t1`.
+    /// The range is empty where no timestamp is within `t0..t1`.
+    pub fn range_between(&self, t0: S, t1: S) -> Range<usize> {
+        let start = self.times.partition_point(|s| *s < t0);
+        let end = self.times.partition_point(|s| *s < t1).max(start);
+
+        self.untimed() + start..self.untimed() + end
+    }
+
+    /// Get the number of slices without a timestamp.
+    fn untimed(&self) -> usize {
+        self.array.shape[self.axis] - self.times.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CircularArrayVec, CircularIndex, TimedAxis};
+
+    #[test]
+    fn timed() {
+        let array = CircularArrayVec::from_iter_offset([3, 4, 2], [1, 2, 0], 0..24);
+        let mut timed = TimedAxis::new(array, 1);
+        assert_eq!(timed.latest(), None);
+        assert_eq!(timed.slice_at(u64::MAX), None);
+        assert_eq!(timed.range_between(0, u64::MAX), 4..4);
+
+        for t in 1..=6 {
+            let el = vec![t as usize; 6];
+            timed.push(t * 10, &el);
+        }
+
+        assert_eq!(timed.latest(), Some(60));
+        assert_eq!(
+            timed.timestamps().copied().collect::<Vec<_>>(),
+            &[30, 40, 50, 60]
+        );
+        assert_eq!(timed.timestamp(0), Some(30));
+        assert_eq!(timed.slice_at(29), None);
+        assert_eq!(timed.slice_at(30), Some(0));
+        assert_eq!(timed.slice_at(55), Some(2));
+        assert_eq!(timed.slice_at(100), Some(3));
+        assert_eq!(timed.range_between(35, 60), 1..3);
+        assert_eq!(timed.range_between(60, 35), 3..3);
+
+        let i = timed.slice_at(45).unwrap();
+        assert!(timed.array().iter_index(1, i).all(|el| *el == 4));
+    }
+
+    #[test]
+    fn timed_untimed() {
+        let array = CircularArrayVec::from_iter([2, 3], 0..6);
+        let mut timed = TimedAxis::new(array, 1);
+        timed.push(1.0, &[6, 7]);
+
+        assert_eq!(timed.timestamp(1), None);
+        assert_eq!(timed.timestamp(2), Some(1.0));
+        assert_eq!(timed.range_between(f64::MIN, f64::MAX), 2..3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn timed_monotonic() {
+        let array = CircularArrayVec::from_iter([2, 3], 0..6);
+        let mut timed = TimedAxis::new(array, 1);
+        timed.push(2, &[6, 7]);
+        timed.push(2, &[8, 9]);
+    }
+}
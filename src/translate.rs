@@ -0,0 +1,929 @@
+use std::array;
+use std::cmp::Ordering;
+use std::ops::Range;
+
+use crate::array_iter::iter_indices;
+use crate::index::RawIndexAdaptor;
+use crate::index_iter::IndexIterator;
+use crate::span::{BoundSpan, UnboundSpan};
+use crate::{Boundary, CircularArray, CircularIndex, CircularMut};
+
+impl<const N: usize, A: AsRef<[T]> + AsMut<[T]>, T: Clone> CircularArray<N, A, T> {
+    /// Translate the array by `delta` on every axis in a single pass. Elements
+    /// evicted by the translation are replaced with elements retrieved from the
+    /// given `el_fn`, as per [`CircularMut::translate_front`] and
+    /// [`CircularMut::translate_back`].
+    ///
+    /// Unlike translating each axis in turn, elements ingested by more than one
+    /// axis (the corner of a diagonal translation) are retrieved only once. The
+    /// ingested region is split into one disjoint block per translated axis, each
+    /// passed to `el_fn` as contiguous axis `0` ranges of the *source* array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a negative translation exceeds the `origin` of its axis.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::ops::Range;
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// // A [4, 4] source array.
+    /// let src = [
+    ///      0,  1,  2,  3,
+    ///      4,  5,  6,  7,
+    ///      8,  9, 10, 11,
+    ///     12, 13, 14, 15,
+    /// ];
+    /// let el_fn = |index: [Range<usize>; 2]| {
+    ///     &src[index[1].start * 4 + index[0].start..index[1].start * 4 + index[0].end]
+    /// };
+    ///
+    /// // A [2, 2] circular array positioned at `[1, 2]`.
+    /// let mut dst = CircularArray::new([2, 2], vec![
+    ///      9, 10,
+    ///     13, 14,
+    /// ]);
+    ///
+    /// // Translate diagonally by 1 on axis 0 and -1 on axis 1.
+    /// dst.translate([1, -1], [1, 2], el_fn);
+    ///
+    /// assert_eq!(dst.iter().cloned().collect::<Vec<usize>>(), &[
+    ///      6,  7,
+    ///     10, 11,
+    /// ]);
+    /// ```
+    pub fn translate<'b, F>(&mut self, delta: [isize; N], origin: [usize; N], mut el_fn: F)
+    where
+        T: 'b,
+        F: FnMut([Range<usize>; N]) -> &'b [T],
+    {
+        let origin = translate_origin(origin, delta);
+        self.translate_offset(delta);
+
+        // Ingested by `delta` is equivalent to evicted by `-delta`.
+        for block in blocks(self.shape, delta.map(|d| d.saturating_neg())) {
+            let src = IndexIterator::new_unbound(unbound_spans(&block));
+            let dst = IndexIterator::new_bound(self.block_spans(&block));
+
+            self.translate_spans(src, dst, origin, &mut el_fn);
+        }
+    }
+
+    /// Translate the array by `delta` on every axis, as per [`CircularArray::translate`],
+    /// where retrieving elements may fail. All elements are retrieved from `el_fn`
+    /// prior to modifying the array, such that on error the array is unchanged.
+    /// Closures returning `Option` may be adapted with [`Option::ok_or`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error returned by `el_fn`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a negative translation exceeds the `origin` of its axis.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::ops::Range;
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// // A source of 4 loaded elements.
+    /// let src = [0, 1, 2, 3];
+    /// let el_fn = |index: [Range<usize>; 1]| src.get(index[0].clone()).ok_or("not loaded");
+    ///
+    /// let mut dst = CircularArray::new([2], vec![1, 2]);
+    ///
+    /// assert_eq!(dst.try_translate([1], [1], el_fn), Ok(()));
+    /// assert_eq!(dst.try_translate([2], [2], el_fn), Err("not loaded"));
+    /// assert_eq!(dst.iter().cloned().collect::<Vec<_>>(), &[2, 3]);
+    /// ```
+    pub fn try_translate<'b, F, E>(
+        &mut self,
+        delta: [isize; N],
+        origin: [usize; N],
+        el_fn: F,
+    ) -> Result<(), E>
+    where
+        T: 'b,
+        F: FnMut([Range<usize>; N]) -> Result<&'b [T], E>,
+    {
+        let translated = translate_origin(origin, delta);
+        let slices = blocks(self.shape, delta.map(|d| d.saturating_neg()))
+            .flat_map(|block| {
+                IndexIterator::new_unbound(unbound_spans(&block)).into_ranges(translated)
+            })
+            .map(el_fn)
+            .collect::<Result<Vec<_>, E>>()?;
+
+        let mut slices = slices.into_iter();
+        self.translate(delta, origin, |_| {
+            slices.next().expect("Misaligned src ranges")
+        });
+
+        Ok(())
+    }
+
+    /// Translate the array by `n` on the given `axis`, inserting elements to the
+    /// **front** of the array, as per [`CircularMut::translate_front`], where
+    /// retrieving elements may fail. See [`CircularArray::try_translate`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error returned by `el_fn`, leaving the array unchanged.
+    pub fn try_translate_front<'b, F, E>(
+        &mut self,
+        axis: usize,
+        n: usize,
+        origin: [usize; N],
+        el_fn: F,
+    ) -> Result<(), E>
+    where
+        T: 'b,
+        F: FnMut([Range<usize>; N]) -> Result<&'b [T], E>,
+    {
+        assert_shape_index!(axis, N);
+        let delta = array::from_fn(|i| if i == axis { n as isize } else { 0 });
+
+        self.try_translate(delta, origin, el_fn)
+    }
+
+    /// Translate the array by `-n` on the given `axis`, inserting elements to the
+    /// **back** of the array, as per [`CircularMut::translate_back`], where
+    /// retrieving elements may fail. See [`CircularArray::try_translate`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error returned by `el_fn`, leaving the array unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the translation exceeds the `origin` of the `axis`.
+    pub fn try_translate_back<'b, F, E>(
+        &mut self,
+        axis: usize,
+        n: usize,
+        origin: [usize; N],
+        el_fn: F,
+    ) -> Result<(), E>
+    where
+        T: 'b,
+        F: FnMut([Range<usize>; N]) -> Result<&'b [T], E>,
+    {
+        assert_shape_index!(axis, N);
+        let delta = array::from_fn(|i| {
+            if i == axis {
+                (n as isize).wrapping_neg()
+            } else {
+                0
+            }
+        });
+
+        self.try_translate(delta, origin, el_fn)
+    }
+
+    /// Translate the array by `n` on the given `axis`, inserting elements to the
+    /// **front** of the array where `n` is positive, or the **back** where `n` is
+    /// negative. Equivalent to [`CircularMut::translate_front`] and
+    /// [`CircularMut::translate_back`], with the given `origin` updated to the
+    /// translated position of the array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a negative translation exceeds the `origin` of the `axis`.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::ops::Range;
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// // A [4, 4] source array.
+    /// let src = [
+    ///      0,  1,  2,  3,
+    ///      4,  5,  6,  7,
+    ///      8,  9, 10, 11,
+    ///     12, 13, 14, 15,
+    /// ];
+    /// let el_fn = |index: [Range<usize>; 2]| {
+    ///     &src[index[1].start * 4 + index[0].start..index[1].start * 4 + index[0].end]
+    /// };
+    ///
+    /// // A [2, 2] circular array positioned at `[1, 2]`.
+    /// let mut origin = [1, 2];
+    /// let mut dst = CircularArray::new([2, 2], vec![
+    ///      9, 10,
+    ///     13, 14,
+    /// ]);
+    ///
+    /// dst.translate_axis(0, 1, &mut origin, el_fn);
+    /// dst.translate_axis(1, -2, &mut origin, el_fn);
+    ///
+    /// assert_eq!(origin, [2, 0]);
+    /// assert_eq!(dst.iter().cloned().collect::<Vec<usize>>(), &[
+    ///     2, 3,
+    ///     6, 7,
+    /// ]);
+    /// ```
+    pub fn translate_axis<'b, F>(
+        &mut self,
+        axis: usize,
+        n: isize,
+        origin: &mut [usize; N],
+        el_fn: F,
+    ) where
+        T: 'b,
+        F: FnMut([Range<usize>; N]) -> &'b [T],
+    {
+        let len = n.unsigned_abs();
+        match n < 0 {
+            true => {
+                self.translate_back(axis, len, *origin, el_fn);
+                origin[axis] -= len;
+            }
+            false => {
+                self.translate_front(axis, len, *origin, el_fn);
+                origin[axis] += len;
+            }
+        }
+    }
+
+    /// Translate the array by `delta` on every axis, as per [`CircularArray::translate`],
+    /// first passing each evicted block to `on_evict`. The block is given as the
+    /// ranges of the *source* array relative to `origin`, alongside an iterator of
+    /// its elements, aligned to the offset. This allows edits to the outgoing
+    /// region to be written back to the source.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a negative translation exceeds the `origin` of its axis.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::ops::Range;
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let src = [0, 1, 2, 3, 4, 5];
+    /// let el_fn = |index: [Range<usize>; 1]| &src[index[0].clone()];
+    ///
+    /// // A [3] circular array positioned at `[1]`.
+    /// let mut dst = CircularArray::new([3], vec![1, 2, 3]);
+    /// let mut evicted = vec![];
+    ///
+    /// dst.translate_evict([2], [1], el_fn, |ranges, iter| {
+    ///     evicted.push((ranges, iter.cloned().collect::<Vec<_>>()));
+    /// });
+    ///
+    /// assert_eq!(evicted, [([1..3], vec![1, 2])]);
+    /// assert_eq!(dst.iter().cloned().collect::<Vec<_>>(), &[3, 4, 5]);
+    /// ```
+    pub fn translate_evict<'b, F, E>(
+        &mut self,
+        delta: [isize; N],
+        origin: [usize; N],
+        el_fn: F,
+        on_evict: E,
+    ) where
+        T: 'b,
+        F: FnMut([Range<usize>; N]) -> &'b [T],
+        E: FnMut([Range<usize>; N], &mut dyn ExactSizeIterator<Item = &T>),
+    {
+        self.evict(delta, origin, on_evict);
+        self.translate(delta, origin, el_fn);
+    }
+
+    /// Translate the array by `n` on the given `axis`, as per
+    /// [`CircularArray::translate_axis`], first passing the evicted block to
+    /// `on_evict`. See [`CircularArray::translate_evict`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if a negative translation exceeds the `origin` of the `axis`.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::ops::Range;
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let src = [0, 1, 2, 3, 4, 5];
+    /// let el_fn = |index: [Range<usize>; 1]| &src[index[0].clone()];
+    ///
+    /// // A [3] circular array positioned at `[2]`.
+    /// let mut origin = [2];
+    /// let mut dst = CircularArray::new([3], vec![2, 3, 4]);
+    /// let mut evicted = vec![];
+    ///
+    /// dst.translate_axis_evict(0, -1, &mut origin, el_fn, |ranges, iter| {
+    ///     evicted.push((ranges, iter.cloned().collect::<Vec<_>>()));
+    /// });
+    ///
+    /// assert_eq!(origin, [1]);
+    /// assert_eq!(evicted, [([4..5], vec![4])]);
+    /// assert_eq!(dst.iter().cloned().collect::<Vec<_>>(), &[1, 2, 3]);
+    /// ```
+    pub fn translate_axis_evict<'b, F, E>(
+        &mut self,
+        axis: usize,
+        n: isize,
+        origin: &mut [usize; N],
+        el_fn: F,
+        on_evict: E,
+    ) where
+        T: 'b,
+        F: FnMut([Range<usize>; N]) -> &'b [T],
+        E: FnMut([Range<usize>; N], &mut dyn ExactSizeIterator<Item = &T>),
+    {
+        assert_shape_index!(axis, N);
+        let delta = array::from_fn(|i| if i == axis { n } else { 0 });
+
+        self.evict(delta, *origin, on_evict);
+        self.translate_axis(axis, n, origin, el_fn);
+    }
+
+    /// Translate the array by `n` on the given `axis`, as per
+    /// [`CircularArray::translate_axis`], cloning the ingested elements from the
+    /// given `source` array. The `origin` is the position of the array within the
+    /// logical indices of `source`, such that the offset of `source` is handled
+    /// internally.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a negative translation exceeds the `origin` of the `axis`, or the
+    /// translated array is out of bounds of `source`.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// // A [4, 2] source array, offset by 1 on axis 0.
+    /// let src = CircularArray::new_offset([4, 2], [1, 0], vec![
+    ///     3, 0, 1, 2,
+    ///     7, 4, 5, 6,
+    /// ]);
+    ///
+    /// // A [2, 2] circular array positioned at `[0, 0]`.
+    /// let mut origin = [0, 0];
+    /// let mut dst = CircularArray::new([2, 2], vec![
+    ///     0, 1,
+    ///     4, 5,
+    /// ]);
+    ///
+    /// dst.translate_from(0, 2, &mut origin, &src);
+    ///
+    /// assert_eq!(origin, [2, 0]);
+    /// assert_eq!(dst.iter().cloned().collect::<Vec<_>>(), &[
+    ///     2, 3,
+    ///     6, 7,
+    /// ]);
+    /// ```
+    pub fn translate_from<B: AsRef<[T]>>(
+        &mut self,
+        axis: usize,
+        n: isize,
+        origin: &mut [usize; N],
+        source: &CircularArray<N, B, T>,
+    ) {
+        assert_shape_index!(axis, N);
+        let delta = array::from_fn(|i| if i == axis { n } else { 0 });
+        let translated = translate_origin(*origin, delta);
+
+        self.translate_offset(delta);
+        for block in blocks(self.shape, delta.map(|d| d.saturating_neg())) {
+            let len = block.iter().map(|range| range.len()).product();
+            let src = source.iter_slice(array::from_fn(|i| {
+                translated[i] + block[i].start..translated[i] + block[i].end
+            }));
+            let dst = IndexIterator::new_bound_contiguous(self.block_spans(&block));
+
+            self.iter_spans_mut(dst, len)
+                .zip(src)
+                .for_each(|(dst, src)| dst.clone_from(src));
+        }
+        *origin = translated;
+    }
+
+    /// Translate the array by `n` on the given `axis`, as per
+    /// [`CircularArray::translate_from`], where the signed `origin` may extend
+    /// beyond the bounds of `source`. Elements beyond the bounds of `source` are
+    /// resolved by `boundary`.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{Boundary, CircularArray, CircularIndex};
+    /// let src = CircularArray::new([3], vec![0, 1, 2]);
+    ///
+    /// // A [2] circular array positioned at `[1]`.
+    /// let mut origin = [1];
+    /// let mut dst = CircularArray::new([2], vec![1, 2]);
+    ///
+    /// dst.translate_from_bounded(0, 2, &mut origin, &src, Boundary::Clamp);
+    /// assert_eq!(dst.iter().cloned().collect::<Vec<_>>(), &[2, 2]);
+    ///
+    /// dst.translate_from_bounded(0, -4, &mut origin, &src, Boundary::Constant(9));
+    /// assert_eq!(origin, [-1]);
+    /// assert_eq!(dst.iter().cloned().collect::<Vec<_>>(), &[9, 0]);
+    ///
+    /// dst.translate_from_bounded(0, -1, &mut origin, &src, Boundary::Wrap);
+    /// assert_eq!(dst.iter().cloned().collect::<Vec<_>>(), &[1, 9]);
+    /// ```
+    pub fn translate_from_bounded<B: AsRef<[T]>>(
+        &mut self,
+        axis: usize,
+        n: isize,
+        origin: &mut [isize; N],
+        source: &CircularArray<N, B, T>,
+        boundary: Boundary<T>,
+    ) {
+        assert_shape_index!(axis, N);
+        let delta = array::from_fn(|i| if i == axis { n } else { 0 });
+        origin[axis] += n;
+
+        self.translate_offset(delta);
+        for block in blocks(self.shape, delta.map(|d| d.saturating_neg())) {
+            let lens = block.clone().map(|range| range.len());
+            let dst = IndexIterator::new_bound_contiguous(self.block_spans(&block));
+            let src = iter_indices(lens).map(|index| {
+                let mut src = [0; N];
+                for i in 0..N {
+                    let index = origin[i] + (block[i].start + index[i]) as isize;
+                    match boundary.resolve(index, source.shape[i]) {
+                        Some(index) => src[i] = index,
+                        None => return None,
+                    }
+                }
+
+                Some(source.get(src))
+            });
+
+            self.iter_spans_mut(dst, lens.iter().product())
+                .zip(src)
+                .for_each(|(dst, src)| match (src, &boundary) {
+                    (Some(src), _) => dst.clone_from(src),
+                    (None, Boundary::Constant(value)) => dst.clone_from(value),
+                    (None, _) => unreachable!(),
+                });
+        }
+    }
+
+    /// Pass each block evicted by a translation of `delta` to `on_evict`.
+    pub(crate) fn evict<E>(&self, delta: [isize; N], origin: [usize; N], mut on_evict: E)
+    where
+        E: FnMut([Range<usize>; N], &mut dyn ExactSizeIterator<Item = &T>),
+    {
+        for block in blocks(self.shape, delta) {
+            let ranges = array::from_fn(|i| origin[i] + block[i].start..origin[i] + block[i].end);
+            on_evict(ranges, &mut self.iter_slice(block));
+        }
+    }
+}
+
+impl<const N: usize, A: AsRef<[T]> + AsMut<[T]>, T> CircularArray<N, A, T> {
+    /// Translate the array by `n` on the given `axis`, inserting elements to the
+    /// **front** of the array, as per [`CircularMut::translate_front`]. Rather than
+    /// returning a slice of the source, `fill_fn` is passed each index range of the
+    /// source alongside the destination slice of equal length, allowing elements
+    /// to be generated in place. Elements do not require `Clone`.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// // A [3, 2] circular array positioned at `[0, 0]`.
+    /// let mut dst = CircularArray::new([3, 2], vec![
+    ///      0,  1,  2,
+    ///     10, 11, 12,
+    /// ]);
+    ///
+    /// // Generate elements of the value `10 * y + x`.
+    /// dst.translate_front_with(0, 2, [0, 0], |index, dst| {
+    ///     for (x, el) in index[0].clone().zip(dst) {
+    ///         *el = 10 * index[1].start + x;
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(dst.iter().cloned().collect::<Vec<_>>(), &[
+    ///      2,  3,  4,
+    ///     12, 13, 14,
+    /// ]);
+    /// ```
+    pub fn translate_front_with<F>(&mut self, axis: usize, n: usize, origin: [usize; N], fill_fn: F)
+    where
+        F: FnMut([Range<usize>; N], &mut [T]),
+    {
+        assert_shape_index!(axis, N);
+        let delta = array::from_fn(|i| if i == axis { n as isize } else { 0 });
+
+        self.translate_with(delta, origin, fill_fn);
+    }
+
+    /// Translate the array by `-n` on the given `axis`, inserting elements to the
+    /// **back** of the array, as per [`CircularMut::translate_back`]. See
+    /// [`CircularArray::translate_front_with`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the translation exceeds the `origin` of the `axis`.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// // A [3, 2] circular array positioned at `[2, 0]`.
+    /// let mut dst = CircularArray::new([3, 2], vec![
+    ///      2,  3,  4,
+    ///     12, 13, 14,
+    /// ]);
+    ///
+    /// // Generate elements of the value `10 * y + x`.
+    /// dst.translate_back_with(0, 1, [2, 0], |index, dst| {
+    ///     for (x, el) in index[0].clone().zip(dst) {
+    ///         *el = 10 * index[1].start + x;
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(dst.iter().cloned().collect::<Vec<_>>(), &[
+    ///      1,  2,  3,
+    ///     11, 12, 13,
+    /// ]);
+    /// ```
+    pub fn translate_back_with<F>(&mut self, axis: usize, n: usize, origin: [usize; N], fill_fn: F)
+    where
+        F: FnMut([Range<usize>; N], &mut [T]),
+    {
+        assert_shape_index!(axis, N);
+        let delta = array::from_fn(|i| {
+            if i == axis {
+                (n as isize).wrapping_neg()
+            } else {
+                0
+            }
+        });
+
+        self.translate_with(delta, origin, fill_fn);
+    }
+
+    /// Translate the array by `delta`, passing each ingested contiguous slice to
+    /// `fill_fn` alongside its index range within the source.
+    fn translate_with<F>(&mut self, delta: [isize; N], origin: [usize; N], mut fill_fn: F)
+    where
+        F: FnMut([Range<usize>; N], &mut [T]),
+    {
+        let origin = translate_origin(origin, delta);
+        self.translate_offset(delta);
+
+        for block in blocks(self.shape, delta.map(|d| d.saturating_neg())) {
+            let spans = self.block_spans(&block);
+            let CircularArray {
+                array,
+                strides,
+                shape,
+                offset,
+                ..
+            } = self;
+
+            for span in IndexIterator::new_bound(spans) {
+                // Raw spans do not wrap, such that the logical span is contiguous.
+                let src = array::from_fn(|i| {
+                    let start = origin[i] + (span[i].start + shape[i] - offset[i]) % shape[i];
+                    start..start + span[i].len()
+                });
+                let (start, end) = span.split_bounds();
+                let dst = strides.offset_index(*start)..strides.offset_index(*end) + 1;
+
+                fill_fn(src, &mut array.as_mut()[dst]);
+            }
+        }
+    }
+
+    /// Translate the offset by `delta` on every axis.
+    fn translate_offset(&mut self, delta: [isize; N]) {
+        for (axis, delta) in delta.iter().enumerate() {
+            let n = delta.unsigned_abs().min(self.shape[axis]);
+            match delta.signum() {
+                1 => self.incr_offset(axis, n),
+                -1 => self.decr_offset(axis, n),
+                _ => {}
+            }
+        }
+    }
+
+    /// Get the raw spans of the given `block` of logical indices.
+    fn block_spans(&self, block: &[Range<usize>; N]) -> [BoundSpan; N] {
+        array::from_fn(|i| {
+            BoundSpan::new(block[i].start, block[i].len(), self.shape[i])
+                .wrapping_add(self.offset[i])
+        })
+    }
+}
+
+/// Get the unbound spans of the given `block` of logical indices.
+fn unbound_spans<const N: usize>(block: &[Range<usize>; N]) -> [UnboundSpan; N] {
+    array::from_fn(|i| UnboundSpan::from_len(block[i].start, block[i].len()))
+}
+
+/// Get the `origin` translated by `delta`, asserting the translation is in bounds.
+fn translate_origin<const N: usize>(origin: [usize; N], delta: [isize; N]) -> [usize; N] {
+    array::from_fn(|i| {
+        origin[i].checked_add_signed(delta[i]).unwrap_or_else(|| {
+            panic!(
+                "translation {} is out of bounds for axis {}, origin {:?}",
+                delta[i], i, origin
+            )
+        })
+    })
+}
+
+/// Get the disjoint blocks of logical indices evicted from an array of the given
+/// `shape` by a translation of `delta`. Each block is retained on preceding axes,
+/// evicted on its own axis, and whole on following axes. Empty blocks are skipped.
+fn blocks<const N: usize>(
+    shape: [usize; N],
+    delta: [isize; N],
+) -> impl Iterator<Item = [Range<usize>; N]> {
+    let n: [usize; N] = array::from_fn(|i| delta[i].unsigned_abs().min(shape[i]));
+    let (evicted, retained): ([Range<usize>; N], [Range<usize>; N]) = (
+        array::from_fn(|i| match delta[i] < 0 {
+            true => shape[i] - n[i]..shape[i],
+            false => 0..n[i],
+        }),
+        array::from_fn(|i| match delta[i] < 0 {
+            true => 0..shape[i] - n[i],
+            false => n[i]..shape[i],
+        }),
+    );
+
+    (0..N)
+        .map(move |axis| {
+            array::from_fn(|i| match i.cmp(&axis) {
+                Ordering::Less => retained[i].clone(),
+                Ordering::Equal => evicted[i].clone(),
+                Ordering::Greater => 0..shape[i],
+            })
+        })
+        .filter(|block: &[Range<usize>; N]| block.iter().all(|range| !range.is_empty()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::array;
+    use std::collections::HashSet;
+    use std::ops::Range;
+
+    use crate::strides::Strides;
+    use crate::{Boundary, CircularArrayVec, CircularIndex};
+
+    /// Iterate over the indices of the box of `shape` at `origin`, axis `0` first.
+    fn iter_box(shape: [usize; 3], origin: [usize; 3]) -> impl Iterator<Item = [usize; 3]> {
+        (0..shape[2])
+            .flat_map(move |z| (0..shape[1]).map(move |y| [y, z]))
+            .flat_map(move |[y, z]| (0..shape[0]).map(move |x| [x, y, z]))
+            .map(move |index| array::from_fn(|i| origin[i] + index[i]))
+    }
+
+    /// Create an array of `shape` at `origin`, holding the flat source indices.
+    fn init(
+        shape: [usize; 3],
+        origin: [usize; 3],
+        strides: &Strides<3>,
+    ) -> CircularArrayVec<3, usize> {
+        CircularArrayVec::from_iter(
+            shape,
+            iter_box(shape, origin).map(|index| strides.offset_index(index)),
+        )
+    }
+
+    #[test]
+    fn translate() {
+        let src_shape = [9, 8, 7];
+        let src_strides = Strides::new(&src_shape);
+        let src = (0..src_shape.iter().product()).collect::<Vec<usize>>();
+        let src_fn = |idx: [Range<usize>; 3]| &src[src_strides.flatten_range(idx)];
+
+        let shape = [4, 3, 2];
+        let deltas = [
+            [1, 1, 1],
+            [-2, 1, 0],
+            [0, -3, 1],
+            [4, 2, -1],
+            [-1, 0, 0],
+            [1, -1, -2],
+            [0, 0, 0],
+        ];
+
+        let mut origin = [2, 3, 2];
+        let mut m = init(shape, origin, &src_strides);
+        let mut expected = init(shape, origin, &src_strides);
+
+        for delta in deltas {
+            m.translate(delta, origin, src_fn);
+            for (axis, d) in delta.into_iter().enumerate() {
+                expected.translate_axis(axis, d, &mut origin, src_fn);
+            }
+
+            assert!(m.iter().eq(expected.iter()));
+            assert!(m.iter().eq(init(shape, origin, &src_strides).iter()));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn translate_origin() {
+        let src = [0; 4];
+        let mut m = CircularArrayVec::from_iter([2], 0..2);
+        m.translate([-2], [1], |idx: [Range<usize>; 1]| &src[idx[0].clone()]);
+    }
+
+    #[test]
+    fn translate_axis() {
+        let src = (0..16).collect::<Vec<usize>>();
+        let src_fn = |idx: [Range<usize>; 1]| &src[idx[0].clone()];
+
+        let mut origin = [6];
+        let mut m = CircularArrayVec::from_iter([4], 6..10);
+        for n in [2, -3, 0, -5, 4, 6, -4] {
+            m.translate_axis(0, n, &mut origin, src_fn);
+            assert!(m.iter().eq(src[origin[0]..origin[0] + 4].iter()));
+        }
+        assert_eq!(origin, [6]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn translate_axis_origin() {
+        let src = [0; 4];
+        let mut m = CircularArrayVec::from_iter([2], 0..2);
+        m.translate_axis(0, -2, &mut [1], |idx: [Range<usize>; 1]| {
+            &src[idx[0].clone()]
+        });
+    }
+
+    #[test]
+    fn translate_evict() {
+        let src_shape = [9, 8, 7];
+        let src_strides = Strides::new(&src_shape);
+        let src = (0..src_shape.iter().product()).collect::<Vec<usize>>();
+        let src_fn = |idx: [Range<usize>; 3]| &src[src_strides.flatten_range(idx)];
+
+        let shape = [4, 3, 2];
+        let mut origin = [2, 3, 2];
+        let mut m = init(shape, origin, &src_strides);
+
+        for delta in [[1, 1, 1], [-2, 1, 0], [0, -3, 1], [4, 2, -1], [1, -1, -2]] {
+            let translated = array::from_fn(|i| origin[i].checked_add_signed(delta[i]).unwrap());
+            let outgoing = |index: &[usize; 3]| {
+                (0..3).any(|i| !(translated[i]..translated[i] + shape[i]).contains(&index[i]))
+            };
+
+            let mut evicted = HashSet::new();
+            m.translate_evict(delta, origin, src_fn, |ranges, iter| {
+                let lens = ranges.clone().map(|range| range.len());
+                assert_eq!(iter.len(), lens.iter().product());
+
+                for index in iter_box(lens, ranges.map(|range| range.start)) {
+                    assert!(outgoing(&index));
+                    assert_eq!(iter.next(), Some(&src_strides.offset_index(index)));
+                    assert!(evicted.insert(index));
+                }
+            });
+            assert_eq!(
+                evicted.len(),
+                iter_box(shape, origin).filter(outgoing).count()
+            );
+
+            origin = translated;
+            assert!(m.iter().eq(init(shape, origin, &src_strides).iter()));
+        }
+
+        let mut evicted = 0;
+        m.translate_axis_evict(1, -2, &mut origin, src_fn, |_, iter| evicted += iter.len());
+        assert_eq!(evicted, 16);
+        assert!(m.iter().eq(init(shape, origin, &src_strides).iter()));
+    }
+
+    #[test]
+    fn translate_from() {
+        let src_shape = [9, 8, 7];
+        let src_strides = Strides::new(&src_shape);
+        let src = (0..src_shape.iter().product()).collect::<Vec<usize>>();
+        let src_fn = |idx: [Range<usize>; 3]| &src[src_strides.flatten_range(idx)];
+
+        // The source, offset such that logical indices match `src`.
+        let offset = [4, 1, 6];
+        let source = CircularArrayVec::from_iter_offset(
+            src_shape,
+            offset,
+            iter_box(src_shape, [0; 3]).map(|raw| {
+                src_strides.offset_index(array::from_fn(|i| {
+                    (raw[i] + src_shape[i] - offset[i]) % src_shape[i]
+                }))
+            }),
+        );
+        assert!(source.iter().eq(src.iter()));
+
+        let shape = [4, 3, 2];
+        let mut origin = [2, 3, 2];
+        let mut m = init(shape, origin, &src_strides);
+        let mut expected = init(shape, origin, &src_strides);
+
+        for (axis, n) in [(0, 2), (1, -3), (2, 3), (0, -2), (1, 4), (2, -1), (0, 3)] {
+            let mut expected_origin = origin;
+            m.translate_from(axis, n, &mut origin, &source);
+            expected.translate_axis(axis, n, &mut expected_origin, src_fn);
+
+            assert_eq!(origin, expected_origin);
+            assert!(m.iter().eq(expected.iter()));
+        }
+    }
+
+    #[test]
+    fn translate_with() {
+        let src_shape = [9, 8, 7];
+        let src_strides = Strides::new(&src_shape);
+        let src = (0..src_shape.iter().product()).collect::<Vec<usize>>();
+        let src_fn = |idx: [Range<usize>; 3]| &src[src_strides.flatten_range(idx)];
+        let fill_fn = |idx: [Range<usize>; 3], dst: &mut [usize]| {
+            dst.clone_from_slice(src_fn(idx));
+        };
+
+        let shape = [4, 3, 2];
+        let mut origin = [2, 3, 2];
+        let mut m = init(shape, origin, &src_strides);
+
+        for (axis, n) in [
+            (0, 2),
+            (1, -3),
+            (2, 3),
+            (0, -2),
+            (1, 4),
+            (2, -1),
+            (0, 3),
+            (0, -5),
+        ] {
+            let len = isize::unsigned_abs(n);
+            match n < 0 {
+                true => m.translate_back_with(axis, len, origin, fill_fn),
+                false => m.translate_front_with(axis, len, origin, fill_fn),
+            }
+            origin[axis] = origin[axis].checked_add_signed(n).unwrap();
+
+            assert!(m.iter().eq(init(shape, origin, &src_strides).iter()));
+        }
+    }
+
+    #[test]
+    fn try_translate() {
+        let src_shape = [9, 8, 7];
+        let src_strides = Strides::new(&src_shape);
+        let src = (0..src_shape.iter().product()).collect::<Vec<usize>>();
+        let src_fn = |idx: [Range<usize>; 3]| &src[src_strides.flatten_range(idx)];
+        // Elements beyond axis 0 index 6 are not loaded.
+        let try_fn = |idx: [Range<usize>; 3]| match idx[0].end <= 6 {
+            true => Ok(src_fn(idx)),
+            false => Err(idx),
+        };
+
+        let shape = [4, 3, 2];
+        let mut origin = [1, 3, 2];
+        let mut m = init(shape, origin, &src_strides);
+
+        for delta in [[1, 1, 1], [-1, -2, 0], [0, 3, -1], [1, 0, 0], [-2, -1, 1]] {
+            assert_eq!(m.try_translate(delta, origin, try_fn), Ok(()));
+            origin = array::from_fn(|i| origin[i].checked_add_signed(delta[i]).unwrap());
+            assert!(m.iter().eq(init(shape, origin, &src_strides).iter()));
+        }
+
+        let expected = m.clone();
+        assert_eq!(
+            m.try_translate([3, 1, 0], origin, try_fn),
+            Err([4..7, 5..6, 3..4])
+        );
+        assert_eq!(
+            m.try_translate_front(0, 3, origin, try_fn),
+            Err([4..7, 4..5, 3..4])
+        );
+        assert_eq!(m.offset(), expected.offset());
+        assert!(m.iter().eq(expected.iter()));
+
+        assert_eq!(m.try_translate_back(1, 2, origin, try_fn), Ok(()));
+        origin[1] -= 2;
+        assert!(m.iter().eq(init(shape, origin, &src_strides).iter()));
+    }
+
+    #[test]
+    fn translate_from_bounded() {
+        let src_shape = [5, 4];
+        let source = CircularArrayVec::from_iter_offset(src_shape, [2, 1], 0..20);
+
+        for boundary in [Boundary::Wrap, Boundary::Clamp, Boundary::Constant(-1)] {
+            let shape = [3, 2];
+            let mut origin = [-2, 1];
+            let mut m = CircularArrayVec::from_iter(shape, (0..6).map(|_| 0));
+            m.translate_from_bounded(0, 3, &mut origin, &source, boundary);
+
+            for (axis, n) in [(0, 2), (1, -3), (0, -7), (1, 5), (0, 1), (1, -1), (0, 9)] {
+                m.translate_from_bounded(axis, n, &mut origin, &source, boundary);
+
+                for (index, el) in m.indexed_iter() {
+                    let src = array::from_fn(|i| {
+                        boundary.resolve(origin[i] + index[i] as isize, src_shape[i])
+                    });
+                    match src {
+                        [Some(x), Some(y)] => assert_eq!(el, source.get([x, y])),
+                        _ => assert_eq!(*el, -1),
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,180 @@
+use std::array;
+use std::ops::Range;
+
+use crate::array_iter::CircularArrayIterator;
+use crate::index::RawIndexAdaptor;
+use crate::index_iter::IndexIterator;
+use crate::span::BoundSpan;
+use crate::CircularArray;
+
+impl<const N: usize, A: AsRef<[T]>, T> CircularArray<N, A, T> {
+    /// Get a reference to the element at the given index, aligned to the offset,
+    /// without bounds checking. See [`CircularIndex::get`](crate::CircularIndex::get).
+    ///
+    /// # Safety
+    ///
+    /// Each index **must** be less than the length of its axis, and the length of
+    /// the buffer **must** equal the product of the shape (which may be broken
+    /// through [`CircularArray::data_mut`]). The offset is wrapped, and may be
+    /// out of bounds (such as where set through [`CircularArray::offset_mut`]).
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::CircularArray;
+    /// let array = CircularArray::new_offset([3, 2], [1, 0], vec![
+    ///     2, 0, 1,
+    ///     5, 3, 4,
+    /// ]);
+    ///
+    /// assert_eq!(unsafe { array.get_unchecked([2, 1]) }, &5);
+    /// ```
+    pub unsafe fn get_unchecked(&self, index: [usize; N]) -> &T {
+        let i = self.wrap_unchecked(index);
+        // SAFETY: The caller guarantees `index` is in bounds of the shape.
+        unsafe { self.array.as_ref().get_unchecked(i) }
+    }
+
+    /// Iterate over all elements within the given `slice`, aligned to the offset,
+    /// without bounds checking. See
+    /// [`CircularIndex::iter_slice`](crate::CircularIndex::iter_slice).
+    ///
+    /// # Safety
+    ///
+    /// The end of each range **must** be less than or equal to the length of its
+    /// axis, and the length of the buffer **must** equal the product of the shape.
+    /// See [`CircularArray::get_unchecked`].
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::CircularArray;
+    /// let array = CircularArray::new_offset([3, 2], [1, 0], vec![
+    ///     2, 0, 1,
+    ///     5, 3, 4,
+    /// ]);
+    /// let slice = unsafe { array.iter_slice_unchecked([1..3, 0..2]) };
+    ///
+    /// assert_eq!(slice.cloned().collect::<Vec<_>>(), &[1, 2, 4, 5]);
+    /// ```
+    pub unsafe fn iter_slice_unchecked(
+        &self,
+        slice: [Range<usize>; N],
+    ) -> impl ExactSizeIterator<Item = &T> {
+        self.debug_assert_buffer_len();
+        let spans = array::from_fn(|i| {
+            let range = &slice[i];
+            BoundSpan::new(self.wrap_offset(i, range.start), range.len(), self.shape[i])
+        });
+
+        let iter = IndexIterator::new_bound_contiguous(spans)
+            .into_flat_ranges(&self.strides)
+            // SAFETY: The caller guarantees `slice` is in bounds of the shape, such
+            // that each range is in bounds of the buffer.
+            .flat_map(|range| unsafe { self.array.as_ref().get_unchecked(range) });
+        let len = spans.iter().map(|spans| spans.len()).product();
+
+        CircularArrayIterator::new(iter, len)
+    }
+
+    /// Get the buffer index of the given `index`, aligned to the offset.
+    fn wrap_unchecked(&self, index: [usize; N]) -> usize {
+        self.debug_assert_buffer_len();
+        self.strides
+            .offset_index(array::from_fn(|i| self.wrap_offset(i, index[i])))
+    }
+
+    /// Wrap an `index < len` of the given `axis` aligned to the offset, wrapping
+    /// the offset into bounds of the axis, and the aligned index by subtraction.
+    fn wrap_offset(&self, axis: usize, index: usize) -> usize {
+        wrap(
+            index + self.wrap_index(axis, self.offset[axis]),
+            self.shape[axis],
+        )
+    }
+
+    /// Assert the length of the buffer equals the product of the shape, in debug
+    /// builds.
+    fn debug_assert_buffer_len(&self) {
+        debug_assert_eq!(
+            self.array.as_ref().len(),
+            self.len(),
+            "buffer length does not match shape {:?}",
+            self.shape
+        );
+    }
+}
+
+impl<const N: usize, A: AsRef<[T]> + AsMut<[T]>, T> CircularArray<N, A, T> {
+    /// Get a mutable reference to the element at the given index, aligned to the
+    /// offset, without bounds checking. See [`CircularMut::get_mut`](crate::CircularMut::get_mut).
+    ///
+    /// # Safety
+    ///
+    /// Each index **must** be less than the length of its axis, and the length of
+    /// the buffer **must** equal the product of the shape (which may be broken
+    /// through [`CircularArray::data_mut`]). The offset is wrapped, and may be
+    /// out of bounds (such as where set through [`CircularArray::offset_mut`]).
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let mut array = CircularArray::new_offset([3, 2], [1, 0], vec![
+    ///     2, 0, 1,
+    ///     5, 3, 4,
+    /// ]);
+    /// unsafe { *array.get_unchecked_mut([2, 1]) = 9 };
+    ///
+    /// assert_eq!(array.get([2, 1]), &9);
+    /// ```
+    pub unsafe fn get_unchecked_mut(&mut self, index: [usize; N]) -> &mut T {
+        let i = self.wrap_unchecked(index);
+        // SAFETY: The caller guarantees `index` is in bounds of the shape.
+        unsafe { self.array.as_mut().get_unchecked_mut(i) }
+    }
+}
+
+/// Wrap an index `< 2 * len` into `0..len`.
+fn wrap(index: usize, len: usize) -> usize {
+    match index >= len {
+        true => index - len,
+        false => index,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CircularArrayVec, CircularIndex};
+
+    #[test]
+    fn unchecked() {
+        let shape = [4, 3, 2];
+        let mut m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..shape.iter().product());
+
+        for (index, el) in m.indexed_iter() {
+            assert_eq!(unsafe { m.get_unchecked(index) }, el);
+        }
+        for slice in [[1..4, 0..2, 1..2], [0..4, 0..3, 0..2], [3..4, 2..3, 0..1]] {
+            let unchecked = unsafe { m.iter_slice_unchecked(slice.clone()) };
+            assert!(unchecked.eq(m.iter_slice(slice)));
+        }
+
+        unsafe { *m.get_unchecked_mut([3, 2, 1]) = 99 };
+        assert_eq!(m.get([3, 2, 1]), &99);
+    }
+
+    #[test]
+    fn unchecked_offset_mut() {
+        let mut m = CircularArrayVec::from_iter([3, 4], 0..12);
+        let expected = CircularArrayVec::from_iter_offset([3, 4], [1, 1], 0..12);
+        *m.offset_mut() = [7, 9];
+
+        for (index, el) in expected.indexed_iter() {
+            assert_eq!(unsafe { m.get_unchecked(index) }, el);
+        }
+        let slice = unsafe { m.iter_slice_unchecked([1..3, 2..4]) };
+        assert!(slice.eq(expected.iter_slice([1..3, 2..4])));
+
+        unsafe { *m.get_unchecked_mut([2, 3]) = 99 };
+        *m.offset_mut() = [1, 1];
+        assert_eq!(m.get([2, 3]), &99);
+    }
+}
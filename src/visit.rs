@@ -0,0 +1,87 @@
+use std::array;
+use std::ops::Range;
+
+use crate::array_iter::iter_indices;
+use crate::CircularArray;
+
+impl<const N: usize, A: AsRef<[T]>, T> CircularArray<N, A, T> {
+    /// Call `visitor` with each contiguous run of elements within the given `slice`,
+    /// aligned to the offset. Runs are visited in logical order, each paired with
+    /// the logical index of its first element. Runs span at most one row (axis
+    /// `0`) of the slice, split where the row wraps within the array.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let array = CircularArray::new_offset([3, 2], [1, 0], vec![
+    ///     2, 0, 1,
+    ///     5, 3, 4,
+    /// ]);
+    /// let mut runs = vec![];
+    /// array.visit_regions([1..3, 0..2], |index, run| runs.push((index, run.to_vec())));
+    ///
+    /// assert_eq!(runs, &[
+    ///     ([1, 0], vec![1]),
+    ///     ([2, 0], vec![2]),
+    ///     ([1, 1], vec![4]),
+    ///     ([2, 1], vec![5]),
+    /// ]);
+    /// ```
+    pub fn visit_regions<F: FnMut([usize; N], &[T])>(
+        &self,
+        slice: [Range<usize>; N],
+        mut visitor: F,
+    ) {
+        let region = self.view_region(slice);
+        if region.is_empty() {
+            return;
+        }
+
+        let data = self.array.as_ref();
+        let (origin, shape) = (*region.origin(), *region.shape());
+        let (len, run) = (self.shape[0], shape[0]);
+
+        let mut rows = shape;
+        rows[0] = 1;
+
+        for index in iter_indices(rows) {
+            let mut index: [usize; N] = array::from_fn(|i| index[i] + origin[i]);
+            let raw = array::from_fn(|i| (index[i] + self.offset[i]) % self.shape[i]);
+
+            let start = self.strides.offset_index(raw);
+            let head = run.min(len - raw[0]);
+            visitor(index, &data[start..start + head]);
+
+            if head < run {
+                let start = start - raw[0];
+                index[0] += head;
+                visitor(index, &data[start..start + run - head]);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CircularArrayVec, CircularIndex};
+
+    #[test]
+    fn visit_regions() {
+        let shape = [4, 3, 2];
+        let m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..shape.iter().product());
+        let slice = [1..4, 0..2, 1..2];
+
+        let mut visited = vec![];
+        m.visit_regions(slice.clone(), |index, run| {
+            run.iter().enumerate().for_each(|(i, el)| {
+                assert_eq!(m.get([index[0] + i, index[1], index[2]]), el);
+            });
+            visited.extend_from_slice(run);
+        });
+        assert!(visited.iter().eq(m.iter_slice(slice)));
+
+        let mut runs = 0;
+        m.visit_regions([0..0, 0..3, 0..2], |_, _| runs += 1);
+        assert_eq!(runs, 0);
+    }
+}
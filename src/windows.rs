@@ -0,0 +1,81 @@
+use std::array;
+
+use crate::{CircularArray, CircularArrayRegion};
+
+impl<const N: usize, A: AsRef<[T]>, T> CircularArray<N, A, T> {
+    /// Iterate over views of every run of `k` consecutive slices of the given
+    /// `axis`, aligned to the offset. Each [`CircularArrayRegion`] has a length of
+    /// `k` on `axis`, and spans every other axis. Runs crossing the bounds of the
+    /// underlying buffer are handled by the region. Yields no views where `k` is
+    /// greater than the length of `axis`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is `0`.
+    ///
+    /// # Example
+    /// ```
+    /// # use n_circular_array::{CircularArray, CircularIndex};
+    /// let array = CircularArray::new_offset([4, 2], [1, 0], vec![
+    ///     3, 0, 1, 2,
+    ///     7, 4, 5, 6,
+    /// ]);
+    /// let windows = array
+    ///     .windows(0, 3)
+    ///     .map(|window| window.iter().cloned().collect::<Vec<_>>())
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(windows, [
+    ///     [0, 1, 2, 4, 5, 6],
+    ///     [1, 2, 3, 5, 6, 7],
+    /// ]);
+    /// ```
+    pub fn windows(
+        &self,
+        axis: usize,
+        k: usize,
+    ) -> impl ExactSizeIterator<Item = CircularArrayRegion<'_, N, T>> {
+        assert_shape_index!(axis, N);
+        assert!(k != 0, "window length must be greater than 0");
+
+        (0..(self.shape[axis] + 1).saturating_sub(k)).map(move |i| {
+            self.view_region(array::from_fn(|j| match j == axis {
+                true => i..i + k,
+                false => 0..self.shape[j],
+            }))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CircularArrayVec, CircularIndex};
+
+    #[test]
+    fn windows() {
+        let shape = [4, 3, 2];
+        let m = CircularArrayVec::from_iter_offset(shape, [1, 2, 1], 0..24);
+
+        for axis in 0..3 {
+            for k in 1..=shape[axis] {
+                let windows = m.windows(axis, k);
+                assert_eq!(windows.len(), shape[axis] - k + 1);
+
+                for (i, window) in windows.enumerate() {
+                    let mut expected = shape;
+                    expected[axis] = k;
+                    assert_eq!(window.shape(), &expected);
+                    assert!(window.iter().eq(m.iter_range(axis, i..i + k)));
+                }
+            }
+            assert_eq!(m.windows(axis, shape[axis] + 1).len(), 0);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn windows_zero() {
+        let m = CircularArrayVec::from_iter([3, 3], 0..9);
+        let _ = m.windows(0, 0);
+    }
+}